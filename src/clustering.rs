@@ -0,0 +1,174 @@
+use std::num::NonZeroUsize;
+
+use num::Float;
+use rand::Rng;
+
+use crate::{DimensionError, Matrix, MatrixError, Shape};
+
+/// The result of [kmeans]
+#[derive(Debug, Clone)]
+pub struct KMeans<F> {
+    centroids: Matrix<F>,
+    assignments: Vec<usize>,
+    iterations: usize,
+}
+impl<F> KMeans<F> {
+    /// A `k x data.width()` matrix where each row is a cluster center
+    pub fn centroids(&self) -> &Matrix<F> {
+        return &self.centroids;
+    }
+    /// The index of the centroid each row of the data was assigned to
+    pub fn assignments(&self) -> &[usize] {
+        return &self.assignments;
+    }
+    /// The number of update/assignment rounds that were performed after the initial assignment
+    pub fn iterations(&self) -> usize {
+        return self.iterations;
+    }
+}
+
+/// Cluster the rows of `data` into `k` groups using Lloyd's algorithm with k-means++ seeding
+/// ## Parameters
+/// - `data`: each row is a point.
+/// - `k`: the number of clusters.
+/// - `max_iterations`: stop after this many rounds even if the assignments are still changing,
+///   with `0` every point is assigned to the nearest initial centroid.
+/// - `rng`: source of randomness for choosing the initial centroids.
+/// ## Returns
+/// - The [KMeans] centroids and assignments.
+/// ## Errors
+/// - [MatrixError::DimensionError]
+///   - if `k` is greater than the number of rows in `data`
+pub fn kmeans<F: Float, R: Rng + ?Sized>(
+    data: &Matrix<F>,
+    k: NonZeroUsize,
+    max_iterations: usize,
+    rng: &mut R,
+) -> Result<KMeans<F>, MatrixError> {
+    if k.get() > data.height() {
+        return Err(DimensionError::TooSmall.into());
+    }
+
+    let mut centroids = initial_centroids(data, k, rng);
+    let mut assignments = data
+        .rows()
+        .map(|row| nearest_centroid(&centroids, row).0)
+        .collect::<Vec<_>>();
+
+    let mut iterations = 0;
+    while iterations < max_iterations {
+        iterations += 1;
+
+        let mut sums = Matrix::<F>::zeros(Shape {
+            rows: k,
+            cols: data.width_nonzero(),
+        });
+        let mut counts = vec![0usize; k.get()];
+        for (row, &cluster) in data.rows().zip(assignments.iter()) {
+            counts[cluster] += 1;
+            for (sum, &element) in sums[cluster].iter_mut().zip(row) {
+                *sum = *sum + element;
+            }
+        }
+        for (cluster, &count) in counts.iter().enumerate() {
+            // an empty cluster keeps its previous centroid
+            if count == 0 {
+                continue;
+            }
+            let count = F::from(count).expect("count fits in a float");
+            for (centroid, &sum) in centroids[cluster].iter_mut().zip(sums[cluster].iter()) {
+                *centroid = sum / count;
+            }
+        }
+
+        let mut changed = false;
+        for (row_index, row) in data.rows().enumerate() {
+            let nearest = nearest_centroid(&centroids, row).0;
+            if assignments[row_index] != nearest {
+                assignments[row_index] = nearest;
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+
+    return Ok(KMeans {
+        centroids,
+        assignments,
+        iterations,
+    });
+}
+
+/// k-means++ seeding: each new centroid is a row chosen with probability proportional to its
+/// squared distance from the nearest centroid chosen so far
+fn initial_centroids<F: Float, R: Rng + ?Sized>(
+    data: &Matrix<F>,
+    k: NonZeroUsize,
+    rng: &mut R,
+) -> Matrix<F> {
+    let mut centroids = Matrix::zeros(Shape {
+        rows: k,
+        cols: data.width_nonzero(),
+    });
+
+    let first = rng.gen_range(0..data.height());
+    centroids[0].copy_from_slice(&data[first]);
+
+    for cluster in 1..k.get() {
+        let chosen = centroids_so_far(&centroids, cluster);
+        let distances = data
+            .rows()
+            .map(|row| nearest_centroid(&chosen, row).1.to_f64().unwrap_or(0.0))
+            .collect::<Vec<_>>();
+        let total = distances.iter().sum::<f64>();
+
+        let mut next = rng.gen_range(0..data.height());
+        if total > 0.0 {
+            let mut target = rng.gen::<f64>() * total;
+            for (row_index, &distance) in distances.iter().enumerate() {
+                target -= distance;
+                if target <= 0.0 {
+                    next = row_index;
+                    break;
+                }
+            }
+        }
+        centroids[cluster].copy_from_slice(&data[next]);
+    }
+
+    return centroids;
+}
+
+/// The first `count` rows of `centroids`
+fn centroids_so_far<F: Float>(centroids: &Matrix<F>, count: usize) -> Matrix<F> {
+    let mut chosen = Matrix::zeros(Shape {
+        rows: NonZeroUsize::new(count).expect("at least one centroid is chosen"),
+        cols: centroids.width_nonzero(),
+    });
+    for (chosen_row, row) in chosen.rows_mut().zip(centroids.rows()) {
+        chosen_row.copy_from_slice(row);
+    }
+    return chosen;
+}
+
+/// The index of, and squared distance to, the centroid closest to `point`
+fn nearest_centroid<F: Float>(centroids: &Matrix<F>, point: &[F]) -> (usize, F) {
+    return centroids
+        .rows()
+        .map(|centroid| {
+            centroid
+                .iter()
+                .zip(point)
+                .fold(F::zero(), |sum, (&c, &p)| sum + (c - p) * (c - p))
+        })
+        .enumerate()
+        .fold((0, F::infinity()), |nearest, (index, distance)| {
+            if distance < nearest.1 {
+                (index, distance)
+            } else {
+                nearest
+            }
+        });
+}
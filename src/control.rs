@@ -0,0 +1,271 @@
+use std::num::NonZeroUsize;
+
+use num::Float;
+
+use crate::{DimensionError, Matrix, MatrixError, Shape};
+
+/// Solve the Sylvester equation `A * X + X * B` == `C` with the Bartels–Stewart algorithm <br>
+/// `A` and `B` are reduced to real Schur form `A` == `U * T * Uᵀ`, `B` == `V * S * Vᵀ`,
+/// the triangular system `T * Y + Y * S` == `Uᵀ * C * V` is solved one column
+/// (or `2 x 2` block of `S`) at a time, then `X` == `U * Y * Vᵀ`.
+/// ## Parameters
+/// - `a`: `m x m`.
+/// - `b`: `n x n`.
+/// - `c`: `m x n`.
+/// ## Returns
+/// - The `m x n` solution `X`.
+///   It is unique exactly when `A` and `-B` have no eigenvalue in common.
+/// ## Errors
+/// - [MatrixError::Decomposition]
+///   - if `a` or `b` is not square
+///   - if the Schur decomposition of `a` or `b` does not converge
+///   - if `A` and `-B` share an eigenvalue so the solution is not unique
+/// - [MatrixError::DimensionError]
+///   - if `c` is not `a.height() x b.height()`
+pub fn solve_sylvester<F: Float>(
+    a: &Matrix<F>,
+    b: &Matrix<F>,
+    c: &Matrix<F>,
+) -> Result<Matrix<F>, MatrixError> {
+    let (u, t) = a.schur()?.into_parts();
+    let (v, s) = b.schur()?.into_parts();
+    if c.height() != a.height() {
+        return Err(DimensionError::UnexpectedHeight {
+            expected: a.height(),
+            actual: c.shape(),
+        }
+        .into());
+    }
+    if c.width() != b.height() {
+        return Err(DimensionError::UnexpectedWidth {
+            expected: b.height(),
+            actual: c.shape(),
+        }
+        .into());
+    }
+
+    let f = u.transpose().matrix_multiply(c)?.matrix_multiply(&v)?;
+    let (m, n) = (a.height(), b.height());
+    let mut y = Matrix::zeros(Shape {
+        rows: a.height_nonzero(),
+        cols: b.height_nonzero(),
+    });
+
+    let mut column = 0;
+    while column < n {
+        // the right hand side of each column with the already solved columns moved over
+        let right_hand_side = |k: usize, y: &Matrix<F>| -> Vec<F> {
+            return (0..m)
+                .map(|row| (0..column).fold(f[row][k], |sum, j| sum - y[row][j] * s[j][k]))
+                .collect();
+        };
+
+        let block = if column + 1 < n && !s[column + 1][column].is_zero() {
+            2
+        } else {
+            1
+        };
+        let size = NonZeroUsize::new(block * m).expect("m is not 0");
+
+        // block == 1: (T + s_kk I) y_k = f_k
+        // block == 2: T y_k + s_kk y_k + s_(k+1)k y_(k+1) = f_k
+        //             T y_(k+1) + s_k(k+1) y_k + s_(k+1)(k+1) y_(k+1) = f_(k+1)
+        let mut system = Matrix::zeros(Shape::square(size));
+        let mut rhs = Matrix::zeros(Shape {
+            rows: size,
+            cols: NonZeroUsize::MIN,
+        });
+        for i in 0..block {
+            for (row, value) in right_hand_side(column + i, &y).into_iter().enumerate() {
+                rhs[i * m + row][0] = value;
+            }
+            for j in 0..block {
+                for row in 0..m {
+                    if i == j {
+                        for k in 0..m {
+                            system[i * m + row][j * m + k] = t[row][k];
+                        }
+                    }
+                    let coupling = &mut system[i * m + row][j * m + row];
+                    *coupling = *coupling + s[column + j][column + i];
+                }
+            }
+        }
+
+        let solution = system.lu()?.solve(&rhs)?;
+        for i in 0..block {
+            for row in 0..m {
+                y[row][column + i] = solution[i * m + row][0];
+            }
+        }
+        column += block;
+    }
+
+    return u.matrix_multiply(&y)?.matrix_multiply(&v.transpose());
+}
+
+/// Solve the continuous Lyapunov equation `A * X + X * Aᵀ + Q` == `0` <br>
+/// The special case of [solve_sylvester] with `B` == `Aᵀ` and `C` == `-Q`.
+/// If `A` is stable and `Q` is symmetric positive definite then `X` is too.
+/// ## Errors
+/// - Same as [solve_sylvester]
+pub fn solve_lyapunov<F: Float>(a: &Matrix<F>, q: &Matrix<F>) -> Result<Matrix<F>, MatrixError> {
+    return solve_sylvester(a, &a.transpose(), &q.scalar_multiply(-F::one()));
+}
+
+/// The controllability matrix `[B, A * B, A² * B, ..., Aⁿ⁻¹ * B]` of `xₖ₊₁` == `A * xₖ + B * uₖ`
+/// ## Parameters
+/// - `a`: the `n x n` state matrix.
+/// - `b`: the `n x m` input matrix.
+/// ## Returns
+/// - The `n x (n * m)` controllability matrix.
+/// ## Errors
+/// - [MatrixError::DimensionError]
+///   - if `a` is not square
+///   - if `b.height()` != `a.height()`
+pub fn controllability_matrix<F: Float>(
+    a: &Matrix<F>,
+    b: &Matrix<F>,
+) -> Result<Matrix<F>, MatrixError> {
+    check_state_matrix(a)?;
+    if b.height() != a.height() {
+        return Err(DimensionError::UnexpectedHeight {
+            expected: a.height(),
+            actual: b.shape(),
+        }
+        .into());
+    }
+
+    let (n, m) = (a.height(), b.width());
+    let width = NonZeroUsize::new(n * m).expect("n and m are not 0");
+    let mut controllability = Matrix::zeros(Shape {
+        rows: a.height_nonzero(),
+        cols: width,
+    });
+
+    let mut block = b.clone();
+    for power in 0..n {
+        if power > 0 {
+            block = a.matrix_multiply(&block)?;
+        }
+        for (row, block_row) in controllability.rows_mut().zip(block.rows()) {
+            row[power * m..(power + 1) * m].copy_from_slice(block_row);
+        }
+    }
+
+    return Ok(controllability);
+}
+
+/// The observability matrix `[C; C * A; C * A²; ...; C * Aⁿ⁻¹]` of `xₖ₊₁` == `A * xₖ`, `yₖ` == `C * xₖ`
+/// ## Parameters
+/// - `a`: the `n x n` state matrix.
+/// - `c`: the `p x n` output matrix.
+/// ## Returns
+/// - The `(n * p) x n` observability matrix.
+/// ## Errors
+/// - [MatrixError::DimensionError]
+///   - if `a` is not square
+///   - if `c.width()` != `a.width()`
+pub fn observability_matrix<F: Float>(
+    a: &Matrix<F>,
+    c: &Matrix<F>,
+) -> Result<Matrix<F>, MatrixError> {
+    check_state_matrix(a)?;
+    if c.width() != a.width() {
+        return Err(DimensionError::UnexpectedWidth {
+            expected: a.width(),
+            actual: c.shape(),
+        }
+        .into());
+    }
+
+    // observability of (A, C) is controllability of (Aᵀ, Cᵀ)
+    return Ok(controllability_matrix(&a.transpose(), &c.transpose())?.transpose());
+}
+
+/// `true` if every state can be reached from every other, the [controllability_matrix] has rank `n`
+/// ## Errors
+/// - Same as [controllability_matrix]
+pub fn is_controllable<F: Float>(a: &Matrix<F>, b: &Matrix<F>) -> Result<bool, MatrixError> {
+    let controllability = controllability_matrix(a, b)?;
+    let (_, pivot_columns) = controllability.reduce_row_echelon(controllability.width());
+    return Ok(pivot_columns.len() == a.height());
+}
+
+/// `true` if the state can be recovered from the outputs, the [observability_matrix] has rank `n`
+/// ## Errors
+/// - Same as [observability_matrix]
+pub fn is_observable<F: Float>(a: &Matrix<F>, c: &Matrix<F>) -> Result<bool, MatrixError> {
+    return is_controllable(&a.transpose(), &c.transpose());
+}
+
+/// Simulate the discrete time linear system `xₖ₊₁` == `A * xₖ + B * uₖ`
+/// ## Parameters
+/// - `a`: the `n x n` state matrix.
+/// - `b`: the `n x m` input matrix.
+/// - `x0`: the `n` initial states.
+/// - `inputs`: `steps x m`, row `k` is the input `uₖ`.
+/// ## Returns
+/// - The `(steps + 1) x n` trajectory, row `k` is the state `xₖ` starting with `x0`.
+/// ## Errors
+/// - [MatrixError::DimensionError]
+///   - if `a` is not square
+///   - if `b.height()` or `x0.len()` != `a.height()`
+///   - if `inputs.width()` != `b.width()`
+pub fn simulate_lti<F: Float>(
+    a: &Matrix<F>,
+    b: &Matrix<F>,
+    x0: &[F],
+    inputs: &Matrix<F>,
+) -> Result<Matrix<F>, MatrixError> {
+    check_state_matrix(a)?;
+    if b.height() != a.height() {
+        return Err(DimensionError::UnexpectedHeight {
+            expected: a.height(),
+            actual: b.shape(),
+        }
+        .into());
+    }
+    if x0.len() != a.height() {
+        return Err(DimensionError::UnexpectedLength {
+            expected: a.height(),
+            actual: x0.len(),
+        }
+        .into());
+    }
+    if inputs.width() != b.width() {
+        return Err(DimensionError::UnexpectedWidth {
+            expected: b.width(),
+            actual: inputs.shape(),
+        }
+        .into());
+    }
+
+    let steps = NonZeroUsize::MIN.saturating_add(inputs.height());
+    let mut trajectory = Matrix::zeros(Shape {
+        rows: steps,
+        cols: a.width_nonzero(),
+    });
+    trajectory[0].copy_from_slice(x0);
+
+    for (step, input) in inputs.rows().enumerate() {
+        for state in 0..a.height() {
+            let drift =
+                (0..a.width()).fold(F::zero(), |sum, k| sum + a[state][k] * trajectory[step][k]);
+            let forcing = input
+                .iter()
+                .enumerate()
+                .fold(F::zero(), |sum, (k, &u)| sum + b[state][k] * u);
+            trajectory[step + 1][state] = drift + forcing;
+        }
+    }
+
+    return Ok(trajectory);
+}
+
+fn check_state_matrix<F>(a: &Matrix<F>) -> Result<(), MatrixError> {
+    if a.width() != a.height() {
+        return Err(DimensionError::NotSquare.into());
+    }
+    return Ok(());
+}
@@ -1,43 +1,159 @@
-use std::{fs, num::NonZeroUsize, path::Path, str::FromStr};
+use std::{collections::BTreeMap, fmt::Display, fs, num::NonZeroUsize, path::Path, str::FromStr};
 
-use crate::error::{DataSetError, ParseDataSetError};
+use num::Float;
 
-pub struct DataPoint<T> {
-    input: T,
-    output: T,
+use crate::{
+    error::{DataSetError, ParseDataSetError},
+    matrix::sort::compare_nan_last,
+    SortDirection,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataPoint<I, O = I> {
+    input: I,
+    output: O,
 }
-impl<T> DataPoint<T> {
-    pub fn input(&self) -> &T {
+impl<I, O> DataPoint<I, O> {
+    pub fn input(&self) -> &I {
         return &self.input;
     }
-    pub fn output(&self) -> &T {
+    pub fn output(&self) -> &O {
         return &self.output;
     }
 }
 
-pub struct DataSet<T> {
-    data: Vec<DataPoint<T>>,
+/// Pairs of `input, output` values <br>
+/// The inputs are usually the same type as the outputs,
+/// but can be something else like the [crate::Timestamp]s of a [crate::TimeSeries].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataSet<I, O = I> {
+    data: Vec<DataPoint<I, O>>,
 }
 
-impl<T> DataSet<T> {
+impl<I, O> DataSet<I, O> {
     pub fn len(&self) -> usize {
         return self.data.len();
     }
+    pub fn is_empty(&self) -> bool {
+        return self.data.is_empty();
+    }
+    /// Every constructor rejects an empty [DataSet] and [DataSet::data_mut] cannot remove points,
+    /// so this never panics
     pub fn len_nonzero(&self) -> NonZeroUsize {
         return NonZeroUsize::new(self.len()).expect("Length can not be zero");
     }
-    pub fn data(&self) -> &[DataPoint<T>] {
+    pub fn data(&self) -> &[DataPoint<I, O>] {
         return &self.data;
     }
-    pub fn data_mut(&mut self) -> &mut [DataPoint<T>] {
+    pub fn data_mut(&mut self) -> &mut [DataPoint<I, O>] {
         return &mut self.data;
     }
 }
 
-impl<T> DataSet<T>
+impl<I: Clone, O: Clone> DataSet<I, O> {
+    /// A new [DataSet] with the points of `self` followed by the points of `other`,
+    /// for example to combine several measurement runs
+    pub fn merge(&self, other: &DataSet<I, O>) -> DataSet<I, O> {
+        let data = self.data.iter().chain(other.data.iter()).cloned().collect();
+        return DataSet { data };
+    }
+
+    /// Split the points into one [DataSet] per key, keeping their order within each group
+    /// ## Parameters
+    /// - `key`: the group of each point, such as a bucket of its input.
+    /// ## Returns
+    /// - The groups ordered by key, none of them empty.
+    pub fn group_by<K: Ord>(
+        &self,
+        mut key: impl FnMut(&DataPoint<I, O>) -> K,
+    ) -> BTreeMap<K, DataSet<I, O>> {
+        let mut groups = BTreeMap::<K, DataSet<I, O>>::new();
+        for data_point in self.data.iter() {
+            groups
+                .entry(key(data_point))
+                .or_insert_with(|| DataSet { data: Vec::new() })
+                .data
+                .push(data_point.clone());
+        }
+        return groups;
+    }
+
+    /// [DataSet::group_by] then `fit` each group, for example one trend per category
+    /// ## Errors
+    /// - The first error returned by `fit`, in key order.
+    pub fn fit_per_group<K: Ord, T, E>(
+        &self,
+        key: impl FnMut(&DataPoint<I, O>) -> K,
+        mut fit: impl FnMut(&DataSet<I, O>) -> Result<T, E>,
+    ) -> Result<BTreeMap<K, T>, E> {
+        return self
+            .group_by(key)
+            .into_iter()
+            .map(|(key, group)| Ok((key, fit(&group)?)))
+            .collect();
+    }
+}
+
+impl<F: Float, O: Clone> DataSet<F, O> {
+    /// Pair the outputs of points in `self` and `other` whose inputs match <br>
+    /// Both are walked in ascending input order and each point is matched at most once,
+    /// to the first unmatched point of the other within `tolerance`.
+    /// Points without a match and points whose input is not finite, which would match anything or nothing, are dropped.
+    /// ## Parameters
+    /// - `tolerance`: the largest difference between inputs that still match, `0` for exact matches.
+    /// ## Returns
+    /// - A [DataSet] sorted by input, with the input of `self` and the outputs of both.
+    /// ## Errors
+    /// - [DataSetError::Empty]
+    ///   - if no inputs match
+    pub fn join_on_input<P: Clone>(
+        &self,
+        other: &DataSet<F, P>,
+        tolerance: F,
+    ) -> Result<DataSet<F, (O, P)>, DataSetError> {
+        let sorted = |inputs: Vec<F>| {
+            let mut indices = (0..inputs.len())
+                .filter(|&index| inputs[index].is_finite())
+                .collect::<Vec<_>>();
+            indices.sort_by(|&a, &b| {
+                compare_nan_last(&inputs[a], &inputs[b], SortDirection::Ascending)
+            });
+            indices
+        };
+        let lhs = sorted(self.data.iter().map(|point| point.input).collect());
+        let rhs = sorted(other.data.iter().map(|point| point.input).collect());
+
+        let mut data = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < lhs.len() && j < rhs.len() {
+            let (lhs_point, rhs_point) = (&self.data[lhs[i]], &other.data[rhs[j]]);
+            if rhs_point.input < lhs_point.input - tolerance {
+                j += 1;
+            } else if rhs_point.input > lhs_point.input + tolerance {
+                i += 1;
+            } else {
+                data.push(DataPoint {
+                    input: lhs_point.input,
+                    output: (lhs_point.output.clone(), rhs_point.output.clone()),
+                });
+                i += 1;
+                j += 1;
+            }
+        }
+
+        if data.is_empty() {
+            return Err(DataSetError::Empty);
+        }
+        return Ok(DataSet { data });
+    }
+}
+
+impl<I, O> DataSet<I, O>
 where
-    T: FromStr,
-    T::Err: std::error::Error + 'static,
+    I: FromStr,
+    I::Err: std::error::Error + 'static,
+    O: FromStr,
+    O::Err: std::error::Error + 'static,
 {
     /// - Must be only 2 columns
     /// - leftmost column is input values
@@ -62,10 +178,23 @@ where
     }
 }
 
-impl<T> FromStr for DataSet<T>
+impl<I: Display, O: Display> DataSet<I, O> {
+    /// Write one `input, output` pair per line, the format [DataSet::from_csv] reads
+    pub fn to_csv_string(&self) -> String {
+        let mut csv = String::new();
+        for data_point in self.data.iter() {
+            csv.push_str(&format!("{}, {}\n", data_point.input, data_point.output));
+        }
+        return csv;
+    }
+}
+
+impl<I, O> FromStr for DataSet<I, O>
 where
-    T: FromStr,
-    T::Err: std::error::Error + 'static,
+    I: FromStr,
+    I::Err: std::error::Error + 'static,
+    O: FromStr,
+    O::Err: std::error::Error + 'static,
 {
     type Err = ParseDataSetError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -78,7 +207,7 @@ where
                 .next()
                 .ok_or_else(|| ParseDataSetError::missing_input(line_index + 1))?
                 .trim();
-            let input = input.parse::<T>().map_err(|parse_error| {
+            let input = input.parse::<I>().map_err(|parse_error| {
                 ParseDataSetError::parse_value_error(line_index + 1, parse_error, input.to_owned())
             })?;
 
@@ -86,7 +215,7 @@ where
                 .next()
                 .ok_or_else(|| ParseDataSetError::missing_output(line_index + 1))?
                 .trim();
-            let output = output.parse::<T>().map_err(|parse_error| {
+            let output = output.parse::<O>().map_err(|parse_error| {
                 ParseDataSetError::parse_value_error(line_index + 1, parse_error, output.to_owned())
             })?;
 
@@ -99,26 +228,30 @@ where
             data.push(data_point);
         }
 
+        if data.is_empty() {
+            return Err(ParseDataSetError::empty(s.lines().count() + 1));
+        }
+
         return Ok(Self { data });
     }
 }
 
-impl<T: Copy, const N: usize> TryFrom<[(T, T); N]> for DataSet<T> {
+impl<I: Copy, O: Copy, const N: usize> TryFrom<[(I, O); N]> for DataSet<I, O> {
     type Error = DataSetError;
-    fn try_from(value: [(T, T); N]) -> Result<Self, Self::Error> {
+    fn try_from(value: [(I, O); N]) -> Result<Self, Self::Error> {
         TryFrom::try_from(value.as_slice())
     }
 }
 
-impl<T: Copy> TryFrom<&[(T, T)]> for DataSet<T> {
+impl<I: Copy, O: Copy> TryFrom<&[(I, O)]> for DataSet<I, O> {
     type Error = DataSetError;
-    fn try_from(value: &[(T, T)]) -> Result<Self, Self::Error> {
-        if value.len() == 0 {
+    fn try_from(value: &[(I, O)]) -> Result<Self, Self::Error> {
+        if value.is_empty() {
             return Err(DataSetError::Empty);
         }
 
         let data = value
-            .into_iter()
+            .iter()
             .map(|&(input, output)| DataPoint { input, output })
             .collect();
 
@@ -126,10 +259,10 @@ impl<T: Copy> TryFrom<&[(T, T)]> for DataSet<T> {
     }
 }
 
-impl<T: Copy, const N: usize> TryFrom<([T; N], [T; N])> for DataSet<T> {
+impl<I: Copy, O: Copy, const N: usize> TryFrom<([I; N], [O; N])> for DataSet<I, O> {
     type Error = DataSetError;
 
-    fn try_from((inputs, outputs): ([T; N], [T; N])) -> Result<Self, Self::Error> {
+    fn try_from((inputs, outputs): ([I; N], [O; N])) -> Result<Self, Self::Error> {
         if N == 0 {
             return Err(DataSetError::Empty);
         }
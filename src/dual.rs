@@ -0,0 +1,382 @@
+use std::{
+    cmp::Ordering,
+    num::FpCategory,
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+};
+
+use num::{Float, Num, NumCast, One, ToPrimitive, Zero};
+
+/// A dual number `value + derivative * ε` where `ε²` == `0` <br>
+/// Running any computation on duals carries the derivative along with the value (forward mode
+/// automatic differentiation), so a matrix expression or regression loss written for a generic
+/// [Float] can be differentiated without hand coded derivatives.
+/// Comparisons and [Zero::is_zero] only look at the value so algorithms branch exactly as they would on `F`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Dual<F> {
+    value: F,
+    derivative: F,
+}
+impl<F: Float> Dual<F> {
+    pub fn new(value: F, derivative: F) -> Self {
+        return Dual { value, derivative };
+    }
+    /// A value that does not depend on the variable, its derivative is `0`
+    pub fn constant(value: F) -> Self {
+        return Dual::new(value, F::zero());
+    }
+    /// The variable being differentiated with respect to, its derivative is `1`
+    pub fn variable(value: F) -> Self {
+        return Dual::new(value, F::one());
+    }
+    pub fn value(&self) -> F {
+        return self.value;
+    }
+    pub fn derivative(&self) -> F {
+        return self.derivative;
+    }
+
+    /// Apply a function with value `value` and derivative `slope` at `self.value` by the chain rule
+    fn chain(self, value: F, slope: F) -> Self {
+        return Dual::new(value, slope * self.derivative);
+    }
+}
+
+/// The derivative of `f` at `x`
+pub fn derivative<F: Float>(f: impl FnOnce(Dual<F>) -> Dual<F>, x: F) -> F {
+    return f(Dual::variable(x)).derivative();
+}
+
+/// The gradient of `f` at `x`, one forward pass per coordinate
+pub fn gradient<F: Float>(f: impl Fn(&[Dual<F>]) -> Dual<F>, x: &[F]) -> Vec<F> {
+    let mut point = x.iter().map(|&x| Dual::constant(x)).collect::<Vec<_>>();
+    return (0..x.len())
+        .map(|coordinate| {
+            point[coordinate] = Dual::variable(x[coordinate]);
+            let partial = f(&point).derivative();
+            point[coordinate] = Dual::constant(x[coordinate]);
+            partial
+        })
+        .collect();
+}
+
+impl<F: Float> From<F> for Dual<F> {
+    fn from(value: F) -> Self {
+        return Dual::constant(value);
+    }
+}
+
+impl<F: PartialEq> PartialEq for Dual<F> {
+    fn eq(&self, other: &Self) -> bool {
+        return self.value == other.value;
+    }
+}
+
+impl<F: PartialOrd> PartialOrd for Dual<F> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return self.value.partial_cmp(&other.value);
+    }
+}
+
+impl<F: Float> Add for Dual<F> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        return Dual::new(self.value + rhs.value, self.derivative + rhs.derivative);
+    }
+}
+
+impl<F: Float> Sub for Dual<F> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        return Dual::new(self.value - rhs.value, self.derivative - rhs.derivative);
+    }
+}
+
+impl<F: Float> Mul for Dual<F> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        return Dual::new(
+            self.value * rhs.value,
+            self.derivative * rhs.value + self.value * rhs.derivative,
+        );
+    }
+}
+
+impl<F: Float> Div for Dual<F> {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self::Output {
+        return Dual::new(
+            self.value / rhs.value,
+            (self.derivative * rhs.value - self.value * rhs.derivative) / (rhs.value * rhs.value),
+        );
+    }
+}
+
+impl<F: Float> Rem for Dual<F> {
+    type Output = Self;
+    fn rem(self, rhs: Self) -> Self::Output {
+        let quotient = (self.value / rhs.value).trunc();
+        return Dual::new(
+            self.value % rhs.value,
+            self.derivative - quotient * rhs.derivative,
+        );
+    }
+}
+
+impl<F: Float> Neg for Dual<F> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        return Dual::new(-self.value, -self.derivative);
+    }
+}
+
+impl<F: Float> Zero for Dual<F> {
+    fn zero() -> Self {
+        return Dual::constant(F::zero());
+    }
+    fn is_zero(&self) -> bool {
+        return self.value.is_zero();
+    }
+}
+
+impl<F: Float> One for Dual<F> {
+    fn one() -> Self {
+        return Dual::constant(F::one());
+    }
+}
+
+impl<F: Float> Num for Dual<F> {
+    type FromStrRadixErr = F::FromStrRadixErr;
+    /// Parses a constant
+    fn from_str_radix(text: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        return F::from_str_radix(text, radix).map(Dual::constant);
+    }
+}
+
+impl<F: Float> ToPrimitive for Dual<F> {
+    fn to_i64(&self) -> Option<i64> {
+        return self.value.to_i64();
+    }
+    fn to_u64(&self) -> Option<u64> {
+        return self.value.to_u64();
+    }
+    fn to_f64(&self) -> Option<f64> {
+        return self.value.to_f64();
+    }
+}
+
+impl<F: Float> NumCast for Dual<F> {
+    fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+        return <F as NumCast>::from(n).map(Dual::constant);
+    }
+}
+
+/// Constants of `F` as [Dual::constant]s
+macro_rules! constants {
+    ($($method:ident),* $(,)?) => {
+        $(fn $method() -> Self { return Dual::constant(F::$method()); })*
+    };
+}
+/// Properties of the value
+macro_rules! properties {
+    ($($method:ident -> $output:ty),* $(,)?) => {
+        $(fn $method(self) -> $output { return self.value.$method(); })*
+    };
+}
+/// Step functions, their derivative is `0` almost everywhere
+macro_rules! steps {
+    ($($method:ident),* $(,)?) => {
+        $(fn $method(self) -> Self { return Dual::constant(self.value.$method()); })*
+    };
+}
+
+impl<F: Float> Float for Dual<F> {
+    constants!(
+        nan,
+        infinity,
+        neg_infinity,
+        neg_zero,
+        min_value,
+        min_positive_value,
+        max_value,
+        epsilon
+    );
+    properties!(
+        is_nan -> bool,
+        is_infinite -> bool,
+        is_finite -> bool,
+        is_normal -> bool,
+        classify -> FpCategory,
+        is_sign_positive -> bool,
+        is_sign_negative -> bool,
+        integer_decode -> (u64, i16, i8),
+    );
+    steps!(floor, ceil, round, trunc, signum);
+
+    fn fract(self) -> Self {
+        return self.chain(self.value.fract(), F::one());
+    }
+    fn abs(self) -> Self {
+        return self.chain(self.value.abs(), self.value.signum());
+    }
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        return self * a + b;
+    }
+    fn recip(self) -> Self {
+        return self.chain(self.value.recip(), -(self.value * self.value).recip());
+    }
+    fn powi(self, n: i32) -> Self {
+        // x⁰ is constant even at 0, where n * xⁿ⁻¹ would be 0 * ∞
+        if n == 0 {
+            return self.chain(F::one(), F::zero());
+        }
+        let n_float = F::from(n).expect("i32 fits in a float");
+        // powf so that n - 1 can not overflow for i32::MIN
+        return self.chain(
+            self.value.powi(n),
+            n_float * self.value.powf(n_float - F::one()),
+        );
+    }
+    fn powf(self, n: Self) -> Self {
+        let power = self.value.powf(n.value);
+        let exponent_term = if n.derivative.is_zero() {
+            F::zero()
+        } else {
+            power * self.value.ln() * n.derivative
+        };
+        return Dual::new(
+            power,
+            n.value * self.value.powf(n.value - F::one()) * self.derivative + exponent_term,
+        );
+    }
+    fn sqrt(self) -> Self {
+        let root = self.value.sqrt();
+        return self.chain(root, (root + root).recip());
+    }
+    fn exp(self) -> Self {
+        let exponential = self.value.exp();
+        return self.chain(exponential, exponential);
+    }
+    fn exp2(self) -> Self {
+        let power = self.value.exp2();
+        return self.chain(power, power * F::from(2).expect("2 is a float").ln());
+    }
+    fn ln(self) -> Self {
+        return self.chain(self.value.ln(), self.value.recip());
+    }
+    fn log(self, base: Self) -> Self {
+        return self.ln() / base.ln();
+    }
+    fn log2(self) -> Self {
+        let ln_2 = F::from(2).expect("2 is a float").ln();
+        return self.chain(self.value.log2(), (self.value * ln_2).recip());
+    }
+    fn log10(self) -> Self {
+        let ln_10 = F::from(10).expect("10 is a float").ln();
+        return self.chain(self.value.log10(), (self.value * ln_10).recip());
+    }
+    fn max(self, other: Self) -> Self {
+        return if other.value > self.value || self.value.is_nan() {
+            other
+        } else {
+            self
+        };
+    }
+    fn min(self, other: Self) -> Self {
+        return if other.value < self.value || self.value.is_nan() {
+            other
+        } else {
+            self
+        };
+    }
+    fn abs_sub(self, other: Self) -> Self {
+        return if self.value > other.value {
+            self - other
+        } else {
+            Dual::zero()
+        };
+    }
+    fn cbrt(self) -> Self {
+        let root = self.value.cbrt();
+        let three = F::from(3).expect("3 is a float");
+        return self.chain(root, (three * root * root).recip());
+    }
+    fn hypot(self, other: Self) -> Self {
+        let length = self.value.hypot(other.value);
+        return Dual::new(
+            length,
+            (self.value * self.derivative + other.value * other.derivative) / length,
+        );
+    }
+    fn sin(self) -> Self {
+        return self.chain(self.value.sin(), self.value.cos());
+    }
+    fn cos(self) -> Self {
+        return self.chain(self.value.cos(), -self.value.sin());
+    }
+    fn tan(self) -> Self {
+        let tangent = self.value.tan();
+        return self.chain(tangent, F::one() + tangent * tangent);
+    }
+    fn asin(self) -> Self {
+        return self.chain(
+            self.value.asin(),
+            (F::one() - self.value * self.value).sqrt().recip(),
+        );
+    }
+    fn acos(self) -> Self {
+        return self.chain(
+            self.value.acos(),
+            -(F::one() - self.value * self.value).sqrt().recip(),
+        );
+    }
+    fn atan(self) -> Self {
+        return self.chain(
+            self.value.atan(),
+            (F::one() + self.value * self.value).recip(),
+        );
+    }
+    fn atan2(self, other: Self) -> Self {
+        let squared_length = self.value * self.value + other.value * other.value;
+        return Dual::new(
+            self.value.atan2(other.value),
+            (other.value * self.derivative - self.value * other.derivative) / squared_length,
+        );
+    }
+    fn sin_cos(self) -> (Self, Self) {
+        return (self.sin(), self.cos());
+    }
+    fn exp_m1(self) -> Self {
+        return self.chain(self.value.exp_m1(), self.value.exp());
+    }
+    fn ln_1p(self) -> Self {
+        return self.chain(self.value.ln_1p(), (F::one() + self.value).recip());
+    }
+    fn sinh(self) -> Self {
+        return self.chain(self.value.sinh(), self.value.cosh());
+    }
+    fn cosh(self) -> Self {
+        return self.chain(self.value.cosh(), self.value.sinh());
+    }
+    fn tanh(self) -> Self {
+        let tangent = self.value.tanh();
+        return self.chain(tangent, F::one() - tangent * tangent);
+    }
+    fn asinh(self) -> Self {
+        return self.chain(
+            self.value.asinh(),
+            (self.value * self.value + F::one()).sqrt().recip(),
+        );
+    }
+    fn acosh(self) -> Self {
+        return self.chain(
+            self.value.acosh(),
+            (self.value * self.value - F::one()).sqrt().recip(),
+        );
+    }
+    fn atanh(self) -> Self {
+        return self.chain(
+            self.value.atanh(),
+            (F::one() - self.value * self.value).recip(),
+        );
+    }
+}
@@ -1,4 +1,11 @@
 pub mod data_set_error;
+pub mod expression_error;
 pub mod matrix_error;
+pub mod model_error;
+pub mod parse_matrix_error;
+pub mod workspace_error;
 
-pub use self::{data_set_error::*, matrix_error::*};
+pub use self::{
+    data_set_error::*, expression_error::*, matrix_error::*, model_error::*, parse_matrix_error::*,
+    workspace_error::*,
+};
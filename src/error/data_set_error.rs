@@ -37,6 +37,12 @@ impl ParseDataSetError {
             line_number,
         };
     }
+    pub fn empty(line_number: usize) -> Self {
+        return Self {
+            kind: ParseDataSetErrorKind::Empty,
+            line_number,
+        };
+    }
     pub fn parse_value_error<E: std::error::Error + 'static>(
         line_number: usize,
         parse_error: E,
@@ -63,9 +69,25 @@ pub enum ParseDataSetErrorKind {
     #[error("There are too many columns for one (input, output) pair")]
     TooManyColumns,
 
+    #[error("There are no (input, output) pairs")]
+    Empty,
+
     #[error("Could not parse {unparsed_value} because {parse_error}")]
     ParseValueError {
         parse_error: Box<dyn std::error::Error>,
         unparsed_value: String,
     },
 }
+
+#[derive(Debug, Error)]
+#[error("{value:?} is not unix seconds, a date like 2024-01-31, or a date and time like 2024-01-31T13:45:00Z")]
+pub struct ParseTimestampError {
+    value: String,
+}
+impl ParseTimestampError {
+    pub fn new(value: &str) -> Self {
+        return Self {
+            value: value.to_owned(),
+        };
+    }
+}
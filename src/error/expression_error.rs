@@ -0,0 +1,36 @@
+use thiserror::Error;
+
+use crate::MatrixError;
+
+#[derive(Debug, Error)]
+pub enum ExpressionError {
+    #[error("Unexpected character {character:?} at position {position}")]
+    UnexpectedCharacter { character: char, position: usize },
+
+    #[error("Unexpected {token:?} at position {position}")]
+    UnexpectedToken { token: String, position: usize },
+
+    #[error("More than {limit} levels of nesting at position {position}")]
+    TooDeeplyNested { limit: usize, position: usize },
+
+    #[error("The expression ended early")]
+    UnexpectedEnd,
+
+    #[error("{0} is not defined")]
+    Undefined(String),
+
+    #[error("{0:?} is not a known function")]
+    UnknownFunction(String),
+
+    #[error("{function} needs a matrix argument")]
+    ExpectedMatrix { function: &'static str },
+
+    #[error("Cannot add a scalar and a matrix")]
+    ScalarMatrixAddition,
+
+    #[error("The size of I can not be inferred from the expression")]
+    UnsizedIdentity,
+
+    #[error("{0}")]
+    Matrix(#[from] MatrixError),
+}
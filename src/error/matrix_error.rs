@@ -1,9 +1,12 @@
 use std::ops::Neg;
 
-use num::Num;
+use num::{Float, Num};
 use thiserror::Error;
 
-use crate::{matrix::MatrixIndex, Matrix};
+use crate::{
+    matrix::{MatrixIndex, MatrixOperand},
+    Matrix, Shape,
+};
 
 #[derive(Debug, Error)]
 pub enum MatrixError {
@@ -24,6 +27,76 @@ pub enum MatrixError {
 
     #[error("Cannot create matrix because {0}")]
     DimensionError(#[from] DimensionError),
+
+    #[error("Cannot decompose matrix because {0}")]
+    Decomposition(#[from] DecompositionError),
+
+    #[error("Cannot cast the element at row {row}, column {column} to the target type")]
+    Cast { row: usize, column: usize },
+
+    #[error("Cannot use a vector with zero length as a direction")]
+    ZeroVector,
+
+    #[error("Index {index} is out of bounds for a dimension of length {length}")]
+    IndexOutOfBounds { index: usize, length: usize },
+
+    #[error("Index (row {row}, column {column}) is out of bounds for a {shape} matrix")]
+    ElementOutOfBounds {
+        row: usize,
+        column: usize,
+        shape: Shape,
+    },
+
+    #[error("An intermediate value overflowed the element type")]
+    Overflow,
+
+    #[error("Cannot use {0} because it is NaN or infinite")]
+    NotFinite(&'static str),
+
+    #[error("Cannot use {0} because it is not invertible")]
+    NotInvertible(&'static str),
+
+    #[error("Row {row} has {actual} elements but the rows before it have {expected}")]
+    InconsistentRow {
+        row: usize,
+        expected: usize,
+        actual: usize,
+    },
+
+    #[error(
+        "Cannot {operation} on a {shape} matrix with {actual} elements, {expected} were expected"
+    )]
+    LengthMismatch {
+        operation: &'static str,
+        shape: Shape,
+        expected: usize,
+        actual: usize,
+    },
+}
+impl MatrixError {
+    /// The [Shape] of the matrix the failing operation was called on (the lhs of a binary operation) <br>
+    /// [None] if the error does not know it.
+    pub fn shape(&self) -> Option<Shape> {
+        return match self {
+            MatrixError::Arithmetic {
+                dimension_error, ..
+            } => dimension_error.lhs_shape(),
+            MatrixError::ElementOutOfBounds { shape, .. }
+            | MatrixError::LengthMismatch { shape, .. } => Some(*shape),
+            _ => None,
+        };
+    }
+
+    /// The [Shape] of the rhs of a failing binary operation <br>
+    /// [None] if the error was not caused by a binary operation.
+    pub fn rhs_shape(&self) -> Option<Shape> {
+        return match self {
+            MatrixError::Arithmetic {
+                dimension_error, ..
+            } => dimension_error.rhs_shape(),
+            _ => None,
+        };
+    }
 }
 impl MatrixError {
     /// Check if two matrices can be multiplied <br>
@@ -36,13 +109,16 @@ impl MatrixError {
     /// ## Errors
     /// - [MatrixError::Arithmetic]
     ///   - if `lhs.width` != `rhs.height`
-    pub fn multiplication<E>(lhs: &Matrix<E>, rhs: &Matrix<E>) -> Result<(), Self> {
-        return if lhs.width() != rhs.height() {
+    pub fn multiplication<E>(
+        lhs: &impl MatrixOperand<E>,
+        rhs: &impl MatrixOperand<E>,
+    ) -> Result<(), Self> {
+        return if !lhs.shape().can_multiply(&rhs.shape()) {
             Err(MatrixError::Arithmetic {
                 operation: ArithmeticOperation::Multiplication,
                 dimension_error: DimensionError::LhsWidthNotEqualToRhsHeight {
-                    lhs_width: lhs.width(),
-                    rhs_height: rhs.height(),
+                    lhs: lhs.shape(),
+                    rhs: rhs.shape(),
                 },
             })
         } else {
@@ -61,14 +137,12 @@ impl MatrixError {
     /// - [MatrixError::Arithmetic]
     ///   - if `lhs` and `rhs` have different dimensions
     pub fn hadamard_product<E>(lhs: &Matrix<E>, rhs: &Matrix<E>) -> Result<(), Self> {
-        return if lhs.width() != rhs.width() || lhs.height() != rhs.height() {
+        return if lhs.shape() != rhs.shape() {
             Err(MatrixError::Arithmetic {
                 operation: ArithmeticOperation::HadamardProduct,
                 dimension_error: DimensionError::DifferentDimensions {
-                    lhs_width: lhs.width(),
-                    lhs_height: lhs.height(),
-                    rhs_width: rhs.width(),
-                    rhs_height: rhs.height(),
+                    lhs: lhs.shape(),
+                    rhs: rhs.shape(),
                 },
             })
         } else {
@@ -87,14 +161,12 @@ impl MatrixError {
     /// - [MatrixError::Arithmetic]
     ///   - if `lhs` and `rhs` have different dimensions
     pub fn addition<E>(lhs: &Matrix<E>, rhs: &Matrix<E>) -> Result<(), Self> {
-        return if lhs.width() != rhs.width() || lhs.height() != rhs.height() {
+        return if lhs.shape() != rhs.shape() {
             Err(MatrixError::Arithmetic {
                 operation: ArithmeticOperation::Addition,
                 dimension_error: DimensionError::DifferentDimensions {
-                    lhs_width: lhs.width(),
-                    lhs_height: lhs.height(),
-                    rhs_width: rhs.width(),
-                    rhs_height: rhs.height(),
+                    lhs: lhs.shape(),
+                    rhs: rhs.shape(),
                 },
             })
         } else {
@@ -169,7 +241,7 @@ impl MatrixError {
     ///   - if either dimension of `matrix` is `0`
     ///   - if `matrix` is not square
     ///   - if the determinant of `matrix` is `0`
-    pub fn inverse<E: Num + Neg<Output = E> + Copy>(matrix: &Matrix<E>) -> Result<(), Self> {
+    pub fn inverse<E: Num + Neg<Output = E> + Clone>(matrix: &Matrix<E>) -> Result<(), Self> {
         return if matrix.width() == 0 || matrix.height() == 0 {
             Err(MatrixError::Inverse(InverseError::DimensionError(
                 DimensionError::Zero,
@@ -186,6 +258,35 @@ impl MatrixError {
     }
 }
 
+impl MatrixError {
+    /// Use this to check if a matrix has a symmetric eigendecomposition <br>
+    /// ## Parameters
+    /// - `matrix`: Matrix to decompose.
+    /// ## Returns
+    /// - <b>UnitType `()`</b>
+    ///   - if `matrix` is square and symmetric
+    /// ## Errors
+    /// - [MatrixError::Decomposition]
+    ///   - if `matrix` is not square
+    ///   - if `matrix[i][j]` differs from `matrix[j][i]` by more than a small relative tolerance
+    pub fn symmetric_eigen<F: Float>(matrix: &Matrix<F>) -> Result<(), Self> {
+        if matrix.width() != matrix.height() {
+            Err(DecompositionError::from(DimensionError::NotSquare))?
+        }
+
+        let tolerance = F::epsilon().sqrt();
+        for (index, &element) in matrix.elements_enumerated() {
+            let mirrored = matrix[index.transpose()];
+            let scale = element.abs().max(mirrored.abs()).max(F::one());
+            if (element - mirrored).abs() > tolerance * scale {
+                Err(DecompositionError::NotSymmetric)?
+            }
+        }
+
+        return Ok(());
+    }
+}
+
 #[derive(Debug, Error)]
 pub enum ArithmeticOperation {
     #[error("Matrix Addition")]
@@ -198,16 +299,13 @@ pub enum ArithmeticOperation {
 
 #[derive(Debug, Error)]
 pub enum DimensionError {
-    #[error("the matrices are not the same size (lhs: {lhs_height}x{lhs_width}, rhs: {rhs_height}x{rhs_width})")]
-    DifferentDimensions {
-        lhs_width: usize,
-        lhs_height: usize,
-        rhs_width: usize,
-        rhs_height: usize,
-    },
+    #[error("the matrices are not the same size (lhs: {lhs}, rhs: {rhs})")]
+    DifferentDimensions { lhs: Shape, rhs: Shape },
 
-    #[error("the width of lhs matrix does not equal the height of rhs matrix (lhs width: {lhs_width}, rhs height: {rhs_height})")]
-    LhsWidthNotEqualToRhsHeight { lhs_width: usize, rhs_height: usize },
+    #[error(
+        "the width of lhs matrix does not equal the height of rhs matrix (lhs: {lhs}, rhs: {rhs})"
+    )]
+    LhsWidthNotEqualToRhsHeight { lhs: Shape, rhs: Shape },
 
     #[error("the matrix is not square")]
     NotSquare,
@@ -217,6 +315,34 @@ pub enum DimensionError {
 
     #[error("the matrix has 0 size")]
     Zero,
+
+    #[error("the matrix is {actual} but {expected} columns were expected")]
+    UnexpectedWidth { expected: usize, actual: Shape },
+
+    #[error("the matrix is {actual} but {expected} rows were expected")]
+    UnexpectedHeight { expected: usize, actual: Shape },
+
+    #[error("the vector has {actual} elements but {expected} were expected")]
+    UnexpectedLength { expected: usize, actual: usize },
+}
+
+impl DimensionError {
+    /// The [Shape] of the lhs matrix if this error compares two matrices
+    pub fn lhs_shape(&self) -> Option<Shape> {
+        return match self {
+            DimensionError::DifferentDimensions { lhs, .. }
+            | DimensionError::LhsWidthNotEqualToRhsHeight { lhs, .. } => Some(*lhs),
+            _ => None,
+        };
+    }
+    /// The [Shape] of the rhs matrix if this error compares two matrices
+    pub fn rhs_shape(&self) -> Option<Shape> {
+        return match self {
+            DimensionError::DifferentDimensions { rhs, .. }
+            | DimensionError::LhsWidthNotEqualToRhsHeight { rhs, .. } => Some(*rhs),
+            _ => None,
+        };
+    }
 }
 
 #[derive(Debug, Error)]
@@ -253,3 +379,27 @@ pub enum InverseError {
     #[error("{0}")]
     DimensionError(#[from] DimensionError),
 }
+
+#[derive(Debug, Error)]
+pub enum DecompositionError {
+    #[error("the matrix is not symmetric")]
+    NotSymmetric,
+
+    #[error("the matrix is not positive semidefinite")]
+    NotPositiveSemidefinite,
+
+    #[error("the matrix is not positive definite")]
+    NotPositiveDefinite,
+
+    #[error("the matrix is singular")]
+    Singular,
+
+    #[error("the algorithm did not converge after {0} iterations")]
+    NoConvergence(usize),
+
+    #[error("the matrix has an eigenvalue on the closed negative real axis so it has no real principal logarithm")]
+    NoPrincipalLogarithm,
+
+    #[error("{0}")]
+    DimensionError(#[from] DimensionError),
+}
@@ -0,0 +1,40 @@
+use std::path::PathBuf;
+
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ModelError {
+    #[error("Failed to access {path}: {source}")]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+
+    #[error("The text does not start with the `matrix-model <version>` header")]
+    MissingHeader,
+
+    #[error("Model format version {0} is not supported, the latest is {latest}", latest = crate::MODEL_FORMAT_VERSION)]
+    UnsupportedVersion(String),
+
+    #[error("The model has no {0} field")]
+    MissingField(&'static str),
+
+    #[error("{value:?} is not a valid value for the {field} field")]
+    InvalidField { field: String, value: String },
+
+    #[error("The {0} field appears more than once")]
+    DuplicateField(String),
+
+    #[error("{0:?} is not a field of the {basis} basis", basis = .1)]
+    UnknownField(String, &'static str),
+
+    #[error("{0:?} is not a known basis")]
+    UnknownBasis(String),
+
+    #[error("The {basis} basis needs {expected} coefficients but there are {actual}")]
+    CoefficientCount {
+        basis: &'static str,
+        expected: usize,
+        actual: usize,
+    },
+}
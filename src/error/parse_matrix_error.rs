@@ -0,0 +1,64 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+#[error("Could not parse Matrix because {kind} on line {line_number}")]
+pub struct ParseMatrixError {
+    kind: ParseMatrixErrorKind,
+    line_number: usize,
+}
+impl ParseMatrixError {
+    pub fn kind(&self) -> &ParseMatrixErrorKind {
+        return &self.kind;
+    }
+    pub fn line_number(&self) -> usize {
+        return self.line_number;
+    }
+    pub fn invalid_header(line_number: usize, header: String) -> Self {
+        return Self {
+            kind: ParseMatrixErrorKind::InvalidHeader(header),
+            line_number,
+        };
+    }
+    pub fn unexpected_row_count(line_number: usize, expected: usize, actual: usize) -> Self {
+        return Self {
+            kind: ParseMatrixErrorKind::UnexpectedRowCount { expected, actual },
+            line_number,
+        };
+    }
+    pub fn unexpected_column_count(line_number: usize, expected: usize, actual: usize) -> Self {
+        return Self {
+            kind: ParseMatrixErrorKind::UnexpectedColumnCount { expected, actual },
+            line_number,
+        };
+    }
+    pub fn empty(line_number: usize) -> Self {
+        return Self {
+            kind: ParseMatrixErrorKind::Empty,
+            line_number,
+        };
+    }
+    pub fn invalid_element(line_number: usize, element: String) -> Self {
+        return Self {
+            kind: ParseMatrixErrorKind::InvalidElement(element),
+            line_number,
+        };
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ParseMatrixErrorKind {
+    #[error("the header {0:?} is not `<height>x<width>` with non zero dimensions")]
+    InvalidHeader(String),
+
+    #[error("there are {actual} rows but the header says {expected}")]
+    UnexpectedRowCount { expected: usize, actual: usize },
+
+    #[error("there are {actual} elements but the header says {expected}")]
+    UnexpectedColumnCount { expected: usize, actual: usize },
+
+    #[error("{0:?} is not a valid element")]
+    InvalidElement(String),
+
+    #[error("there are no rows")]
+    Empty,
+}
@@ -0,0 +1,29 @@
+use std::path::PathBuf;
+
+use thiserror::Error;
+
+use crate::{ParseDataSetError, ParseMatrixError};
+
+#[derive(Debug, Error)]
+pub enum WorkspaceError {
+    #[error("{0:?} is not a valid name, use letters, digits, and underscores")]
+    InvalidName(String),
+
+    #[error("Failed to access {path}: {source}")]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+
+    #[error("Failed to read the matrix in {path}: {source}")]
+    ParseMatrix {
+        path: PathBuf,
+        source: ParseMatrixError,
+    },
+
+    #[error("Failed to read the data set in {path}: {source}")]
+    ParseDataSet {
+        path: PathBuf,
+        source: ParseDataSetError,
+    },
+}
@@ -0,0 +1,461 @@
+use std::collections::HashMap;
+
+use num::Float;
+
+use crate::{ExpressionError, Matrix};
+
+/// A function that can be called in an [Expression]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Function {
+    /// `inv(m)`
+    Inverse,
+    /// `det(m)`
+    Determinant,
+    /// `t(m)`, the same as `m'`
+    Transpose,
+}
+impl Function {
+    pub fn name(&self) -> &'static str {
+        return match self {
+            Function::Inverse => "inv",
+            Function::Determinant => "det",
+            Function::Transpose => "t",
+        };
+    }
+    fn from_name(name: &str) -> Option<Self> {
+        return [
+            Function::Inverse,
+            Function::Determinant,
+            Function::Transpose,
+        ]
+        .into_iter()
+        .find(|function| function.name() == name);
+    }
+}
+
+/// A parsed expression tree over [Matrix] values <br>
+/// The grammar, loosest binding first, is
+/// ```text
+/// sum     = product (("+" | "-") product)*
+/// product = unary ("*" unary)*
+/// unary   = "-" unary | postfix
+/// postfix = primary "'"*
+/// primary = number | name | function "(" sum ")" | "(" sum ")"
+/// ```
+/// where `'` is the transpose. The name `I` is the identity, sized to match whatever it is
+/// added to or multiplied with, unless the bindings define `I`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expression<F> {
+    Number(F),
+    Variable(String),
+    Negate(Box<Self>),
+    Add(Box<Self>, Box<Self>),
+    Subtract(Box<Self>, Box<Self>),
+    Multiply(Box<Self>, Box<Self>),
+    Transpose(Box<Self>),
+    Call(Function, Box<Self>),
+}
+
+/// The result of evaluating an [Expression]
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExpressionValue<F> {
+    Scalar(F),
+    Matrix(Matrix<F>),
+}
+impl<F> From<Matrix<F>> for ExpressionValue<F> {
+    fn from(matrix: Matrix<F>) -> Self {
+        return ExpressionValue::Matrix(matrix);
+    }
+}
+impl<F: Copy> ExpressionValue<F> {
+    pub fn as_scalar(&self) -> Option<F> {
+        return match self {
+            ExpressionValue::Scalar(scalar) => Some(*scalar),
+            ExpressionValue::Matrix(_) => None,
+        };
+    }
+    pub fn as_matrix(&self) -> Option<&Matrix<F>> {
+        return match self {
+            ExpressionValue::Scalar(_) => None,
+            ExpressionValue::Matrix(matrix) => Some(matrix),
+        };
+    }
+}
+
+/// Where an [Expression] looks up the values of its variables <br>
+/// Implemented for maps from names to [Matrix] or [ExpressionValue], and for [crate::Workspace].
+pub trait ExpressionBindings<F> {
+    /// The value of the variable `name`, or [None] if it is not bound
+    fn binding(&self, name: &str) -> Option<ExpressionValue<F>>;
+}
+impl<F, V: Clone + Into<ExpressionValue<F>>> ExpressionBindings<F> for HashMap<String, V> {
+    fn binding(&self, name: &str) -> Option<ExpressionValue<F>> {
+        return self.get(name).cloned().map(Into::into);
+    }
+}
+
+/// Parse `source` as an [Expression] then evaluate it with the values in `bindings`
+/// ## Parameters
+/// - `source`: an expression such as `"A * B' + 2*I"`, see [Expression] for the grammar.
+/// - `bindings`: the value of each variable, see [ExpressionBindings].
+/// ## Errors
+/// - [ExpressionError]
+///   - if `source` does not follow the grammar or calls an unknown function
+///   - if a variable is not in `bindings`
+///   - if an operation is not defined for its operands, like adding a scalar to a matrix
+///   - with the [crate::MatrixError] of an operation that fails, like inverting a singular matrix
+pub fn parse_matrix_expression<F: Float>(
+    source: &str,
+    bindings: &impl ExpressionBindings<F>,
+) -> Result<ExpressionValue<F>, ExpressionError> {
+    return Expression::parse(source)?.evaluate(bindings);
+}
+
+impl<F: Float> Expression<F> {
+    /// Build the tree for `source`, see [Expression] for the grammar
+    /// ## Errors
+    /// - [ExpressionError]
+    ///   - if `source` has a character that is not part of the grammar
+    ///   - if the tokens do not follow the grammar
+    ///   - if a function other than `inv`, `det`, or `t` is called
+    pub fn parse(source: &str) -> Result<Self, ExpressionError> {
+        let tokens = tokenize(source)?;
+        let mut parser = Parser {
+            tokens: &tokens,
+            index: 0,
+            depth: 0,
+        };
+        let expression = parser.sum()?;
+        if let Some((token, position)) = parser.tokens.get(parser.index) {
+            return Err(ExpressionError::UnexpectedToken {
+                token: token.describe(),
+                position: *position,
+            });
+        }
+        return Ok(expression);
+    }
+
+    /// Evaluate the tree with the values in `bindings`
+    /// ## Errors
+    /// - Same as [parse_matrix_expression] other than the parsing errors
+    pub fn evaluate(
+        &self,
+        bindings: &impl ExpressionBindings<F>,
+    ) -> Result<ExpressionValue<F>, ExpressionError> {
+        return match self.value(bindings)? {
+            Value::Scalar(scalar) => Ok(ExpressionValue::Scalar(scalar)),
+            Value::Matrix(matrix) => Ok(ExpressionValue::Matrix(matrix)),
+            Value::Identity(_) => Err(ExpressionError::UnsizedIdentity),
+        };
+    }
+
+    fn value(&self, bindings: &impl ExpressionBindings<F>) -> Result<Value<F>, ExpressionError> {
+        return match self {
+            Expression::Number(number) => Ok(Value::Scalar(*number)),
+            Expression::Variable(name) => match bindings.binding(name) {
+                Some(value) => Ok(match value {
+                    ExpressionValue::Scalar(scalar) => Value::Scalar(scalar),
+                    ExpressionValue::Matrix(matrix) => Value::Matrix(matrix),
+                }),
+                None if name == "I" => Ok(Value::Identity(F::one())),
+                None => Err(ExpressionError::Undefined(name.clone())),
+            },
+            Expression::Negate(operand) => {
+                multiply(Value::Scalar(-F::one()), operand.value(bindings)?)
+            }
+            Expression::Add(lhs, rhs) => add(lhs.value(bindings)?, rhs.value(bindings)?, F::one()),
+            Expression::Subtract(lhs, rhs) => {
+                add(lhs.value(bindings)?, rhs.value(bindings)?, -F::one())
+            }
+            Expression::Multiply(lhs, rhs) => multiply(lhs.value(bindings)?, rhs.value(bindings)?),
+            Expression::Transpose(operand) => call(Function::Transpose, operand.value(bindings)?),
+            Expression::Call(function, argument) => call(*function, argument.value(bindings)?),
+        };
+    }
+}
+
+/// An intermediate value, which unlike [ExpressionValue] can be an identity of unknown size
+enum Value<F> {
+    Scalar(F),
+    Matrix(Matrix<F>),
+    /// `scale * I`
+    Identity(F),
+}
+
+/// `lhs + sign * rhs`
+fn add<F: Float>(lhs: Value<F>, rhs: Value<F>, sign: F) -> Result<Value<F>, ExpressionError> {
+    let sum = match (lhs, rhs) {
+        (Value::Scalar(lhs), Value::Scalar(rhs)) => Value::Scalar(lhs + sign * rhs),
+        (Value::Identity(lhs), Value::Identity(rhs)) => Value::Identity(lhs + sign * rhs),
+        (Value::Matrix(lhs), Value::Matrix(rhs)) => {
+            Value::Matrix(lhs.add(&rhs.scalar_multiply(sign))?)
+        }
+        (Value::Matrix(lhs), Value::Identity(scale)) => {
+            let identity = Matrix::identity(lhs.height_nonzero()).scalar_multiply(sign * scale);
+            Value::Matrix(lhs.add(&identity)?)
+        }
+        (Value::Identity(scale), Value::Matrix(rhs)) => {
+            let identity = Matrix::identity(rhs.height_nonzero()).scalar_multiply(scale);
+            Value::Matrix(identity.add(&rhs.scalar_multiply(sign))?)
+        }
+        (Value::Scalar(_), _) | (_, Value::Scalar(_)) => {
+            return Err(ExpressionError::ScalarMatrixAddition)
+        }
+    };
+    return Ok(sum);
+}
+
+fn multiply<F: Float>(lhs: Value<F>, rhs: Value<F>) -> Result<Value<F>, ExpressionError> {
+    let product = match (lhs, rhs) {
+        (Value::Scalar(lhs), Value::Scalar(rhs)) => Value::Scalar(lhs * rhs),
+        (Value::Matrix(lhs), Value::Matrix(rhs)) => Value::Matrix(lhs.matrix_multiply(&rhs)?),
+        (Value::Scalar(scale) | Value::Identity(scale), Value::Matrix(matrix))
+        | (Value::Matrix(matrix), Value::Scalar(scale) | Value::Identity(scale)) => {
+            Value::Matrix(matrix.scalar_multiply(scale))
+        }
+        (Value::Scalar(lhs) | Value::Identity(lhs), Value::Identity(rhs))
+        | (Value::Identity(lhs), Value::Scalar(rhs)) => Value::Identity(lhs * rhs),
+    };
+    return Ok(product);
+}
+
+fn call<F: Float>(function: Function, argument: Value<F>) -> Result<Value<F>, ExpressionError> {
+    let result = match (function, argument) {
+        (Function::Transpose, Value::Matrix(matrix)) => Value::Matrix(matrix.transpose()),
+        (Function::Inverse, Value::Matrix(matrix)) => Value::Matrix(matrix.inverse()?),
+        (Function::Determinant, Value::Matrix(matrix)) => Value::Scalar(matrix.determinant_lu()?),
+        (Function::Transpose, identity @ Value::Identity(_)) => identity,
+        (Function::Inverse, Value::Identity(scale)) => Value::Identity(scale.recip()),
+        (Function::Determinant, Value::Identity(_)) => {
+            return Err(ExpressionError::UnsizedIdentity)
+        }
+        (Function::Transpose, scalar @ Value::Scalar(_)) => scalar,
+        (function, Value::Scalar(_)) => {
+            return Err(ExpressionError::ExpectedMatrix {
+                function: function.name(),
+            })
+        }
+    };
+    return Ok(result);
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token<F> {
+    Number(F),
+    Name(String),
+    Plus,
+    Minus,
+    Star,
+    Apostrophe,
+    OpenParenthesis,
+    CloseParenthesis,
+}
+impl<F: Float> Token<F> {
+    fn describe(&self) -> String {
+        return match self {
+            Token::Number(number) => format!("{}", number.to_f64().unwrap_or(f64::NAN)),
+            Token::Name(name) => name.clone(),
+            Token::Plus => "+".to_string(),
+            Token::Minus => "-".to_string(),
+            Token::Star => "*".to_string(),
+            Token::Apostrophe => "'".to_string(),
+            Token::OpenParenthesis => "(".to_string(),
+            Token::CloseParenthesis => ")".to_string(),
+        };
+    }
+}
+
+/// Split `source` into tokens, each with the character position it starts at
+fn tokenize<F: Float>(source: &str) -> Result<Vec<(Token<F>, usize)>, ExpressionError> {
+    let characters = source.chars().collect::<Vec<_>>();
+    let mut tokens = Vec::new();
+    let mut position = 0;
+    while let Some(&character) = characters.get(position) {
+        let start = position;
+        let token = match character {
+            _ if character.is_whitespace() => {
+                position += 1;
+                continue;
+            }
+            '+' => Token::Plus,
+            '-' => Token::Minus,
+            '*' => Token::Star,
+            '\'' => Token::Apostrophe,
+            '(' => Token::OpenParenthesis,
+            ')' => Token::CloseParenthesis,
+            '0'..='9' | '.' => {
+                while characters
+                    .get(position)
+                    .is_some_and(|c| c.is_ascii_digit() || *c == '.')
+                {
+                    position += 1;
+                }
+                let text = characters[start..position].iter().collect::<String>();
+                let number = text.parse::<f64>().ok().and_then(F::from).ok_or(
+                    ExpressionError::UnexpectedToken {
+                        token: text,
+                        position: start,
+                    },
+                )?;
+                tokens.push((Token::Number(number), start));
+                continue;
+            }
+            _ if character.is_alphabetic() || character == '_' => {
+                while characters
+                    .get(position)
+                    .is_some_and(|c| c.is_alphanumeric() || *c == '_')
+                {
+                    position += 1;
+                }
+                let name = characters[start..position].iter().collect();
+                tokens.push((Token::Name(name), start));
+                continue;
+            }
+            _ => {
+                return Err(ExpressionError::UnexpectedCharacter {
+                    character,
+                    position,
+                })
+            }
+        };
+        tokens.push((token, start));
+        position += 1;
+    }
+    return Ok(tokens);
+}
+
+/// How deep the tree of an [Expression] can be before [Expression::parse] gives up
+/// instead of overflowing the stack while parsing, evaluating, or dropping it <br>
+/// Every operator, parenthesis, and call is one level,
+/// including each link of a chain like `a + b + c` which nests into its left operand.
+const MAX_NESTING_DEPTH: usize = 256;
+
+/// Recursive descent over the grammar documented on [Expression]
+struct Parser<'a, F> {
+    tokens: &'a [(Token<F>, usize)],
+    index: usize,
+    /// The number of levels of the tree above the current token
+    depth: usize,
+}
+impl<F: Float> Parser<'_, F> {
+    fn peek(&self) -> Option<&Token<F>> {
+        return self.tokens.get(self.index).map(|(token, _)| token);
+    }
+    fn next(&mut self) -> Option<(Token<F>, usize)> {
+        let token = self.tokens.get(self.index).cloned();
+        self.index += 1;
+        return token;
+    }
+    /// Go one level deeper for the operator that was just taken with [Parser::next]
+    /// ## Errors
+    /// - [ExpressionError::TooDeeplyNested]
+    ///   - if the branch is already [MAX_NESTING_DEPTH] levels deep
+    fn descend(&mut self) -> Result<(), ExpressionError> {
+        if self.depth >= MAX_NESTING_DEPTH {
+            return Err(ExpressionError::TooDeeplyNested {
+                limit: MAX_NESTING_DEPTH,
+                position: self.tokens[self.index - 1].1,
+            });
+        }
+        self.depth += 1;
+        return Ok(());
+    }
+    fn expect_close(&mut self) -> Result<(), ExpressionError> {
+        return match self.next() {
+            Some((Token::CloseParenthesis, _)) => Ok(()),
+            Some((token, position)) => Err(ExpressionError::UnexpectedToken {
+                token: token.describe(),
+                position,
+            }),
+            None => Err(ExpressionError::UnexpectedEnd),
+        };
+    }
+
+    fn sum(&mut self) -> Result<Expression<F>, ExpressionError> {
+        let depth = self.depth;
+        let mut expression = self.product()?;
+        loop {
+            expression = match self.peek() {
+                Some(Token::Plus) => {
+                    self.next();
+                    self.descend()?;
+                    Expression::Add(Box::new(expression), Box::new(self.product()?))
+                }
+                Some(Token::Minus) => {
+                    self.next();
+                    self.descend()?;
+                    Expression::Subtract(Box::new(expression), Box::new(self.product()?))
+                }
+                _ => break,
+            };
+        }
+        self.depth = depth;
+        return Ok(expression);
+    }
+
+    fn product(&mut self) -> Result<Expression<F>, ExpressionError> {
+        let depth = self.depth;
+        let mut expression = self.unary()?;
+        while let Some(Token::Star) = self.peek() {
+            self.next();
+            self.descend()?;
+            expression = Expression::Multiply(Box::new(expression), Box::new(self.unary()?));
+        }
+        self.depth = depth;
+        return Ok(expression);
+    }
+
+    fn unary(&mut self) -> Result<Expression<F>, ExpressionError> {
+        if let Some(Token::Minus) = self.peek() {
+            self.next();
+            self.descend()?;
+            let operand = self.unary()?;
+            self.depth -= 1;
+            return Ok(Expression::Negate(Box::new(operand)));
+        }
+        return self.postfix();
+    }
+
+    fn postfix(&mut self) -> Result<Expression<F>, ExpressionError> {
+        let depth = self.depth;
+        let mut expression = self.primary()?;
+        while let Some(Token::Apostrophe) = self.peek() {
+            self.next();
+            self.descend()?;
+            expression = Expression::Transpose(Box::new(expression));
+        }
+        self.depth = depth;
+        return Ok(expression);
+    }
+
+    fn primary(&mut self) -> Result<Expression<F>, ExpressionError> {
+        return match self.next() {
+            Some((Token::Number(number), _)) => Ok(Expression::Number(number)),
+            Some((Token::OpenParenthesis, _)) => {
+                self.descend()?;
+                let expression = self.sum()?;
+                self.expect_close()?;
+                self.depth -= 1;
+                Ok(expression)
+            }
+            Some((Token::Name(name), _)) => {
+                if let Some(Token::OpenParenthesis) = self.peek() {
+                    self.next();
+                    self.descend()?;
+                    let function =
+                        Function::from_name(&name).ok_or(ExpressionError::UnknownFunction(name))?;
+                    let argument = self.sum()?;
+                    self.expect_close()?;
+                    self.depth -= 1;
+                    return Ok(Expression::Call(function, Box::new(argument)));
+                }
+                Ok(Expression::Variable(name))
+            }
+            Some((token, position)) => Err(ExpressionError::UnexpectedToken {
+                token: token.describe(),
+                position,
+            }),
+            None => Err(ExpressionError::UnexpectedEnd),
+        };
+    }
+}
@@ -0,0 +1,252 @@
+use std::{
+    cell::Cell,
+    num::FpCategory,
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+};
+
+use num::{Float, Num, NumCast, One, ToPrimitive, Zero};
+
+thread_local! {
+    static COUNTS: Cell<FlopReport> = const { Cell::new(FlopReport::new()) };
+}
+
+/// How many floating point operations [Counted] elements performed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FlopReport {
+    additions: u64,
+    multiplications: u64,
+    divisions: u64,
+    functions: u64,
+}
+impl FlopReport {
+    const fn new() -> Self {
+        return FlopReport {
+            additions: 0,
+            multiplications: 0,
+            divisions: 0,
+            functions: 0,
+        };
+    }
+    /// Additions and subtractions
+    pub fn additions(&self) -> u64 {
+        return self.additions;
+    }
+    pub fn multiplications(&self) -> u64 {
+        return self.multiplications;
+    }
+    /// Divisions, remainders, and reciprocals
+    pub fn divisions(&self) -> u64 {
+        return self.divisions;
+    }
+    /// Square roots, exponentials, trigonometry, and other elementary functions
+    pub fn functions(&self) -> u64 {
+        return self.functions;
+    }
+    /// Every counted operation
+    pub fn total(&self) -> u64 {
+        return self.additions + self.multiplications + self.divisions + self.functions;
+    }
+}
+impl Add for FlopReport {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        return FlopReport {
+            additions: self.additions + rhs.additions,
+            multiplications: self.multiplications + rhs.multiplications,
+            divisions: self.divisions + rhs.divisions,
+            functions: self.functions + rhs.functions,
+        };
+    }
+}
+
+/// Run `operation` and count the operations its [Counted] elements perform on this thread <br>
+/// Counting is per thread so work done on the rayon thread pool is not included.
+/// Nested calls each see their own operations and the outer call also sees the inner ones.
+pub fn count_flops<R>(operation: impl FnOnce() -> R) -> (R, FlopReport) {
+    let before = COUNTS.with(|counts| counts.replace(FlopReport::new()));
+    let result = operation();
+    let report = COUNTS.with(|counts| counts.replace(before + counts.get()));
+    return (result, report);
+}
+
+fn record(update: impl FnOnce(&mut FlopReport)) {
+    COUNTS.with(|counts| {
+        let mut report = counts.get();
+        update(&mut report);
+        counts.set(report);
+    });
+}
+
+/// A float element that tallies every arithmetic operation performed on it <br>
+/// Convert a matrix with [crate::Matrix::cast] then wrap the algorithm in [count_flops].
+/// Comparisons, negation, and constants are free.
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct Counted<F>(F);
+impl<F> Counted<F> {
+    pub fn new(value: F) -> Self {
+        return Counted(value);
+    }
+    pub fn value(self) -> F {
+        return self.0;
+    }
+}
+impl<F> From<F> for Counted<F> {
+    fn from(value: F) -> Self {
+        return Counted(value);
+    }
+}
+
+macro_rules! counted_binary_operator {
+    ($trait:ident, $method:ident, $field:ident) => {
+        impl<F: Float> $trait for Counted<F> {
+            type Output = Self;
+            fn $method(self, rhs: Self) -> Self::Output {
+                record(|report| report.$field += 1);
+                return Counted(self.0.$method(rhs.0));
+            }
+        }
+    };
+}
+counted_binary_operator!(Add, add, additions);
+counted_binary_operator!(Sub, sub, additions);
+counted_binary_operator!(Mul, mul, multiplications);
+counted_binary_operator!(Div, div, divisions);
+counted_binary_operator!(Rem, rem, divisions);
+
+impl<F: Float> Neg for Counted<F> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        return Counted(-self.0);
+    }
+}
+impl<F: Float> Zero for Counted<F> {
+    fn zero() -> Self {
+        return Counted(F::zero());
+    }
+    fn is_zero(&self) -> bool {
+        return self.0.is_zero();
+    }
+}
+impl<F: Float> One for Counted<F> {
+    fn one() -> Self {
+        return Counted(F::one());
+    }
+}
+impl<F: Float> Num for Counted<F> {
+    type FromStrRadixErr = F::FromStrRadixErr;
+    fn from_str_radix(text: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        return F::from_str_radix(text, radix).map(Counted);
+    }
+}
+impl<F: Float> ToPrimitive for Counted<F> {
+    fn to_i64(&self) -> Option<i64> {
+        return self.0.to_i64();
+    }
+    fn to_u64(&self) -> Option<u64> {
+        return self.0.to_u64();
+    }
+    fn to_f64(&self) -> Option<f64> {
+        return self.0.to_f64();
+    }
+}
+impl<F: Float> NumCast for Counted<F> {
+    fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+        return <F as NumCast>::from(n).map(Counted);
+    }
+}
+
+/// Delegates to `F` without counting
+macro_rules! free {
+    ($($method:ident() -> $output:ty),* $(,)?) => {
+        $(fn $method() -> $output { return Counted(F::$method()); })*
+    };
+    ($($method:ident(self) -> $output:ty),* $(,)?) => {
+        $(fn $method(self) -> $output { return self.0.$method(); })*
+    };
+}
+/// Delegates to `F` counting one operation in `$field`
+macro_rules! counted {
+    ($field:ident: $($method:ident(self $(, $argument:ident: $argument_type:ty)*)),* $(,)?) => {
+        $(fn $method(self $(, $argument: $argument_type)*) -> Self {
+            record(|report| report.$field += 1);
+            return Counted(self.0.$method($($argument),*));
+        })*
+    };
+}
+
+impl<F: Float> Float for Counted<F> {
+    free!(
+        nan() -> Self,
+        infinity() -> Self,
+        neg_infinity() -> Self,
+        neg_zero() -> Self,
+        min_value() -> Self,
+        min_positive_value() -> Self,
+        max_value() -> Self,
+        epsilon() -> Self,
+    );
+    free!(
+        is_nan(self) -> bool,
+        is_infinite(self) -> bool,
+        is_finite(self) -> bool,
+        is_normal(self) -> bool,
+        classify(self) -> FpCategory,
+        is_sign_positive(self) -> bool,
+        is_sign_negative(self) -> bool,
+        integer_decode(self) -> (u64, i16, i8),
+    );
+    counted!(additions: floor(self), ceil(self), round(self), trunc(self), fract(self));
+    counted!(divisions: recip(self));
+    counted!(
+        functions: sqrt(self), exp(self), exp2(self), ln(self), log2(self), log10(self),
+        cbrt(self), sin(self), cos(self), tan(self), asin(self), acos(self), atan(self),
+        exp_m1(self), ln_1p(self), sinh(self), cosh(self), tanh(self), asinh(self),
+        acosh(self), atanh(self), powi(self, n: i32)
+    );
+
+    fn abs(self) -> Self {
+        return Counted(self.0.abs());
+    }
+    fn signum(self) -> Self {
+        return Counted(self.0.signum());
+    }
+    fn max(self, other: Self) -> Self {
+        return Counted(self.0.max(other.0));
+    }
+    fn min(self, other: Self) -> Self {
+        return Counted(self.0.min(other.0));
+    }
+    #[allow(deprecated)]
+    fn abs_sub(self, other: Self) -> Self {
+        record(|report| report.additions += 1);
+        return Counted(self.0.abs_sub(other.0));
+    }
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        record(|report| {
+            report.multiplications += 1;
+            report.additions += 1;
+        });
+        return Counted(self.0.mul_add(a.0, b.0));
+    }
+    fn powf(self, n: Self) -> Self {
+        record(|report| report.functions += 1);
+        return Counted(self.0.powf(n.0));
+    }
+    fn log(self, base: Self) -> Self {
+        record(|report| report.functions += 1);
+        return Counted(self.0.log(base.0));
+    }
+    fn hypot(self, other: Self) -> Self {
+        record(|report| report.functions += 1);
+        return Counted(self.0.hypot(other.0));
+    }
+    fn atan2(self, other: Self) -> Self {
+        record(|report| report.functions += 1);
+        return Counted(self.0.atan2(other.0));
+    }
+    fn sin_cos(self) -> (Self, Self) {
+        record(|report| report.functions += 2);
+        let (sin, cos) = self.0.sin_cos();
+        return (Counted(sin), Counted(cos));
+    }
+}
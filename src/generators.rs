@@ -0,0 +1,219 @@
+use std::num::NonZeroUsize;
+
+use num::Float;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::{sample_standard_normal, DataSet, Householder, Matrix, Shape};
+
+/// The condition number of [Matrix::well_conditioned_spd]
+pub const WELL_CONDITIONED: f64 = 1e1;
+/// The condition number of [Matrix::ill_conditioned_spd]
+pub const ILL_CONDITIONED: f64 = 1e10;
+
+impl<F: Float> Matrix<F> {
+    /// A `size x size` orthogonal matrix drawn uniformly (from the Haar measure) <br>
+    /// `Q` from the QR factorization of a matrix of standard normal samples,
+    /// with each column multiplied by the sign of the matching diagonal element of `R`
+    /// so the distribution does not depend on the sign convention of the factorization.
+    /// The same `seed` always gives the same matrix.
+    pub fn random_orthogonal(size: NonZeroUsize, seed: u64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+
+        let mut r = Matrix::zeros(Shape::square(size));
+        for element in r.elements_mut() {
+            *element = sample_standard_normal(&mut rng);
+        }
+
+        let mut q = Matrix::identity(size);
+        let mut signs = Vec::with_capacity(size.get());
+        for column in 0..size.get() {
+            let below = (column..size.get())
+                .map(|row| r[row][column])
+                .collect::<Vec<_>>();
+            let (reflector, beta) =
+                Householder::zeroing(&below, column).expect("below is not empty");
+            reflector
+                .apply_left(&mut r)
+                .expect("the reflector fits in r");
+            reflector
+                .apply_right(&mut q)
+                .expect("the reflector fits in q");
+            signs.push(if beta < F::zero() {
+                -F::one()
+            } else {
+                F::one()
+            });
+        }
+
+        for row in q.rows_mut() {
+            for (element, &sign) in row.iter_mut().zip(signs.iter()) {
+                *element = *element * sign;
+            }
+        }
+
+        return q;
+    }
+
+    /// A `size x size` symmetric positive definite matrix with a chosen condition number <br>
+    /// `Q * diag(λ) * Qᵀ` where `Q` is [Matrix::random_orthogonal]
+    /// and the eigenvalues `λ` are spaced geometrically from `1` to `condition_number`.
+    /// The same `seed` always gives the same matrix.
+    /// ## Panics
+    /// - if `condition_number` is less than one or NaN
+    pub fn random_spd(size: NonZeroUsize, condition_number: F, seed: u64) -> Self {
+        assert!(
+            condition_number >= F::one(),
+            "condition_number must be at least one"
+        );
+
+        let q = Matrix::<F>::random_orthogonal(size, seed);
+        let last = F::from(size.get() - 1).expect("usize converts to a float");
+        let eigenvalues = (0..size.get())
+            .map(|index| {
+                if last.is_zero() {
+                    F::one()
+                } else {
+                    let exponent = F::from(index).expect("usize converts to a float") / last;
+                    condition_number.powf(exponent)
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // fill the upper triangle and mirror it so the result is exactly symmetric
+        let mut spd = Matrix::zeros(Shape::square(size));
+        for i in 0..size.get() {
+            for j in i..size.get() {
+                let element = (0..size.get())
+                    .fold(F::zero(), |sum, k| sum + q[i][k] * eigenvalues[k] * q[j][k]);
+                spd[i][j] = element;
+                spd[j][i] = element;
+            }
+        }
+
+        return spd;
+    }
+
+    /// [Matrix::random_spd] with a condition number of [WELL_CONDITIONED]
+    pub fn well_conditioned_spd(size: NonZeroUsize, seed: u64) -> Self {
+        let condition_number = F::from(WELL_CONDITIONED).expect("f64 converts to a float");
+        return Matrix::random_spd(size, condition_number, seed);
+    }
+
+    /// [Matrix::random_spd] with a condition number of [ILL_CONDITIONED]
+    pub fn ill_conditioned_spd(size: NonZeroUsize, seed: u64) -> Self {
+        let condition_number = F::from(ILL_CONDITIONED).expect("f64 converts to a float");
+        return Matrix::random_spd(size, condition_number, seed);
+    }
+}
+
+/// How the noise added to each output of a synthetic [DataSet] is drawn
+#[derive(Debug, Clone, PartialEq)]
+pub enum NoiseModel<F> {
+    /// Exact outputs
+    None,
+    /// Normal with mean zero
+    Gaussian { standard_deviation: F },
+    /// Uniform over `[-half_width, half_width)`
+    Uniform { half_width: F },
+    /// Normal with mean zero and a standard deviation of `scale * |x|`
+    Heteroscedastic { scale: F },
+    /// Noise from `base`, except each point is an outlier with probability `rate`
+    /// and gets normal noise with a standard deviation of `magnitude` instead
+    Contaminated {
+        base: Box<NoiseModel<F>>,
+        rate: F,
+        magnitude: F,
+    },
+}
+impl<F: Float> NoiseModel<F> {
+    /// Draw the noise for the point with `input` as its `x`
+    pub fn sample<R: Rng + ?Sized>(&self, input: F, rng: &mut R) -> F {
+        return match self {
+            NoiseModel::None => F::zero(),
+            NoiseModel::Gaussian { standard_deviation } => {
+                *standard_deviation * sample_standard_normal::<F, _>(rng)
+            }
+            NoiseModel::Uniform { half_width } => {
+                let uniform = F::from(rng.gen::<f64>()).expect("f64 converts to a float");
+                *half_width * (uniform + uniform - F::one())
+            }
+            NoiseModel::Heteroscedastic { scale } => {
+                *scale * input.abs() * sample_standard_normal::<F, _>(rng)
+            }
+            NoiseModel::Contaminated {
+                base,
+                rate,
+                magnitude,
+            } => {
+                let uniform = F::from(rng.gen::<f64>()).expect("f64 converts to a float");
+                if uniform < *rate {
+                    *magnitude * sample_standard_normal::<F, _>(rng)
+                } else {
+                    base.sample(input, rng)
+                }
+            }
+        };
+    }
+}
+
+impl<F: Float> DataSet<F> {
+    /// Sample a polynomial with gaussian noise added to each output <br>
+    /// [DataSet::synthetic_polynomial_with_noise] with [NoiseModel::Gaussian]
+    /// ## Parameters
+    /// - `noise`: the standard deviation of the noise, zero for exact outputs.
+    pub fn synthetic_polynomial(
+        coefficients: &[F],
+        noise: F,
+        count: NonZeroUsize,
+        seed: u64,
+    ) -> Self {
+        let noise = NoiseModel::Gaussian {
+            standard_deviation: noise,
+        };
+        return DataSet::synthetic_polynomial_with_noise(coefficients, &noise, count, seed);
+    }
+
+    /// Sample a polynomial with noise from `noise` added to each output <br>
+    /// The inputs are evenly spaced over `[-1, 1]` (just `0` if `count` is one).
+    /// The same `seed` always gives the same data set.
+    /// ## Parameters
+    /// - `coefficients`: the coefficient of each power of `x` in ascending order,
+    ///   the same order [DataSet::polynomial_fit] returns.
+    /// - `noise`: how the noise is drawn, see [NoiseModel].
+    /// - `count`: the number of points.
+    /// - `seed`: the seed of the random number generator.
+    pub fn synthetic_polynomial_with_noise(
+        coefficients: &[F],
+        noise: &NoiseModel<F>,
+        count: NonZeroUsize,
+        seed: u64,
+    ) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let step = match count.get() {
+            1 => F::zero(),
+            count => {
+                let two = F::one() + F::one();
+                two / F::from(count - 1).expect("usize converts to a float")
+            }
+        };
+        let start = if count.get() == 1 {
+            F::zero()
+        } else {
+            -F::one()
+        };
+
+        let points = (0..count.get())
+            .map(|index| {
+                let input = start + step * F::from(index).expect("usize converts to a float");
+                let exact = coefficients
+                    .iter()
+                    .rev()
+                    .fold(F::zero(), |sum, &coefficient| sum * input + coefficient);
+                let output = exact + noise.sample(input, &mut rng);
+                (input, output)
+            })
+            .collect::<Vec<_>>();
+
+        return DataSet::try_from(points.as_slice()).expect("count is not zero");
+    }
+}
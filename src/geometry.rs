@@ -0,0 +1,135 @@
+use std::num::NonZeroUsize;
+
+use num::Float;
+
+use crate::{DimensionError, Matrix, MatrixError, Shape, SymmetricEigen};
+
+/// A line in 2d space through `point` along the unit vector `direction`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Line2d<F> {
+    point: [F; 2],
+    direction: [F; 2],
+}
+impl<F: Float> Line2d<F> {
+    /// A point on the line (the centroid of the fitted points)
+    pub fn point(&self) -> [F; 2] {
+        return self.point;
+    }
+    /// The unit vector along the line
+    pub fn direction(&self) -> [F; 2] {
+        return self.direction;
+    }
+    /// The unit vector perpendicular to the line
+    pub fn normal(&self) -> [F; 2] {
+        return [-self.direction[1], self.direction[0]];
+    }
+    /// The perpendicular distance from `point` to the line
+    pub fn distance(&self, point: [F; 2]) -> F {
+        let normal = self.normal();
+        let offset = [point[0] - self.point[0], point[1] - self.point[1]];
+        return (offset[0] * normal[0] + offset[1] * normal[1]).abs();
+    }
+}
+
+/// A plane in 3d space through `point` perpendicular to the unit vector `normal`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Plane3d<F> {
+    point: [F; 3],
+    normal: [F; 3],
+}
+impl<F: Float> Plane3d<F> {
+    /// A point on the plane (the centroid of the fitted points)
+    pub fn point(&self) -> [F; 3] {
+        return self.point;
+    }
+    /// The unit vector perpendicular to the plane
+    pub fn normal(&self) -> [F; 3] {
+        return self.normal;
+    }
+    /// The perpendicular distance from `point` to the plane
+    pub fn distance(&self, point: [F; 3]) -> F {
+        return (0..3)
+            .map(|axis| (point[axis] - self.point[axis]) * self.normal[axis])
+            .fold(F::zero(), |sum, term| sum + term)
+            .abs();
+    }
+}
+
+/// Fit a line to 2d points by total least squares <br>
+/// (minimizes the perpendicular distance of each point, not the vertical distance)
+/// ## Parameters
+/// - `points`: a matrix where each row is a point `[x, y]`.
+/// ## Returns
+/// - The best fit [Line2d] through the centroid of `points`.
+/// ## Errors
+/// - [MatrixError::DimensionError]
+///   - if `points` does not have exactly 2 columns
+///   - if there are fewer than 2 points
+pub fn fit_line_2d<F: Float>(points: &Matrix<F>) -> Result<Line2d<F>, MatrixError> {
+    let (centroid, eigen) = principal_axes(points, 2)?;
+    let eigenvectors = eigen.eigenvectors();
+
+    // the eigenvector with the largest eigenvalue points along the line
+    let direction = [eigenvectors[0][1], eigenvectors[1][1]];
+
+    return Ok(Line2d {
+        point: [centroid[0], centroid[1]],
+        direction,
+    });
+}
+
+/// Fit a plane to 3d points by total least squares <br>
+/// (minimizes the perpendicular distance of each point)
+/// ## Parameters
+/// - `points`: a matrix where each row is a point `[x, y, z]`.
+/// ## Returns
+/// - The best fit [Plane3d] through the centroid of `points`.
+/// ## Errors
+/// - [MatrixError::DimensionError]
+///   - if `points` does not have exactly 3 columns
+///   - if there are fewer than 3 points
+pub fn fit_plane_3d<F: Float>(points: &Matrix<F>) -> Result<Plane3d<F>, MatrixError> {
+    let (centroid, eigen) = principal_axes(points, 3)?;
+    let eigenvectors = eigen.eigenvectors();
+
+    // the eigenvector with the smallest eigenvalue is perpendicular to the plane
+    let normal = [eigenvectors[0][0], eigenvectors[1][0], eigenvectors[2][0]];
+
+    return Ok(Plane3d {
+        point: [centroid[0], centroid[1], centroid[2]],
+        normal,
+    });
+}
+
+/// Centroid and eigendecomposition of the scatter matrix of `points`
+fn principal_axes<F: Float>(
+    points: &Matrix<F>,
+    dimension: usize,
+) -> Result<(Vec<F>, SymmetricEigen<F>), MatrixError> {
+    if points.width() != dimension {
+        return Err(DimensionError::UnexpectedWidth {
+            expected: dimension,
+            actual: points.shape(),
+        }
+        .into());
+    }
+    if points.height() < dimension {
+        return Err(DimensionError::TooSmall.into());
+    }
+
+    let centroid = points.column_means();
+
+    let size = NonZeroUsize::new(dimension).expect("dimension cannot be zero");
+    let mut scatter = Matrix::zeros(Shape::square(size));
+    for point in points.rows() {
+        for i in 0..dimension {
+            for j in 0..dimension {
+                scatter[i][j] = scatter[i][j] + (point[i] - centroid[i]) * (point[j] - centroid[j]);
+            }
+        }
+    }
+
+    let eigen = scatter.symmetric_eigen()?;
+
+    return Ok((centroid, eigen));
+}
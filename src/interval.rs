@@ -0,0 +1,311 @@
+use std::{
+    cmp::Ordering,
+    fmt::Display,
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+};
+
+use num::{Float, Num, One, Zero};
+
+use crate::{DecompositionError, DimensionError, Matrix, MatrixError};
+
+/// A closed interval `[lower, upper]` of floats that is guaranteed to contain the exact result <br>
+/// Every operation rounds its bounds outwards by about one unit in the last place,
+/// so a [Matrix] of intervals carries a rigorous bound on the rounding error of whatever it computes.
+/// Dividing by an interval that contains `0` gives the whole real line.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Interval<F> {
+    lower: F,
+    upper: F,
+}
+impl<F: Float> Interval<F> {
+    /// The interval from `lower` to `upper`
+    /// ## Returns
+    /// - [None] if `lower` > `upper` or either is `NaN`
+    pub fn new(lower: F, upper: F) -> Option<Self> {
+        return (lower <= upper).then_some(Interval { lower, upper });
+    }
+    /// The degenerate interval `[value, value]`
+    pub fn point(value: F) -> Self {
+        return Interval {
+            lower: value,
+            upper: value,
+        };
+    }
+    /// `[value - radius, value + radius]` rounded outwards
+    pub fn with_radius(value: F, radius: F) -> Self {
+        return Interval {
+            lower: round_down(value - radius.abs()),
+            upper: round_up(value + radius.abs()),
+        };
+    }
+    /// `(-∞, ∞)`
+    pub fn entire() -> Self {
+        return Interval {
+            lower: F::neg_infinity(),
+            upper: F::infinity(),
+        };
+    }
+
+    pub fn lower(&self) -> F {
+        return self.lower;
+    }
+    pub fn upper(&self) -> F {
+        return self.upper;
+    }
+    /// `upper - lower`
+    pub fn width(&self) -> F {
+        return self.upper - self.lower;
+    }
+    /// The center of the interval
+    pub fn midpoint(&self) -> F {
+        let two = F::one() + F::one();
+        return self.lower / two + self.upper / two;
+    }
+    /// The smallest absolute value in the interval
+    pub fn mignitude(&self) -> F {
+        return if self.contains(F::zero()) {
+            F::zero()
+        } else {
+            self.lower.abs().min(self.upper.abs())
+        };
+    }
+    /// The largest absolute value in the interval
+    pub fn magnitude(&self) -> F {
+        return self.lower.abs().max(self.upper.abs());
+    }
+    pub fn contains(&self, value: F) -> bool {
+        return self.lower <= value && value <= self.upper;
+    }
+    /// The smallest interval containing both `self` and `other`
+    pub fn hull(&self, other: &Self) -> Self {
+        return Interval {
+            lower: self.lower.min(other.lower),
+            upper: self.upper.max(other.upper),
+        };
+    }
+}
+
+/// The next float below `value`, or lower
+fn round_down<F: Float>(value: F) -> F {
+    if value.is_infinite() || value.is_nan() {
+        return value;
+    }
+    return value - (value.abs() * F::epsilon()).max(F::min_positive_value());
+}
+
+/// The next float above `value`, or higher
+fn round_up<F: Float>(value: F) -> F {
+    return -round_down(-value);
+}
+
+impl<F: Float> From<F> for Interval<F> {
+    fn from(value: F) -> Self {
+        return Interval::point(value);
+    }
+}
+
+impl<F: Display> Display for Interval<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return write!(f, "[{}, {}]", self.lower, self.upper);
+    }
+}
+
+/// `Less` or `Greater` only when every element of one interval is below every element of the other
+impl<F: Float> PartialOrd for Interval<F> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self == other && self.lower == self.upper {
+            return Some(Ordering::Equal);
+        } else if self.upper < other.lower {
+            return Some(Ordering::Less);
+        } else if self.lower > other.upper {
+            return Some(Ordering::Greater);
+        }
+        return None;
+    }
+}
+
+impl<F: Float> Add for Interval<F> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        return Interval {
+            lower: round_down(self.lower + rhs.lower),
+            upper: round_up(self.upper + rhs.upper),
+        };
+    }
+}
+
+impl<F: Float> Sub for Interval<F> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        return self + -rhs;
+    }
+}
+
+impl<F: Float> Neg for Interval<F> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        return Interval {
+            lower: -self.upper,
+            upper: -self.lower,
+        };
+    }
+}
+
+impl<F: Float> Mul for Interval<F> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        let products = [
+            self.lower * rhs.lower,
+            self.lower * rhs.upper,
+            self.upper * rhs.lower,
+            self.upper * rhs.upper,
+        ]
+        // 0 * ∞ only happens with an unbounded interval, which stays unbounded
+        .map(|product| if product.is_nan() { F::zero() } else { product });
+
+        let (lower, upper) = products.iter().fold(
+            (F::infinity(), F::neg_infinity()),
+            |(lower, upper), &product| (lower.min(product), upper.max(product)),
+        );
+        return Interval {
+            lower: round_down(lower),
+            upper: round_up(upper),
+        };
+    }
+}
+
+impl<F: Float> Div for Interval<F> {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self::Output {
+        if rhs.contains(F::zero()) {
+            return Interval::entire();
+        }
+        let reciprocal = Interval {
+            lower: round_down(F::one() / rhs.upper),
+            upper: round_up(F::one() / rhs.lower),
+        };
+        return self * reciprocal;
+    }
+}
+
+/// An enclosure of `self - rhs * trunc(self / rhs)`, which has the sign of `self` and is smaller than `|rhs|`
+impl<F: Float> Rem for Interval<F> {
+    type Output = Self;
+    fn rem(self, rhs: Self) -> Self::Output {
+        let bound = rhs.magnitude();
+        return Interval {
+            lower: if self.lower >= F::zero() {
+                F::zero()
+            } else {
+                -bound.min(self.lower.abs())
+            },
+            upper: if self.upper <= F::zero() {
+                F::zero()
+            } else {
+                bound.min(self.upper)
+            },
+        };
+    }
+}
+
+impl<F: Float> Zero for Interval<F> {
+    fn zero() -> Self {
+        return Interval::point(F::zero());
+    }
+    fn is_zero(&self) -> bool {
+        return self.lower.is_zero() && self.upper.is_zero();
+    }
+}
+
+impl<F: Float> One for Interval<F> {
+    fn one() -> Self {
+        return Interval::point(F::one());
+    }
+}
+
+impl<F: Float> Num for Interval<F> {
+    type FromStrRadixErr = F::FromStrRadixErr;
+    /// Parses a single value as a point interval
+    fn from_str_radix(text: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        return F::from_str_radix(text, radix).map(Interval::point);
+    }
+}
+
+impl<F: Float> Matrix<Interval<F>> {
+    /// The interval matrix with each element of `matrix` as a point interval
+    pub fn from_points(matrix: &Matrix<F>) -> Self {
+        return matrix.map(|&element| Interval::point(element));
+    }
+
+    /// The midpoint of every element
+    pub fn midpoints(&self) -> Matrix<F> {
+        return self.map(Interval::midpoint);
+    }
+
+    /// Solve `self * X` == `b` by interval Gaussian elimination with partial pivoting <br>
+    /// The pivot in each column is the interval with the largest mignitude.
+    /// Every exact solution of every point system inside the intervals is contained in the result.
+    /// ## Returns
+    /// - An enclosure of `X` with the same dimensions as `b`.
+    /// ## Errors
+    /// - [MatrixError::Decomposition]
+    ///   - if `self` is not square
+    ///   - if a pivot contains `0` so the matrix cannot be shown to be nonsingular
+    /// - [MatrixError::DimensionError]
+    ///   - if `b.height()` != `self.height()`
+    pub fn interval_solve(&self, b: &Self) -> Result<Self, MatrixError> {
+        if self.width() != self.height() {
+            Err(DecompositionError::from(DimensionError::NotSquare))?
+        }
+        if b.height() != self.height() {
+            return Err(DimensionError::UnexpectedHeight {
+                expected: self.height(),
+                actual: b.shape(),
+            }
+            .into());
+        }
+
+        let size = self.height();
+        let mut a = self.clone();
+        let mut x = b.clone();
+
+        for column in 0..size {
+            let pivot_row = (column..size)
+                .max_by(|&i, &j| {
+                    a[i][column]
+                        .mignitude()
+                        .partial_cmp(&a[j][column].mignitude())
+                        .unwrap_or(Ordering::Equal)
+                })
+                .expect("column < size so the range is not empty");
+            if a[pivot_row][column].contains(F::zero()) {
+                Err(DecompositionError::Singular)?
+            }
+            a.swap_rows(pivot_row, column);
+            x.swap_rows(pivot_row, column);
+
+            let pivot = a[column][column];
+            for row in (column + 1)..size {
+                let multiplier = a[row][column] / pivot;
+                for k in column..size {
+                    a[row][k] = a[row][k] - multiplier * a[column][k];
+                }
+                for k in 0..x.width() {
+                    x[row][k] = x[row][k] - multiplier * x[column][k];
+                }
+            }
+        }
+
+        for row in (0..size).rev() {
+            for k in 0..x.width() {
+                let mut sum = x[row][k];
+                for j in (row + 1)..size {
+                    sum = sum - a[row][j] * x[j][k];
+                }
+                x[row][k] = sum / a[row][row];
+            }
+        }
+
+        return Ok(x);
+    }
+}
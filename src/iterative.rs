@@ -0,0 +1,373 @@
+use std::num::NonZeroUsize;
+
+use num::{Float, Num};
+
+use crate::{kernels, ColumnVector, DimensionError, Matrix, MatrixError};
+
+/// Anything that can multiply a [ColumnVector], without necessarily storing its elements <br>
+/// The iterative solvers in this module only ever need `A * x` so they accept any [LinearOperator].
+pub trait LinearOperator<E> {
+    /// The length of the output of [LinearOperator::apply]
+    fn height(&self) -> usize;
+    /// The length of the input of [LinearOperator::apply]
+    fn width(&self) -> usize;
+    /// Calculate `A * x`
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if `x.len()` != `self.width()`
+    fn apply(&self, x: &ColumnVector<E>) -> Result<ColumnVector<E>, MatrixError>;
+}
+
+impl<E: Num + Copy> LinearOperator<E> for Matrix<E> {
+    fn height(&self) -> usize {
+        return Matrix::height(self);
+    }
+    fn width(&self) -> usize {
+        return Matrix::width(self);
+    }
+    fn apply(&self, x: &ColumnVector<E>) -> Result<ColumnVector<E>, MatrixError> {
+        return self.mul_column_vector(x);
+    }
+}
+
+/// A [LinearOperator] defined by a closure
+pub struct FnOperator<Function> {
+    height: usize,
+    width: usize,
+    function: Function,
+}
+impl<Function> FnOperator<Function> {
+    /// `function` maps a vector of length `width` to a vector of length `height`
+    pub fn new(height: usize, width: usize, function: Function) -> Self {
+        return FnOperator {
+            height,
+            width,
+            function,
+        };
+    }
+}
+impl<E, Function: Fn(&ColumnVector<E>) -> ColumnVector<E>> LinearOperator<E>
+    for FnOperator<Function>
+{
+    fn height(&self) -> usize {
+        return self.height;
+    }
+    fn width(&self) -> usize {
+        return self.width;
+    }
+    fn apply(&self, x: &ColumnVector<E>) -> Result<ColumnVector<E>, MatrixError> {
+        check_length(self.width, x.len())?;
+        let product = (self.function)(x);
+        check_length(self.height, product.len())?;
+        return Ok(product);
+    }
+}
+
+/// The result of an iterative solver
+#[derive(Debug, Clone)]
+pub struct IterativeSolution<F> {
+    solution: ColumnVector<F>,
+    iterations: usize,
+    converged: bool,
+    residual_history: Vec<F>,
+}
+impl<F: Float> IterativeSolution<F> {
+    /// The last iterate, even if the solver did not converge
+    pub fn solution(&self) -> &ColumnVector<F> {
+        return &self.solution;
+    }
+    pub fn into_solution(self) -> ColumnVector<F> {
+        return self.solution;
+    }
+    pub fn iterations(&self) -> usize {
+        return self.iterations;
+    }
+    /// `true` if the relative residual dropped below the tolerance
+    pub fn converged(&self) -> bool {
+        return self.converged;
+    }
+    /// The residual norm `|b - A * x|` before the first iteration and after each one
+    pub fn residual_history(&self) -> &[F] {
+        return &self.residual_history;
+    }
+}
+
+/// The dominant eigenpair found by [power_iteration]
+#[derive(Debug, Clone)]
+pub struct PowerIteration<F> {
+    eigenvalue: F,
+    eigenvector: ColumnVector<F>,
+    iterations: usize,
+    converged: bool,
+}
+impl<F: Float> PowerIteration<F> {
+    pub fn eigenvalue(&self) -> F {
+        return self.eigenvalue;
+    }
+    /// A unit eigenvector
+    pub fn eigenvector(&self) -> &ColumnVector<F> {
+        return &self.eigenvector;
+    }
+    pub fn iterations(&self) -> usize {
+        return self.iterations;
+    }
+    /// `true` if the eigenvalue estimate stopped changing within the tolerance
+    pub fn converged(&self) -> bool {
+        return self.converged;
+    }
+}
+
+/// Solve `A * x` == `b` with the conjugate gradient method
+/// ## Parameters
+/// - `operator`: a symmetric positive definite [LinearOperator].
+/// - `b`: the right hand side.
+/// - `tolerance`: stop once `|b - A * x| <= tolerance * |b|`.
+/// - `max_iterations`: stop after this many iterations even if not converged.
+/// ## Returns
+/// - The [IterativeSolution] starting from `x` == `0`.
+/// ## Errors
+/// - [MatrixError::DimensionError]
+///   - if `operator` is not square
+///   - if `b.len()` != `operator.height()`
+pub fn conjugate_gradient<F: Float, A: LinearOperator<F> + ?Sized>(
+    operator: &A,
+    b: &ColumnVector<F>,
+    tolerance: F,
+    max_iterations: usize,
+) -> Result<IterativeSolution<F>, MatrixError> {
+    check_square(operator)?;
+    check_length(operator.height(), b.len())?;
+
+    let threshold = tolerance * b.norm();
+
+    let mut x = b.scale(F::zero());
+    let mut residual = b.clone();
+    let mut direction = residual.clone();
+    let mut residual_norm_squared = residual.norm_squared();
+    let mut residual_history = vec![residual_norm_squared.sqrt()];
+
+    let mut iterations = 0;
+    let mut converged = residual_norm_squared.sqrt() <= threshold;
+    while !converged && iterations < max_iterations {
+        iterations += 1;
+
+        let product = operator.apply(&direction)?;
+        let curvature = direction.dot(&product)?;
+        if curvature.is_zero() {
+            break;
+        }
+        let step = residual_norm_squared / curvature;
+
+        axpy(step, &direction, &mut x);
+        axpy(-step, &product, &mut residual);
+
+        let next_norm_squared = residual.norm_squared();
+        residual_history.push(next_norm_squared.sqrt());
+        converged = next_norm_squared.sqrt() <= threshold;
+
+        let beta = next_norm_squared / residual_norm_squared;
+        residual_norm_squared = next_norm_squared;
+        for (d, &r) in direction.as_mut_slice().iter_mut().zip(residual.iter()) {
+            *d = r + beta * *d;
+        }
+    }
+
+    return Ok(IterativeSolution {
+        solution: x,
+        iterations,
+        converged,
+        residual_history,
+    });
+}
+
+/// Solve `A * x` == `b` with the restarted generalized minimal residual method, GMRES(m) <br>
+/// Unlike [conjugate_gradient] this works for any non singular square operator, symmetric or not.
+/// ## Parameters
+/// - `operator`: a square [LinearOperator].
+/// - `b`: the right hand side.
+/// - `restart`: the number of Krylov basis vectors `m` to build before restarting from the current iterate.
+///   Larger values converge in fewer iterations but cost `O(m * n)` memory.
+/// - `tolerance`: stop once `|b - A * x| <= tolerance * |b|`.
+/// - `max_iterations`: stop after this many operator applications (inner iterations) even if not converged.
+/// ## Returns
+/// - The [IterativeSolution] starting from `x` == `0`.
+///   The residual history has one entry per inner iteration plus one for each restart.
+/// ## Errors
+/// - [MatrixError::DimensionError]
+///   - if `operator` is not square
+///   - if `b.len()` != `operator.height()`
+pub fn gmres<F: Float, A: LinearOperator<F> + ?Sized>(
+    operator: &A,
+    b: &ColumnVector<F>,
+    restart: NonZeroUsize,
+    tolerance: F,
+    max_iterations: usize,
+) -> Result<IterativeSolution<F>, MatrixError> {
+    check_square(operator)?;
+    check_length(operator.height(), b.len())?;
+
+    let threshold = tolerance * b.norm();
+    let restart = restart.get();
+
+    let mut x = b.scale(F::zero());
+    let mut residual_history = Vec::new();
+    let mut iterations = 0;
+    let mut converged = false;
+
+    loop {
+        let mut residual = b.clone();
+        axpy(-F::one(), &operator.apply(&x)?, &mut residual);
+        let beta = residual.norm();
+        residual_history.push(beta);
+        if beta <= threshold {
+            converged = true;
+            break;
+        }
+        if iterations >= max_iterations {
+            break;
+        }
+
+        // orthonormal basis of the Krylov subspace
+        let mut basis = vec![residual.scale(F::one() / beta)];
+        // the Hessenberg matrix, one column per inner iteration, reduced to upper triangular by rotations
+        let mut hessenberg: Vec<Vec<F>> = Vec::with_capacity(restart);
+        let mut rotations: Vec<(F, F)> = Vec::with_capacity(restart);
+        // the right hand side of the small least squares problem, `beta * e₁` rotated
+        let mut g = vec![beta];
+
+        for column in 0..restart {
+            iterations += 1;
+
+            let mut w = operator.apply(&basis[column])?;
+            let mut h = Vec::with_capacity(column + 2);
+            // modified Gram-Schmidt
+            for vector in basis.iter() {
+                let projection = w.dot(vector)?;
+                axpy(-projection, vector, &mut w);
+                h.push(projection);
+            }
+            let w_norm = w.norm();
+            h.push(w_norm);
+
+            for (row, &(cos, sin)) in rotations.iter().enumerate() {
+                let (a, b) = (h[row], h[row + 1]);
+                h[row] = cos * a + sin * b;
+                h[row + 1] = -sin * a + cos * b;
+            }
+            let radius = h[column].hypot(h[column + 1]);
+            let (cos, sin) = if radius.is_zero() {
+                (F::one(), F::zero())
+            } else {
+                (h[column] / radius, h[column + 1] / radius)
+            };
+            h[column] = radius;
+            h[column + 1] = F::zero();
+            rotations.push((cos, sin));
+            g.push(-sin * g[column]);
+            g[column] = cos * g[column];
+
+            hessenberg.push(h);
+
+            let estimate = g[column + 1].abs();
+            residual_history.push(estimate);
+
+            let breakdown = w_norm.is_zero();
+            if estimate <= threshold || breakdown || iterations >= max_iterations {
+                break;
+            }
+            basis.push(w.scale(F::one() / w_norm));
+        }
+
+        // back substitution for the coefficients of the basis vectors
+        let size = hessenberg.len();
+        let mut y = vec![F::zero(); size];
+        for row in (0..size).rev() {
+            let mut sum = g[row];
+            for column in (row + 1)..size {
+                sum = sum - hessenberg[column][row] * y[column];
+            }
+            y[row] = if hessenberg[row][row].is_zero() {
+                F::zero()
+            } else {
+                sum / hessenberg[row][row]
+            };
+        }
+        for (&coefficient, vector) in y.iter().zip(basis.iter()) {
+            axpy(coefficient, vector, &mut x);
+        }
+    }
+
+    return Ok(IterativeSolution {
+        solution: x,
+        iterations,
+        converged,
+        residual_history,
+    });
+}
+
+/// Estimate the eigenvalue of largest magnitude and its eigenvector by repeated multiplication
+/// ## Parameters
+/// - `operator`: a square [LinearOperator].
+/// - `start`: the initial guess, must not be orthogonal to the dominant eigenvector.
+/// - `tolerance`: stop once the eigenvalue estimate changes by at most `tolerance * |λ|`.
+/// - `max_iterations`: stop after this many iterations even if not converged.
+/// ## Returns
+/// - The [PowerIteration] estimate, using the Rayleigh quotient for the eigenvalue.
+/// ## Errors
+/// - [MatrixError::DimensionError]
+///   - if `operator` is not square
+///   - if `start.len()` != `operator.width()`
+/// - [MatrixError::ZeroVector]
+///   - if `start` or some iterate becomes the zero vector
+pub fn power_iteration<F: Float, A: LinearOperator<F> + ?Sized>(
+    operator: &A,
+    start: &ColumnVector<F>,
+    tolerance: F,
+    max_iterations: usize,
+) -> Result<PowerIteration<F>, MatrixError> {
+    check_square(operator)?;
+    check_length(operator.width(), start.len())?;
+
+    let mut eigenvector = start.normalize().ok_or(MatrixError::ZeroVector)?;
+    let mut eigenvalue = F::zero();
+
+    let mut iterations = 0;
+    let mut converged = false;
+    while !converged && iterations < max_iterations {
+        iterations += 1;
+
+        let product = operator.apply(&eigenvector)?;
+        let next_eigenvalue = eigenvector.dot(&product)?;
+        eigenvector = product.normalize().ok_or(MatrixError::ZeroVector)?;
+
+        converged = (next_eigenvalue - eigenvalue).abs() <= tolerance * next_eigenvalue.abs();
+        eigenvalue = next_eigenvalue;
+    }
+
+    return Ok(PowerIteration {
+        eigenvalue,
+        eigenvector,
+        iterations,
+        converged,
+    });
+}
+
+/// `y` += `alpha * x`
+fn axpy<F: Float>(alpha: F, x: &ColumnVector<F>, y: &mut ColumnVector<F>) {
+    kernels::axpy(alpha, x.as_slice(), y.as_mut_slice())
+        .expect("the vectors have the length of the square operator");
+}
+
+fn check_square<E, A: LinearOperator<E> + ?Sized>(operator: &A) -> Result<(), MatrixError> {
+    if operator.height() != operator.width() {
+        return Err(DimensionError::NotSquare.into());
+    }
+    return Ok(());
+}
+
+fn check_length(expected: usize, actual: usize) -> Result<(), MatrixError> {
+    if expected != actual {
+        return Err(DimensionError::UnexpectedLength { expected, actual }.into());
+    }
+    return Ok(());
+}
@@ -0,0 +1,45 @@
+use num::Num;
+
+use crate::{DimensionError, MatrixError};
+
+/// The sum of the products of matching elements, `xᵀ * y`
+/// ## Errors
+/// - [MatrixError::DimensionError]
+///   - if `x` and `y` have different lengths
+pub fn dot<E: Num + Clone>(x: &[E], y: &[E]) -> Result<E, MatrixError> {
+    same_length(x, y)?;
+    return Ok(x
+        .iter()
+        .zip(y)
+        .fold(E::zero(), |sum, (x, y)| sum + x.clone() * y.clone()));
+}
+
+/// `y` += `alpha * x`
+/// ## Errors
+/// - [MatrixError::DimensionError]
+///   - if `x` and `y` have different lengths, `y` is left unchanged
+pub fn axpy<E: Num + Clone>(alpha: E, x: &[E], y: &mut [E]) -> Result<(), MatrixError> {
+    same_length(x, y)?;
+    for (y, x) in y.iter_mut().zip(x) {
+        *y = y.clone() + alpha.clone() * x.clone();
+    }
+    return Ok(());
+}
+
+/// `x` *= `alpha`, such as scaling one row of a [crate::Matrix]
+pub fn scale<E: Num + Clone>(alpha: E, x: &mut [E]) {
+    for x in x.iter_mut() {
+        *x = alpha.clone() * x.clone();
+    }
+}
+
+fn same_length<E>(x: &[E], y: &[E]) -> Result<(), MatrixError> {
+    if x.len() != y.len() {
+        return Err(DimensionError::UnexpectedLength {
+            expected: x.len(),
+            actual: y.len(),
+        }
+        .into());
+    }
+    return Ok(());
+}
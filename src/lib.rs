@@ -1,8 +1,37 @@
+// explicit `return` is the house style
+#![allow(clippy::needless_return)]
+
+#[cfg(feature = "rand")]
+pub mod clustering;
+pub mod control;
 pub mod data_set;
+pub mod dual;
 pub mod error;
+pub mod expression;
+pub mod flops;
+#[cfg(feature = "rand")]
+pub mod generators;
+pub mod geometry;
+pub mod interval;
+pub mod iterative;
+pub mod kernels;
 pub mod matrix;
+pub mod model;
+pub mod preprocessing;
 pub mod regression;
+#[cfg(feature = "rand")]
+pub mod sampling;
 #[cfg(test)]
 pub mod test;
+pub mod time_series;
+pub mod transform;
+pub mod vector;
+pub mod workspace;
 
-pub use crate::{data_set::*, error::*, matrix::*, regression::*};
+#[cfg(feature = "rand")]
+pub use crate::{clustering::*, generators::*, sampling::*};
+pub use crate::{
+    control::*, data_set::*, dual::*, error::*, expression::*, flops::*, geometry::*, interval::*,
+    iterative::*, matrix::*, model::*, preprocessing::*, regression::*, time_series::*,
+    transform::*, vector::*, workspace::*,
+};
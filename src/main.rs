@@ -0,0 +1,186 @@
+// explicit `return` is the house style
+#![allow(clippy::needless_return)]
+
+mod repl;
+
+#[cfg(test)]
+mod main_test;
+
+use std::{
+    env,
+    error::Error,
+    fs,
+    io::{self, IsTerminal},
+    path::PathBuf,
+    process::ExitCode,
+};
+
+use matrix::{DataSet, Matrix};
+
+const USAGE: &str = "\
+usage: matrix <command> [arguments] [--output <path>]
+
+commands:
+  multiply <a.csv> <b.csv>        print the matrix product a * b
+  invert <m.csv>                  print the inverse of m
+  det <m.csv>                     print the determinant of m
+  fit <data.csv> [--degree <n>]   fit a polynomial (default degree 1) to `input, output` rows
+  repl                            evaluate expressions like `C = A * inv(B)` interactively
+
+options:
+  --output <path>   write the result as CSV to <path> instead of printing it, not for repl
+
+matrices are CSV files with one row per line and elements separated by commas";
+
+/// What the command line asked for
+#[derive(Debug, Clone, PartialEq)]
+enum Command {
+    Multiply { lhs: PathBuf, rhs: PathBuf },
+    Invert { path: PathBuf },
+    Determinant { path: PathBuf },
+    Fit { path: PathBuf, degree: usize },
+    Repl,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Arguments {
+    command: Command,
+    output: Option<PathBuf>,
+}
+
+/// Split the command line into a [Command] and the options that apply to every command
+/// ## Errors
+/// - A message for the user if the command is unknown, a path is missing, or an option is malformed
+fn parse_arguments(arguments: impl Iterator<Item = String>) -> Result<Arguments, String> {
+    let mut positional = Vec::new();
+    let mut output = None;
+    let mut degree = None;
+
+    let mut arguments = arguments;
+    while let Some(argument) = arguments.next() {
+        match argument.as_str() {
+            "--output" | "-o" => {
+                let path = arguments.next().ok_or("--output needs a path")?;
+                output = Some(PathBuf::from(path));
+            }
+            "--degree" | "-d" => {
+                let value = arguments.next().ok_or("--degree needs a number")?;
+                let value = value
+                    .parse()
+                    .map_err(|_| format!("{value:?} is not a valid degree"))?;
+                degree = Some(value);
+            }
+            _ => positional.push(argument),
+        }
+    }
+
+    let mut positional = positional.into_iter();
+    let name = positional.next().ok_or("missing command")?;
+    let mut path = |what: &str| {
+        return positional
+            .next()
+            .map(PathBuf::from)
+            .ok_or(format!("{name} needs {what}"));
+    };
+
+    let command = match name.as_str() {
+        "multiply" => Command::Multiply {
+            lhs: path("a left hand side matrix")?,
+            rhs: path("a right hand side matrix")?,
+        },
+        "invert" => Command::Invert {
+            path: path("a matrix")?,
+        },
+        "det" => Command::Determinant {
+            path: path("a matrix")?,
+        },
+        "fit" => Command::Fit {
+            path: path("a data set")?,
+            degree: degree.unwrap_or(1),
+        },
+        "repl" => Command::Repl,
+        _ => return Err(format!("unknown command {name:?}")),
+    };
+    if degree.is_some() && !matches!(command, Command::Fit { .. }) {
+        return Err("--degree only applies to fit".to_string());
+    }
+    if output.is_some() && command == Command::Repl {
+        return Err("--output does not apply to repl, use `save` inside it".to_string());
+    }
+    if let Some(extra) = positional.next() {
+        return Err(format!("unexpected argument {extra:?}"));
+    }
+
+    return Ok(Arguments { command, output });
+}
+
+fn read_matrix(path: &PathBuf) -> Result<Matrix<f64>, Box<dyn Error>> {
+    let csv = fs::read_to_string(path).map_err(|error| format!("{}: {error}", path.display()))?;
+    return Ok(Matrix::from_csv_str(&csv).map_err(|error| format!("{}: {error}", path.display()))?);
+}
+
+/// Run `arguments.command`
+/// ## Returns
+/// - The text to print, empty if the result was written to `arguments.output`
+fn run(arguments: Arguments) -> Result<String, Box<dyn Error>> {
+    let result = match &arguments.command {
+        Command::Multiply { lhs, rhs } => read_matrix(lhs)?.matrix_multiply(&read_matrix(rhs)?)?,
+        Command::Invert { path } => read_matrix(path)?.inverse()?,
+        // a 1x1 matrix so the scalar goes through the same output path as the matrices
+        Command::Determinant { path } => {
+            Matrix::try_from([[read_matrix(path)?.determinant_lu()?]])?
+        }
+        Command::Repl => {
+            if io::stdin().is_terminal() {
+                println!("type `help` for a list of commands");
+            }
+            repl::run_repl(io::stdin().lock(), io::stdout())?;
+            return Ok(String::new());
+        }
+        Command::Fit { path, degree } => {
+            let data_set = DataSet::<f64>::from_csv(path)?;
+            let fit = data_set.polynomial_fit(*degree)?;
+            if arguments.output.is_none() {
+                let mut report = String::new();
+                for (power, coefficient) in fit.coefficients().elements().enumerate() {
+                    report.push_str(&format!("x^{power}: {coefficient}\n"));
+                }
+                report.push_str(&format!(
+                    "residual sum of squares: {}\n",
+                    fit.residual_sum_of_squares()
+                ));
+                return Ok(report);
+            }
+            fit.coefficients().clone()
+        }
+    };
+
+    return match &arguments.output {
+        Some(path) => {
+            fs::write(path, result.to_csv_string())?;
+            Ok(String::new())
+        }
+        None => Ok(result.to_csv_string()),
+    };
+}
+
+fn main() -> ExitCode {
+    let arguments = match parse_arguments(env::args().skip(1)) {
+        Ok(arguments) => arguments,
+        Err(message) => {
+            eprintln!("error: {message}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    return match run(arguments) {
+        Ok(text) => {
+            print!("{text}");
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    };
+}
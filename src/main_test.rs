@@ -0,0 +1,165 @@
+use super::*;
+
+fn arguments(line: &str) -> Result<Arguments, String> {
+    return parse_arguments(line.split_whitespace().map(String::from));
+}
+
+/// A path under the temporary directory that is unique to this process and `name`
+fn temporary_path(name: &str) -> PathBuf {
+    return env::temp_dir().join(format!("matrix_cli_{}_{name}", std::process::id()));
+}
+
+#[test]
+fn parse_commands() {
+    assert_eq!(
+        arguments("multiply a.csv b.csv --output c.csv").unwrap(),
+        Arguments {
+            command: Command::Multiply {
+                lhs: PathBuf::from("a.csv"),
+                rhs: PathBuf::from("b.csv"),
+            },
+            output: Some(PathBuf::from("c.csv")),
+        }
+    );
+    assert_eq!(
+        arguments("fit -d 3 data.csv").unwrap().command,
+        Command::Fit {
+            path: PathBuf::from("data.csv"),
+            degree: 3,
+        }
+    );
+    assert_eq!(
+        arguments("fit data.csv").unwrap().command,
+        Command::Fit {
+            path: PathBuf::from("data.csv"),
+            degree: 1,
+        }
+    );
+    assert_eq!(
+        arguments("-o det.csv det m.csv").unwrap(),
+        Arguments {
+            command: Command::Determinant {
+                path: PathBuf::from("m.csv"),
+            },
+            output: Some(PathBuf::from("det.csv")),
+        }
+    );
+    assert_eq!(arguments("repl").unwrap().command, Command::Repl);
+
+    for invalid in [
+        "",
+        "transpose m.csv",
+        "multiply a.csv",
+        "invert",
+        "invert m.csv extra.csv",
+        "invert m.csv --degree 2",
+        "fit data.csv --degree two",
+        "det m.csv --output",
+        "repl --output out.csv",
+    ] {
+        assert!(arguments(invalid).is_err(), "{invalid:?}");
+    }
+}
+
+#[test]
+fn run_commands() {
+    let matrix = temporary_path("matrix.csv");
+    let data = temporary_path("data.csv");
+    let output = temporary_path("output.csv");
+    fs::write(&matrix, "2, 1\n4, 3\n").unwrap();
+    fs::write(&data, "0, 1\n1, 3\n2, 5\n").unwrap();
+
+    let run_line = |line: &str| {
+        let line = line
+            .replace("MATRIX", matrix.to_str().unwrap())
+            .replace("DATA", data.to_str().unwrap())
+            .replace("OUTPUT", output.to_str().unwrap());
+        run(arguments(&line).unwrap()).map_err(|error| error.to_string())
+    };
+    let parse = |csv: &str| Matrix::<f64>::from_csv_str(csv).unwrap().round_to(10);
+
+    assert_eq!(
+        parse(&run_line("multiply MATRIX MATRIX").unwrap()),
+        Matrix::try_from([[8.0, 5.0], [20.0, 13.0]]).unwrap()
+    );
+    assert_eq!(
+        parse(&run_line("invert MATRIX").unwrap()),
+        Matrix::try_from([[1.5, -0.5], [-2.0, 1.0]]).unwrap()
+    );
+    assert_eq!(parse(&run_line("det MATRIX").unwrap()), parse("2\n"));
+    let report = run_line("fit DATA").unwrap();
+    assert!(report.contains("x^1: 2"), "{report}");
+
+    // every command writes its result instead of printing it
+    for (line, expected) in [
+        ("det MATRIX --output OUTPUT", "2\n"),
+        ("invert MATRIX --output OUTPUT", "1.5, -0.5\n-2, 1\n"),
+        ("fit DATA --output OUTPUT", "1\n2\n"),
+    ] {
+        assert_eq!(run_line(line).unwrap(), "", "{line}");
+        assert_eq!(
+            parse(&fs::read_to_string(&output).unwrap()),
+            parse(expected),
+            "{line}"
+        );
+        fs::remove_file(&output).unwrap();
+    }
+
+    let missing = temporary_path("missing.csv");
+    assert!(run(arguments(&format!("det {}", missing.display())).unwrap()).is_err());
+    fs::write(&matrix, "1, 2\n2, 4\n").unwrap();
+    assert!(run_line("invert MATRIX").is_err());
+
+    fs::remove_file(&matrix).unwrap();
+    fs::remove_file(&data).unwrap();
+}
+
+/// Everything [repl::run_repl] writes for `input`
+fn repl_transcript(input: &str) -> String {
+    let mut output = Vec::new();
+    repl::run_repl(io::Cursor::new(input), &mut output).unwrap();
+    return String::from_utf8(output).unwrap();
+}
+
+#[test]
+fn repl_assigns_and_evaluates() {
+    assert_eq!(
+        repl_transcript("a = 2\na * 3\nb = a - 1\nvars\n"),
+        "> > 6\n> > a b\n> "
+    );
+
+    let matrix = temporary_path("repl_matrix.csv");
+    let saved = temporary_path("repl_saved.csv");
+    fs::write(&matrix, "2, 1\n4, 3\n").unwrap();
+    let transcript = repl_transcript(&format!(
+        "load A {}\nB = inv(A) * A\nB\nd = det(A)\nd * 2\nsave B {}\nquit\nA\n",
+        matrix.display(),
+        saved.display()
+    ));
+    assert_eq!(transcript, "> > > 1, 0\n0, 1\n> > 4\n> > ");
+    assert_eq!(fs::read_to_string(&saved).unwrap(), "1, 0\n0, 1\n");
+
+    fs::remove_file(&matrix).unwrap();
+    fs::remove_file(&saved).unwrap();
+}
+
+#[test]
+fn repl_recovers_from_errors() {
+    let transcript = repl_transcript("x = 1 +\nx\n2 * (3 + 4)\n1 bad = 2\nload A\ny = 5\ny\n");
+    assert_eq!(
+        transcript,
+        "> error: The expression ended early\n\
+         > error: x is not defined\n\
+         > 14\n\
+         > error: \"1 bad\" is not a valid variable name\n\
+         > error: usage: load <name> <path.csv>\n\
+         > > 5\n\
+         > "
+    );
+
+    // a failed assignment leaves no variable behind
+    assert_eq!(
+        repl_transcript("x = 1 +\nvars\n"),
+        "> error: The expression ended early\n> \n> "
+    );
+}
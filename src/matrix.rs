@@ -97,15 +97,15 @@ impl<E> Matrix<E> {
             });
     }
 }
-impl<E: Num + Copy> Matrix<E> {
-    pub fn zeros(height: NonZeroUsize, width: NonZeroUsize) -> Self {
+impl<E: Num + Clone> Matrix<E> {
+    pub fn zeros(shape: Shape) -> Self {
         return Matrix {
-            elements: vec![vec![E::zero(); width.get()].into_boxed_slice(); height.get()]
+            elements: vec![vec![E::zero(); shape.cols()].into_boxed_slice(); shape.rows()]
                 .into_boxed_slice(),
         };
     }
     pub fn identity(size: NonZeroUsize) -> Self {
-        let mut identity = Self::zeros(size, size);
+        let mut identity = Self::zeros(Shape::square(size));
 
         for index in 0..size.get() {
             identity[index][index] = E::one();
@@ -118,11 +118,47 @@ impl<E: Num + Copy> Matrix<E> {
     }
 }
 
+pub mod access;
+pub mod batch;
+pub mod broadcast;
+pub mod builder;
+pub mod canonical;
+pub mod concurrency;
+pub mod construction;
+pub mod conversion;
+pub mod csv;
+pub mod decomposition;
+pub mod diagonal;
+pub mod elementwise;
+pub mod elimination;
+pub mod exact;
+pub mod functions;
+pub mod index_range;
+pub mod integer;
+pub mod mask;
+pub mod nan;
 pub mod operations;
+pub mod orthogonal;
+pub mod permutation;
+pub mod pool;
+pub mod reduction;
+pub mod render;
+pub mod shape;
+pub mod small;
+pub mod solve;
+pub mod sort;
+pub mod sparsity;
 pub mod trait_impls;
+pub mod view;
+
+pub use self::{
+    broadcast::*, builder::*, decomposition::*, elimination::*, index_range::*, integer::*,
+    mask::*, orthogonal::*, permutation::*, pool::*, reduction::Summation, render::*, shape::*,
+    sort::*, sparsity::*, view::*,
+};
 
 /// `MatrixIndex(row_index, column_index)`
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct MatrixIndex {
     row: usize,
     column: usize,
@@ -159,6 +195,41 @@ impl MatrixIndex {
             column: self.row,
         };
     }
+
+    /// The index `row_offset` rows down and `column_offset` columns right
+    /// ## Returns
+    /// - [None] if either index would be negative or overflow
+    pub fn offset(&self, row_offset: isize, column_offset: isize) -> Option<Self> {
+        return Some(Self {
+            row: self.row.checked_add_signed(row_offset)?,
+            column: self.column.checked_add_signed(column_offset)?,
+        });
+    }
+
+    /// `true` if this index is inside a matrix of `shape`
+    pub fn is_within(&self, shape: Shape) -> bool {
+        return self.row < shape.rows() && self.column < shape.cols();
+    }
+
+    /// The indexes directly above, left, right, and below that are inside a matrix of `shape`
+    pub fn neighbors(&self, shape: Shape) -> impl Iterator<Item = Self> {
+        const OFFSETS: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+        let index = *self;
+        return OFFSETS
+            .into_iter()
+            .filter_map(move |(row, column)| index.offset(row, column))
+            .filter(move |neighbor| neighbor.is_within(shape));
+    }
+
+    /// Like [MatrixIndex::neighbors] but also the four diagonal indexes, in row-major order
+    pub fn neighbors_with_diagonals(&self, shape: Shape) -> impl Iterator<Item = Self> {
+        let index = *self;
+        return (-1..=1)
+            .flat_map(|row| (-1..=1).map(move |column| (row, column)))
+            .filter(|&offset| offset != (0, 0))
+            .filter_map(move |(row, column)| index.offset(row, column))
+            .filter(move |neighbor| neighbor.is_within(shape));
+    }
 }
 impl From<(usize, usize)> for MatrixIndex {
     /// `(row_index, column_index)`
@@ -0,0 +1,214 @@
+use std::num::NonZeroUsize;
+
+use crate::{DimensionError, Matrix, MatrixError, MatrixIndex};
+
+impl<E> Matrix<E> {
+    /// Check that `index` is inside `self`
+    /// ## Errors
+    /// - [MatrixError::ElementOutOfBounds]
+    ///   - if `index` is not inside `self`
+    pub fn check_index(&self, index: impl Into<MatrixIndex>) -> Result<(), MatrixError> {
+        let index = index.into();
+        if index.row() >= self.height() || index.column() >= self.width() {
+            return Err(MatrixError::ElementOutOfBounds {
+                row: index.row(),
+                column: index.column(),
+                shape: self.shape(),
+            });
+        }
+        return Ok(());
+    }
+
+    /// [Matrix::get_element] that reports where the index fell outside
+    /// ## Errors
+    /// - [MatrixError::ElementOutOfBounds]
+    ///   - if `index` is not inside `self`
+    pub fn try_get_element(&self, index: impl Into<MatrixIndex>) -> Result<&E, MatrixError> {
+        let index = index.into();
+        self.check_index(index)?;
+        return Ok(&self[index]);
+    }
+
+    /// [Matrix::get_element_mut] that reports where the index fell outside
+    /// ## Errors
+    /// - [MatrixError::ElementOutOfBounds]
+    ///   - if `index` is not inside `self`
+    pub fn try_get_element_mut(
+        &mut self,
+        index: impl Into<MatrixIndex>,
+    ) -> Result<&mut E, MatrixError> {
+        let index = index.into();
+        self.check_index(index)?;
+        return Ok(&mut self[index]);
+    }
+
+    /// [Matrix::set_element] that reports where the index fell outside
+    /// ## Errors
+    /// - [MatrixError::ElementOutOfBounds]
+    ///   - if `index` is not inside `self`
+    pub fn try_set_element(
+        &mut self,
+        index: impl Into<MatrixIndex>,
+        value: E,
+    ) -> Result<(), MatrixError> {
+        *self.try_get_element_mut(index)? = value;
+        return Ok(());
+    }
+
+    /// The row at `row_index`
+    /// ## Errors
+    /// - [MatrixError::ElementOutOfBounds]
+    ///   - if `row_index` >= `self.height()`
+    pub fn try_get_row(&self, row_index: usize) -> Result<&[E], MatrixError> {
+        self.check_index((row_index, 0))?;
+        return Ok(&self[row_index]);
+    }
+
+    /// Swap rows `a` and `b` without copying their elements
+    /// ## Panics
+    /// - if `a` or `b` >= `self.height()`
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        self.elements.swap(a, b);
+    }
+
+    /// Swap columns `a` and `b`
+    /// ## Panics
+    /// - if `a` or `b` >= `self.width()`
+    pub fn swap_columns(&mut self, a: usize, b: usize) {
+        for row in self.rows_mut() {
+            row.swap(a, b);
+        }
+    }
+}
+
+impl<E: Clone> Matrix<E> {
+    /// Overwrite row `row_index` with `values`
+    /// ## Errors
+    /// - [MatrixError::ElementOutOfBounds]
+    ///   - if `row_index` >= `self.height()`
+    /// - [MatrixError::LengthMismatch]
+    ///   - if `values.len()` != `self.width()`
+    pub fn set_row(&mut self, row_index: usize, values: &[E]) -> Result<(), MatrixError> {
+        self.check_index((row_index, 0))?;
+        if values.len() != self.width() {
+            return Err(MatrixError::LengthMismatch {
+                operation: "set a row",
+                shape: self.shape(),
+                expected: self.width(),
+                actual: values.len(),
+            });
+        }
+
+        self.elements[row_index].clone_from_slice(values);
+
+        return Ok(());
+    }
+
+    /// Overwrite column `column_index` with `values`
+    /// ## Errors
+    /// - [MatrixError::ElementOutOfBounds]
+    ///   - if `column_index` >= `self.width()`
+    /// - [MatrixError::LengthMismatch]
+    ///   - if `values.len()` != `self.height()`
+    pub fn set_column(&mut self, column_index: usize, values: &[E]) -> Result<(), MatrixError> {
+        self.check_index((0, column_index))?;
+        if values.len() != self.height() {
+            return Err(MatrixError::LengthMismatch {
+                operation: "set a column",
+                shape: self.shape(),
+                expected: self.height(),
+                actual: values.len(),
+            });
+        }
+
+        for (row, value) in self.rows_mut().zip(values) {
+            row[column_index] = value.clone();
+        }
+
+        return Ok(());
+    }
+
+    /// Copy the rows at `indices`, in that order <br>
+    /// Any iterator of indices works, such as a list, `(0..height).step_by(k)` to keep every k-th row,
+    /// or a list with repeats to duplicate rows.
+    /// ## Errors
+    /// - [MatrixError::ElementOutOfBounds]
+    ///   - if an index >= `self.height()`
+    /// - [MatrixError::DimensionError]
+    ///   - if `indices` is empty
+    pub fn select_rows(
+        &self,
+        indices: impl IntoIterator<Item = usize>,
+    ) -> Result<Self, MatrixError> {
+        let mut elements = Vec::new();
+        for row_index in indices {
+            self.check_index((row_index, 0))?;
+            elements.push(self.elements[row_index].clone());
+        }
+        if elements.is_empty() {
+            Err(DimensionError::Zero)?
+        }
+        return Ok(Matrix {
+            elements: elements.into(),
+        });
+    }
+
+    /// Copy the columns at `indices`, in that order <br>
+    /// Any iterator of indices works, see [Matrix::select_rows].
+    /// ## Errors
+    /// - [MatrixError::ElementOutOfBounds]
+    ///   - if an index >= `self.width()`
+    /// - [MatrixError::DimensionError]
+    ///   - if `indices` is empty
+    pub fn select_columns(
+        &self,
+        indices: impl IntoIterator<Item = usize>,
+    ) -> Result<Self, MatrixError> {
+        let indices = indices.into_iter().collect::<Vec<_>>();
+        if indices.is_empty() {
+            Err(DimensionError::Zero)?
+        }
+        for &column_index in indices.iter() {
+            self.check_index((0, column_index))?;
+        }
+
+        return Ok(Matrix {
+            elements: self
+                .rows()
+                .map(|row| indices.iter().map(|&column| row[column].clone()).collect())
+                .collect(),
+        });
+    }
+
+    /// Copy the `height x width` block whose top left element is `self[top_left]`
+    /// ## Errors
+    /// - [MatrixError::ElementOutOfBounds]
+    ///   - if `top_left` or the bottom right element of the block is not inside `self`
+    pub fn submatrix(
+        &self,
+        top_left: impl Into<MatrixIndex>,
+        height: NonZeroUsize,
+        width: NonZeroUsize,
+    ) -> Result<Self, MatrixError> {
+        let top_left = top_left.into();
+        self.check_index(top_left)?;
+        let last_row = top_left.row().checked_add(height.get() - 1);
+        let last_column = top_left.column().checked_add(width.get() - 1);
+        let (Some(last_row), Some(last_column)) = (last_row, last_column) else {
+            // the block runs past usize::MAX so it can not be inside self
+            return Err(MatrixError::ElementOutOfBounds {
+                row: last_row.unwrap_or(usize::MAX),
+                column: last_column.unwrap_or(usize::MAX),
+                shape: self.shape(),
+            });
+        };
+        self.check_index((last_row, last_column))?;
+
+        return Ok(Matrix {
+            elements: self.elements[top_left.row()..=last_row]
+                .iter()
+                .map(|row| row[top_left.column()..=last_column].into())
+                .collect(),
+        });
+    }
+}
@@ -0,0 +1,133 @@
+use num::Num;
+
+use crate::{DimensionError, Matrix, MatrixError, Shape};
+
+impl<E: Num + Clone> Matrix<E> {
+    /// Calculate `lhs[i] * rhs[i]` for every pair <br>
+    /// Every pair is checked before any product is calculated.
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if `lhs` and `rhs` have different lengths
+    /// - [MatrixError::Arithmetic]
+    ///   - if `lhs[i].width()` != `rhs[i].height()` for some `i`
+    pub fn batch_multiply(lhs: &[Self], rhs: &[Self]) -> Result<Vec<Self>, MatrixError> {
+        check_batch(lhs, rhs)?;
+
+        let mut products = lhs
+            .iter()
+            .zip(rhs)
+            .map(|(lhs, rhs)| {
+                Matrix::zeros(Shape {
+                    rows: lhs.height_nonzero(),
+                    cols: rhs.width_nonzero(),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for ((lhs, rhs), product) in lhs.iter().zip(rhs).zip(products.iter_mut()) {
+            multiply_into(lhs, rhs, product);
+        }
+
+        return Ok(products);
+    }
+
+    /// Calculate `products[i] = lhs[i] * rhs[i]` for every pair, reusing the existing `products` <br>
+    /// Nothing is allocated so a real-time loop can call this every frame with the same buffers.
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if `lhs`, `rhs`, and `products` do not all have the same length
+    ///   - if `products[i]` is not `lhs[i].height() x rhs[i].width()` for some `i`
+    /// - [MatrixError::Arithmetic]
+    ///   - if `lhs[i].width()` != `rhs[i].height()` for some `i`
+    pub fn batch_multiply_into(
+        lhs: &[Self],
+        rhs: &[Self],
+        products: &mut [Self],
+    ) -> Result<(), MatrixError> {
+        check_batch_into(lhs, rhs, products)?;
+
+        for ((lhs, rhs), product) in lhs.iter().zip(rhs).zip(products.iter_mut()) {
+            multiply_into(lhs, rhs, product);
+        }
+
+        return Ok(());
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<E: Num + Clone + Send + Sync> Matrix<E> {
+    /// Like [Matrix::batch_multiply_into] but the pairs are split across the rayon thread pool
+    /// ## Errors
+    /// - Same as [Matrix::batch_multiply_into]
+    pub fn par_batch_multiply_into(
+        lhs: &[Self],
+        rhs: &[Self],
+        products: &mut [Self],
+    ) -> Result<(), MatrixError> {
+        use rayon::prelude::*;
+
+        check_batch_into(lhs, rhs, products)?;
+
+        lhs.par_iter()
+            .zip(rhs)
+            .zip(products)
+            .for_each(|((lhs, rhs), product)| multiply_into(lhs, rhs, product));
+
+        return Ok(());
+    }
+}
+
+/// `product` = `lhs * rhs` where the dimensions have already been checked
+fn multiply_into<E: Num + Clone>(lhs: &Matrix<E>, rhs: &Matrix<E>, product: &mut Matrix<E>) {
+    for (lhs_row, product_row) in lhs.rows().zip(product.rows_mut()) {
+        for (column, product_element) in product_row.iter_mut().enumerate() {
+            *product_element = lhs_row
+                .iter()
+                .zip(rhs.column(column))
+                .fold(E::zero(), |sum, (lhs, rhs)| sum + lhs.clone() * rhs.clone());
+        }
+    }
+}
+
+fn check_batch<E>(lhs: &[Matrix<E>], rhs: &[Matrix<E>]) -> Result<(), MatrixError> {
+    if lhs.len() != rhs.len() {
+        return Err(DimensionError::UnexpectedLength {
+            expected: lhs.len(),
+            actual: rhs.len(),
+        }
+        .into());
+    }
+    for (lhs, rhs) in lhs.iter().zip(rhs) {
+        MatrixError::multiplication(lhs, rhs)?;
+    }
+    return Ok(());
+}
+
+fn check_batch_into<E>(
+    lhs: &[Matrix<E>],
+    rhs: &[Matrix<E>],
+    products: &[Matrix<E>],
+) -> Result<(), MatrixError> {
+    check_batch(lhs, rhs)?;
+    if lhs.len() != products.len() {
+        return Err(DimensionError::UnexpectedLength {
+            expected: lhs.len(),
+            actual: products.len(),
+        }
+        .into());
+    }
+    for ((lhs, rhs), product) in lhs.iter().zip(rhs).zip(products) {
+        let expected = Shape {
+            rows: lhs.height_nonzero(),
+            cols: rhs.width_nonzero(),
+        };
+        if product.shape() != expected {
+            return Err(DimensionError::DifferentDimensions {
+                lhs: expected,
+                rhs: product.shape(),
+            }
+            .into());
+        }
+    }
+    return Ok(());
+}
@@ -0,0 +1,99 @@
+use num::Num;
+
+use crate::{ColumnVector, Matrix, MatrixError, RowVector};
+
+/// Which way a vector is repeated across a [Matrix] by [Matrix::broadcast_op]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    /// The vector has one element per column and is applied to every row
+    Row,
+    /// The vector has one element per row and is applied to every column
+    Column,
+}
+
+impl<E: Clone> Matrix<E> {
+    /// Combine every element with the matching element of `vector` repeated along `axis` <br>
+    /// With [Axis::Row] `result[i][j]` == `op(self[i][j], vector[j])`,
+    /// with [Axis::Column] `result[i][j]` == `op(self[i][j], vector[i])`.
+    /// ## Errors
+    /// - [MatrixError::LengthMismatch]
+    ///   - if `axis` is [Axis::Row] and `vector.len()` != `self.width()`
+    ///   - if `axis` is [Axis::Column] and `vector.len()` != `self.height()`
+    pub fn broadcast_op(
+        &self,
+        axis: Axis,
+        vector: &[E],
+        op: impl Fn(E, E) -> E,
+    ) -> Result<Self, MatrixError> {
+        let (operation, expected) = match axis {
+            Axis::Row => ("broadcast a row", self.width()),
+            Axis::Column => ("broadcast a column", self.height()),
+        };
+        if vector.len() != expected {
+            return Err(MatrixError::LengthMismatch {
+                operation,
+                shape: self.shape(),
+                expected,
+                actual: vector.len(),
+            });
+        }
+
+        let mut result = self.clone();
+        for (index, element) in result.elements_mut_enumerated() {
+            let other = match axis {
+                Axis::Row => &vector[index.column()],
+                Axis::Column => &vector[index.row()],
+            };
+            *element = op(element.clone(), other.clone());
+        }
+
+        return Ok(result);
+    }
+}
+
+impl<E: Num + Clone> Matrix<E> {
+    /// Add `row` to every row
+    /// ## Errors
+    /// - [MatrixError::LengthMismatch]
+    ///   - if `row.len()` != `self.width()`
+    pub fn add_row_vector(&self, row: &RowVector<E>) -> Result<Self, MatrixError> {
+        return self.broadcast_op(Axis::Row, row.as_slice(), |a, b| a + b);
+    }
+    /// Subtract `row` from every row
+    /// ## Errors
+    /// - [MatrixError::LengthMismatch]
+    ///   - if `row.len()` != `self.width()`
+    pub fn sub_row_vector(&self, row: &RowVector<E>) -> Result<Self, MatrixError> {
+        return self.broadcast_op(Axis::Row, row.as_slice(), |a, b| a - b);
+    }
+    /// Scale column `j` by `row[j]`, the same as right multiplying by `diag(row)`
+    /// ## Errors
+    /// - [MatrixError::LengthMismatch]
+    ///   - if `row.len()` != `self.width()`
+    pub fn scale_columns(&self, row: &RowVector<E>) -> Result<Self, MatrixError> {
+        return self.broadcast_op(Axis::Row, row.as_slice(), |a, b| a * b);
+    }
+
+    /// Add `column` to every column
+    /// ## Errors
+    /// - [MatrixError::LengthMismatch]
+    ///   - if `column.len()` != `self.height()`
+    pub fn add_column_vector(&self, column: &ColumnVector<E>) -> Result<Self, MatrixError> {
+        return self.broadcast_op(Axis::Column, column.as_slice(), |a, b| a + b);
+    }
+    /// Subtract `column` from every column
+    /// ## Errors
+    /// - [MatrixError::LengthMismatch]
+    ///   - if `column.len()` != `self.height()`
+    pub fn sub_column_vector(&self, column: &ColumnVector<E>) -> Result<Self, MatrixError> {
+        return self.broadcast_op(Axis::Column, column.as_slice(), |a, b| a - b);
+    }
+    /// Scale row `i` by `column[i]`, the same as left multiplying by `diag(column)` <br>
+    /// Not to be confused with [Matrix::mul_column_vector], the matrix-vector product.
+    /// ## Errors
+    /// - [MatrixError::LengthMismatch]
+    ///   - if `column.len()` != `self.height()`
+    pub fn scale_rows(&self, column: &ColumnVector<E>) -> Result<Self, MatrixError> {
+        return self.broadcast_op(Axis::Column, column.as_slice(), |a, b| a * b);
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{DimensionError, Matrix, MatrixError};
+
+/// Builds a [Matrix] one row at a time when the rows are not known in advance <br>
+/// The first row fixes the width and every later row is checked against it as it is pushed.
+#[derive(Debug, Clone)]
+pub struct MatrixBuilder<E> {
+    rows: Vec<Box<[E]>>,
+}
+impl<E> Default for MatrixBuilder<E> {
+    fn default() -> Self {
+        return MatrixBuilder { rows: Vec::new() };
+    }
+}
+impl<E> MatrixBuilder<E> {
+    pub fn new() -> Self {
+        return Self::default();
+    }
+    /// A builder with room for `height` rows before reallocating
+    pub fn with_capacity(height: usize) -> Self {
+        return MatrixBuilder {
+            rows: Vec::with_capacity(height),
+        };
+    }
+
+    /// The number of rows pushed so far
+    pub fn height(&self) -> usize {
+        return self.rows.len();
+    }
+    /// The width fixed by the first row, [None] before any row is pushed
+    pub fn width(&self) -> Option<usize> {
+        return self.rows.first().map(|row| row.len());
+    }
+
+    /// Append `row` to the bottom of the matrix
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if `row` is the first row and it is empty
+    /// - [MatrixError::InconsistentRow]
+    ///   - if `row` does not have the same length as the first row
+    pub fn push_row(&mut self, row: Vec<E>) -> Result<&mut Self, MatrixError> {
+        match self.width() {
+            None if row.is_empty() => return Err(DimensionError::Zero.into()),
+            Some(width) if row.len() != width => {
+                return Err(MatrixError::InconsistentRow {
+                    row: self.height(),
+                    expected: width,
+                    actual: row.len(),
+                })
+            }
+            _ => self.rows.push(row.into_boxed_slice()),
+        }
+        return Ok(self);
+    }
+
+    /// Finish the [Matrix]
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if no rows were pushed
+    pub fn build(self) -> Result<Matrix<E>, MatrixError> {
+        if self.rows.is_empty() {
+            return Err(DimensionError::Zero.into());
+        }
+        return Ok(Matrix {
+            elements: self.rows.into_boxed_slice(),
+        });
+    }
+}
@@ -0,0 +1,134 @@
+use std::num::NonZeroUsize;
+
+use num::Float;
+
+use crate::{Matrix, ParseMatrixError, Shape};
+
+impl<F: Float> Matrix<F> {
+    /// A stable, exact text form for golden file tests <br>
+    /// The first line is `<height>x<width>` and each following line is one row of space separated elements.
+    /// Each element is written as a hex float `[-]0x<odd mantissa>p<exponent>` meaning `mantissa * 2^exponent`,
+    /// or `inf`, `-inf`, `nan`.
+    /// Every finite value has exactly one spelling and nothing depends on the locale,
+    /// so [Matrix::from_canonical_string] gives back the same bits (every `NaN` becomes the same `NaN`).
+    pub fn to_canonical_string(&self) -> String {
+        let mut canonical = format!("{}x{}\n", self.height(), self.width());
+        for row in self.rows() {
+            let elements = row
+                .iter()
+                .map(|&element| canonical_element(element))
+                .collect::<Vec<_>>();
+            canonical.push_str(&elements.join(" "));
+            canonical.push('\n');
+        }
+        return canonical;
+    }
+
+    /// Parse the output of [Matrix::to_canonical_string]
+    /// ## Errors
+    /// - [ParseMatrixError]
+    ///   - if the header is missing or is not `<height>x<width>` with non zero dimensions
+    ///   - if the number of rows or elements in a row does not match the header
+    ///   - if an element is not in the canonical form or does not fit in `F`
+    pub fn from_canonical_string(canonical: &str) -> Result<Self, ParseMatrixError> {
+        let mut lines = canonical.lines().enumerate();
+
+        let header = lines.next().map(|(_, line)| line).unwrap_or_default();
+        let (height, width) = header
+            .split_once('x')
+            .and_then(|(height, width)| {
+                Some((
+                    height.parse::<NonZeroUsize>().ok()?,
+                    width.parse::<NonZeroUsize>().ok()?,
+                ))
+            })
+            .ok_or_else(|| ParseMatrixError::invalid_header(1, header.to_string()))?;
+
+        let mut matrix = Matrix::zeros(Shape {
+            rows: height,
+            cols: width,
+        });
+        let mut row_count = 0;
+        for (line_index, line) in lines {
+            let line_number = line_index + 1;
+            if row_count == height.get() {
+                return Err(ParseMatrixError::unexpected_row_count(
+                    line_number,
+                    height.get(),
+                    row_count + 1,
+                ));
+            }
+
+            let elements = line.split(' ').collect::<Vec<_>>();
+            if elements.len() != width.get() {
+                return Err(ParseMatrixError::unexpected_column_count(
+                    line_number,
+                    width.get(),
+                    elements.len(),
+                ));
+            }
+            for (element, &text) in matrix[row_count].iter_mut().zip(&elements) {
+                *element = parse_canonical_element(text).ok_or_else(|| {
+                    ParseMatrixError::invalid_element(line_number, text.to_string())
+                })?;
+            }
+            row_count += 1;
+        }
+
+        if row_count != height.get() {
+            return Err(ParseMatrixError::unexpected_row_count(
+                row_count + 2,
+                height.get(),
+                row_count,
+            ));
+        }
+
+        return Ok(matrix);
+    }
+}
+
+fn canonical_element<F: Float>(element: F) -> String {
+    if element.is_nan() {
+        return "nan".to_string();
+    }
+    let sign = if element.is_sign_negative() { "-" } else { "" };
+    if element.is_infinite() {
+        return format!("{sign}inf");
+    }
+
+    let (mut mantissa, mut exponent, _) = element.integer_decode();
+    if mantissa == 0 {
+        return format!("{sign}0x0p0");
+    }
+    let trailing_zeros = mantissa.trailing_zeros();
+    mantissa >>= trailing_zeros;
+    exponent += trailing_zeros as i16;
+
+    return format!("{sign}0x{mantissa:x}p{exponent}");
+}
+
+fn parse_canonical_element<F: Float>(text: &str) -> Option<F> {
+    match text {
+        "nan" => return Some(F::nan()),
+        "inf" => return Some(F::infinity()),
+        "-inf" => return Some(F::neg_infinity()),
+        _ => {}
+    }
+
+    let (negative, unsigned) = match text.strip_prefix('-') {
+        Some(unsigned) => (true, unsigned),
+        None => (false, text),
+    };
+    let (mantissa, exponent) = unsigned.strip_prefix("0x")?.split_once('p')?;
+    let mantissa = u64::from_str_radix(mantissa, 16).ok()?;
+    let exponent = exponent.parse::<i32>().ok()?;
+
+    // applied in two halves so the intermediate power of 2 does not overflow or underflow
+    let two = F::one() + F::one();
+    let half = exponent / 2;
+    let magnitude = F::from(mantissa)? * two.powi(half) * two.powi(exponent - half);
+    let value = if negative { -magnitude } else { magnitude };
+
+    // only the exact canonical spelling is accepted, this also rejects values that do not fit in `F`
+    return (canonical_element(value) == text).then_some(value);
+}
@@ -0,0 +1,57 @@
+use std::{num::NonZeroUsize, ops::Range};
+
+use crate::{Lu, Matrix, MatrixError, MatrixPool, SymmetricEigen, Vector};
+
+/// Thread safety guarantees <br>
+/// [Matrix] owns its elements and has no interior mutability, so it is [Send] and [Sync]
+/// whenever its element type is. Every method that takes `&self` only reads,
+/// so one matrix (for example behind an [std::sync::Arc]) can be read from any number of threads at once.
+/// [Matrix::row_range] and [Matrix::row_shards] split the rows so each thread can work on its own part.
+impl<E> Matrix<E> {
+    /// The rows in `rows`, borrowed
+    /// ## Errors
+    /// - [MatrixError::IndexOutOfBounds]
+    ///   - if `rows.end` is greater than `self.height()`
+    ///   - if `rows.start` is greater than `rows.end`
+    pub fn row_range(
+        &self,
+        rows: Range<usize>,
+    ) -> Result<impl Iterator<Item = &[E]> + Clone, MatrixError> {
+        if rows.end > self.height() || rows.start > rows.end {
+            return Err(MatrixError::IndexOutOfBounds {
+                index: rows.end.max(rows.start),
+                length: self.height(),
+            });
+        }
+        return Ok(self.elements[rows].iter().map(|row| row.as_ref()));
+    }
+
+    /// Split the rows into at most `count` contiguous ranges whose heights differ by at most one <br>
+    /// Pass each range to [Matrix::row_range] on a different thread.
+    pub fn row_shards(&self, count: NonZeroUsize) -> Vec<Range<usize>> {
+        let count = count.get().min(self.height());
+        let (base, remainder) = (self.height() / count, self.height() % count);
+
+        let mut start = 0;
+        return (0..count)
+            .map(|shard| {
+                let end = start + base + usize::from(shard < remainder);
+                let range = start..end;
+                start = end;
+                range
+            })
+            .collect();
+    }
+}
+
+/// Fails to compile if any of these types stop being [Send] and [Sync] for thread safe elements
+#[allow(dead_code)]
+fn assert_send_sync<E: Send + Sync, O: Send + Sync>() {
+    fn is_send_sync<T: Send + Sync>() {}
+    is_send_sync::<Matrix<E>>();
+    is_send_sync::<Vector<E, O>>();
+    is_send_sync::<MatrixPool<E>>();
+    is_send_sync::<Lu<E>>();
+    is_send_sync::<SymmetricEigen<E>>();
+    is_send_sync::<MatrixError>();
+}
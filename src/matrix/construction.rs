@@ -0,0 +1,88 @@
+use std::num::NonZeroUsize;
+
+use num::Num;
+
+use crate::{DimensionError, Matrix, MatrixError, Shape};
+
+impl<E> Matrix<E> {
+    /// Constructs a matrix from a list of rows
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if there are no rows or the first row is empty
+    /// - [MatrixError::InconsistentRow]
+    ///   - if any row has a different length than the first
+    pub fn try_from_rows(rows: Vec<Vec<E>>) -> Result<Self, MatrixError> {
+        let width = rows.first().map_or(0, Vec::len);
+        if width == 0 {
+            return Err(DimensionError::Zero.into());
+        }
+        if let Some(row) = rows.iter().position(|row| row.len() != width) {
+            return Err(MatrixError::InconsistentRow {
+                row,
+                expected: width,
+                actual: rows[row].len(),
+            });
+        }
+
+        return Ok(Matrix {
+            elements: rows.into_iter().map(Vec::into_boxed_slice).collect(),
+        });
+    }
+}
+
+impl<E: Num + Clone> Matrix<E> {
+    /// [Matrix::zeros] for dimensions that are not known to be non zero
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if `height` or `width` is `0`
+    pub fn try_zeros(height: usize, width: usize) -> Result<Self, MatrixError> {
+        return Ok(Matrix::zeros(Shape::try_new(height, width)?));
+    }
+
+    /// [Matrix::identity] for a size that is not known to be non zero
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if `size` is `0`
+    pub fn try_identity(size: usize) -> Result<Self, MatrixError> {
+        let size = NonZeroUsize::new(size).ok_or(DimensionError::Zero)?;
+        return Ok(Matrix::identity(size));
+    }
+
+    /// Constructs a matrix with each of `blocks` placed along the diagonal and zeros elsewhere <br>
+    /// The result is `Σ heights x Σ widths`.
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if `blocks` is empty
+    pub fn block_diagonal(blocks: &[&Self]) -> Result<Self, MatrixError> {
+        let height = blocks.iter().map(|block| block.height()).sum();
+        let width = blocks.iter().map(|block| block.width()).sum();
+        let (Some(height), Some(width)) = (NonZeroUsize::new(height), NonZeroUsize::new(width))
+        else {
+            return Err(DimensionError::Zero.into());
+        };
+
+        let mut block_diagonal = Matrix::zeros(Shape {
+            rows: height,
+            cols: width,
+        });
+
+        let (mut row_offset, mut column_offset) = (0, 0);
+        for block in blocks {
+            for (index, element) in block.elements_enumerated() {
+                block_diagonal[row_offset + index.row()][column_offset + index.column()] =
+                    element.clone();
+            }
+            row_offset += block.height();
+            column_offset += block.width();
+        }
+
+        return Ok(block_diagonal);
+    }
+
+    /// The direct sum `self ⊕ other` <br>
+    /// `self` in the top left, `other` in the bottom right, and zeros elsewhere
+    pub fn direct_sum(&self, other: &Self) -> Self {
+        return Matrix::block_diagonal(&[self, other])
+            .expect("both matrices have non zero dimensions");
+    }
+}
@@ -0,0 +1,101 @@
+use num::{traits::AsPrimitive, Float, NumCast, ToPrimitive};
+
+use crate::{Matrix, MatrixError};
+
+impl<E> Matrix<E> {
+    /// Build a new matrix with the same dimensions by applying `f` to each element
+    pub fn map<T>(&self, mut f: impl FnMut(&E) -> T) -> Matrix<T> {
+        return Matrix {
+            elements: self
+                .rows()
+                .map(|row| row.iter().map(&mut f).collect())
+                .collect(),
+        };
+    }
+
+    /// Build a new matrix with the same dimensions by applying `f` to each element until one fails
+    /// ## Errors
+    /// - The first error returned by `f`.
+    pub fn try_map<T, Error>(
+        &self,
+        mut f: impl FnMut(&E) -> Result<T, Error>,
+    ) -> Result<Matrix<T>, Error> {
+        return Ok(Matrix {
+            elements: self
+                .rows()
+                .map(|row| row.iter().map(&mut f).collect())
+                .collect::<Result<_, _>>()?,
+        });
+    }
+}
+
+impl<E: Clone> Matrix<E> {
+    /// Losslessly convert each element (e.g. `Matrix<i32>` to `Matrix<f64>`)
+    pub fn cast<T: From<E>>(&self) -> Matrix<T> {
+        return self.map(|element| T::from(element.clone()));
+    }
+}
+
+impl<E: Copy> Matrix<E> {
+    /// Convert each element with the semantics of an `as` cast,
+    /// so out of range values saturate or wrap and floats lose precision
+    /// (e.g. `Matrix<f64>` to `Matrix<f32>`)
+    pub fn cast_lossy<T: Copy + 'static>(&self) -> Matrix<T>
+    where
+        E: AsPrimitive<T>,
+    {
+        return self.map(|&element| element.as_());
+    }
+}
+
+impl<E: ToPrimitive + Copy> Matrix<E> {
+    /// Convert each element, failing if any value can not be represented by `T`
+    /// (e.g. `Matrix<f64>` to `Matrix<i32>` fails on `NaN`, `∞`, or values beyond `i32::MAX`)
+    /// ## Errors
+    /// - [MatrixError::Cast]
+    ///   - with the index of the first element that does not fit
+    pub fn try_cast<T: NumCast>(&self) -> Result<Matrix<T>, MatrixError> {
+        return Ok(Matrix {
+            elements: self
+                .rows()
+                .enumerate()
+                .map(|(row, elements)| {
+                    elements
+                        .iter()
+                        .enumerate()
+                        .map(|(column, &element)| {
+                            T::from(element).ok_or(MatrixError::Cast { row, column })
+                        })
+                        .collect()
+                })
+                .collect::<Result<_, _>>()?,
+        });
+    }
+}
+
+impl<F: Float> Matrix<F> {
+    /// Map `[min, max]` linearly onto `0..=255`, such as for exporting a heatmap as a grayscale image <br>
+    /// Elements outside the range saturate to `0` or `255` and `NaN` becomes `0`.
+    /// ## Panics
+    /// - if `min` >= `max`
+    pub fn to_u8_scaled(&self, min: F, max: F) -> Matrix<u8> {
+        assert!(min < max, "to_u8_scaled requires min < max");
+        let levels = F::from(u8::MAX).expect("255 fits in a float");
+        return self.map(|&element| {
+            let fraction = ((element - min) / (max - min)).max(F::zero()).min(F::one());
+            (fraction * levels)
+                .round()
+                .to_u8()
+                .expect("the fraction was clamped to [0, 1]")
+        });
+    }
+
+    /// The inverse of [Matrix::to_u8_scaled], map `0..=255` linearly onto `[min, max]`
+    pub fn from_u8_scaled(bytes: &Matrix<u8>, min: F, max: F) -> Self {
+        let levels = F::from(u8::MAX).expect("255 fits in a float");
+        return bytes.map(|&byte| {
+            let fraction = F::from(byte).expect("a byte fits in a float") / levels;
+            min + fraction * (max - min)
+        });
+    }
+}
@@ -0,0 +1,69 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::{Matrix, ParseMatrixError};
+
+impl<E: FromStr> Matrix<E> {
+    /// Parse one row per line with elements separated by commas <br>
+    /// Whitespace around elements and blank lines are ignored.
+    /// ```csv
+    /// 1.0, 2.0, 3.0
+    /// 4.0, 5.0, 6.0
+    /// ```
+    /// ## Errors
+    /// - [ParseMatrixError]
+    ///   - if there are no rows
+    ///   - if a row does not have as many elements as the first row
+    ///   - if an element can not be parsed as `E`
+    pub fn from_csv_str(csv: &str) -> Result<Self, ParseMatrixError> {
+        let mut rows: Vec<Box<[E]>> = Vec::new();
+
+        for (line_index, line) in csv.lines().enumerate() {
+            let line_number = line_index + 1;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let row = line
+                .split(',')
+                .map(|text| {
+                    let text = text.trim();
+                    text.parse::<E>().map_err(|_| {
+                        ParseMatrixError::invalid_element(line_number, text.to_string())
+                    })
+                })
+                .collect::<Result<Box<[E]>, _>>()?;
+
+            if let Some(first) = rows.first() {
+                if row.len() != first.len() {
+                    return Err(ParseMatrixError::unexpected_column_count(
+                        line_number,
+                        first.len(),
+                        row.len(),
+                    ));
+                }
+            }
+            rows.push(row);
+        }
+
+        if rows.is_empty() {
+            return Err(ParseMatrixError::empty(csv.lines().count() + 1));
+        }
+
+        return Ok(Matrix {
+            elements: rows.into(),
+        });
+    }
+}
+
+impl<E: Display> Matrix<E> {
+    /// Write one row per line with elements separated by `", "`, the format [Matrix::from_csv_str] reads
+    pub fn to_csv_string(&self) -> String {
+        let mut csv = String::new();
+        for row in self.rows() {
+            let elements = row.iter().map(E::to_string).collect::<Vec<_>>();
+            csv.push_str(&elements.join(", "));
+            csv.push('\n');
+        }
+        return csv;
+    }
+}
@@ -0,0 +1,647 @@
+use std::cmp::Ordering;
+
+use num::{Complex, Float};
+
+use crate::{
+    matrix::sort::compare_nan_last, DecompositionError, DimensionError, Givens, Householder,
+    Matrix, MatrixError, MatrixOperand, Shape, SortDirection,
+};
+
+/// The maximum number of Jacobi sweeps before giving up
+const MAX_JACOBI_SWEEPS: usize = 100;
+
+/// The maximum number of Francis QR steps spent on one eigenvalue before giving up
+const MAX_SCHUR_ITERATIONS: usize = 100;
+
+/// The eigenvalues and eigenvectors of a symmetric [Matrix]
+#[derive(Debug, Clone)]
+pub struct SymmetricEigen<F> {
+    eigenvalues: Vec<F>,
+    eigenvectors: Matrix<F>,
+}
+impl<F> SymmetricEigen<F> {
+    /// The eigenvalues in ascending order
+    pub fn eigenvalues(&self) -> &[F] {
+        return &self.eigenvalues;
+    }
+    /// Each column is the unit eigenvector of the eigenvalue with the same index
+    pub fn eigenvectors(&self) -> &Matrix<F> {
+        return &self.eigenvectors;
+    }
+}
+
+/// The LU factorization with partial pivoting of a square [Matrix] <br>
+/// `P * A` == `L * U`
+#[derive(Debug, Clone)]
+pub struct Lu<F> {
+    /// `L` below the diagonal (its unit diagonal is implied) and `U` on and above it
+    factors: Matrix<F>,
+    /// `permutation[i]` is the row of `A` that ended up in row `i`
+    permutation: Vec<usize>,
+    /// `1` for an even number of row swaps and `-1` for odd
+    permutation_sign: F,
+}
+impl<F: Float> Lu<F> {
+    /// The unit lower triangular factor `L`
+    pub fn lower(&self) -> Matrix<F> {
+        let mut lower = Matrix::identity(self.factors.height_nonzero());
+        for (index, element) in lower.elements_mut_enumerated() {
+            if index.column() < index.row() {
+                *element = self.factors[index];
+            }
+        }
+        return lower;
+    }
+    /// The upper triangular factor `U`
+    pub fn upper(&self) -> Matrix<F> {
+        let mut upper = self.factors.clone();
+        for (index, element) in upper.elements_mut_enumerated() {
+            if index.column() < index.row() {
+                *element = F::zero();
+            }
+        }
+        return upper;
+    }
+    /// `permutation[i]` is the row of the original matrix that ended up in row `i`
+    pub fn permutation(&self) -> &[usize] {
+        return &self.permutation;
+    }
+    /// The diagonal of `U`
+    pub fn pivots(&self) -> impl Iterator<Item = F> + '_ {
+        return (0..self.factors.height()).map(|index| self.factors[index][index]);
+    }
+    /// `true` if any pivot is exactly `0`
+    pub fn is_singular(&self) -> bool {
+        return self.pivots().any(|pivot| pivot.is_zero());
+    }
+    /// The determinant of the factored matrix, the signed product of the pivots
+    pub fn determinant(&self) -> F {
+        return self
+            .pivots()
+            .fold(self.permutation_sign, |product, pivot| product * pivot);
+    }
+    /// Solve `A * X` == `b` for `X` by forward then backward substitution
+    /// ## Parameters
+    /// - `b`: the right hand side, one system per column. Any [MatrixOperand] such as a [crate::TransposedView] is accepted.
+    /// ## Returns
+    /// - `X` with the same dimensions as `b`.
+    /// ## Errors
+    /// - [MatrixError::Arithmetic]
+    ///   - if `b.height()` != `A.width()`
+    /// - [MatrixError::Decomposition]
+    ///   - if `A` is singular
+    pub fn solve(&self, b: &impl MatrixOperand<F>) -> Result<Matrix<F>, MatrixError> {
+        self.check_solvable(b)?;
+        let solution = Matrix::zeros(Shape {
+            rows: self.factors.height_nonzero(),
+            cols: b.shape().cols,
+        });
+        return Ok(self.substitute(b, solution));
+    }
+    /// The errors of [Lu::solve]
+    pub(crate) fn check_solvable(&self, b: &impl MatrixOperand<F>) -> Result<(), MatrixError> {
+        MatrixError::multiplication(&self.factors, b)?;
+        if self.is_singular() {
+            Err(DecompositionError::Singular)?
+        }
+        return Ok(());
+    }
+    /// Overwrite `solution` with `X` from `A * X` == `b` <br>
+    /// `b` must already be checked against `A` and `solution` must have the dimensions of `b`.
+    pub(crate) fn substitute(
+        &self,
+        b: &impl MatrixOperand<F>,
+        mut solution: Matrix<F>,
+    ) -> Matrix<F> {
+        let size = self.factors.height();
+        for (row, &original_row) in self.permutation.iter().enumerate() {
+            for column in 0..b.width() {
+                solution[row][column] = *b.element(original_row, column);
+            }
+        }
+
+        for column in 0..b.width() {
+            // L * y = P * b
+            for row in 0..size {
+                let mut sum = solution[row][column];
+                for k in 0..row {
+                    sum = sum - self.factors[row][k] * solution[k][column];
+                }
+                solution[row][column] = sum;
+            }
+            // U * x = y
+            for row in (0..size).rev() {
+                let mut sum = solution[row][column];
+                for k in (row + 1)..size {
+                    sum = sum - self.factors[row][k] * solution[k][column];
+                }
+                solution[row][column] = sum / self.factors[row][row];
+            }
+        }
+
+        return solution;
+    }
+    /// Factor the square matrix `factors` in place
+    pub(crate) fn factor(mut factors: Matrix<F>) -> Self {
+        let size = factors.height();
+        let mut permutation = (0..size).collect::<Vec<_>>();
+        let mut permutation_sign = F::one();
+
+        for column in 0..size {
+            let pivot_row = (column..size)
+                .max_by(|&i, &j| {
+                    factors[i][column]
+                        .abs()
+                        .partial_cmp(&factors[j][column].abs())
+                        .unwrap_or(Ordering::Equal)
+                })
+                .expect("column < size so the range is not empty");
+
+            if pivot_row != column {
+                factors.elements.swap(pivot_row, column);
+                permutation.swap(pivot_row, column);
+                permutation_sign = -permutation_sign;
+            }
+
+            let pivot = factors[column][column];
+            if pivot.is_zero() {
+                continue;
+            }
+
+            for row in (column + 1)..size {
+                let multiplier = factors[row][column] / pivot;
+                factors[row][column] = multiplier;
+                for k in (column + 1)..size {
+                    factors[row][k] = factors[row][k] - multiplier * factors[column][k];
+                }
+            }
+        }
+
+        return Lu {
+            factors,
+            permutation,
+            permutation_sign,
+        };
+    }
+    /// Give back the buffer holding `L` and `U`
+    pub(crate) fn into_factors(self) -> Matrix<F> {
+        return self.factors;
+    }
+    /// The sign (`-1`, `0`, or `1`) and natural log of the absolute value of the determinant
+    pub fn log_determinant(&self) -> (F, F) {
+        if self.is_singular() {
+            return (F::zero(), F::neg_infinity());
+        }
+
+        let mut sign = self.permutation_sign;
+        let mut log_absolute = F::zero();
+        for pivot in self.pivots() {
+            sign = sign * pivot.signum();
+            log_absolute = log_absolute + pivot.abs().ln();
+        }
+
+        return (sign, log_absolute);
+    }
+}
+
+/// A square [Matrix] reduced by an orthogonal similarity transform <br>
+/// `A` == `Q * R * Qᵀ` where `R` is the reduced form
+#[derive(Debug, Clone)]
+pub struct OrthogonalReduction<F> {
+    reduced: Matrix<F>,
+    transform: Matrix<F>,
+}
+impl<F> OrthogonalReduction<F> {
+    /// The reduced form `R` (upper Hessenberg or tridiagonal)
+    pub fn reduced(&self) -> &Matrix<F> {
+        return &self.reduced;
+    }
+    /// The orthogonal `Q`
+    pub fn transform(&self) -> &Matrix<F> {
+        return &self.transform;
+    }
+    /// Take ownership of `(R, Q)`
+    pub fn into_parts(self) -> (Matrix<F>, Matrix<F>) {
+        return (self.reduced, self.transform);
+    }
+}
+
+/// The real Schur decomposition of a square [Matrix] <br>
+/// `A` == `Q * T * Qᵀ` where `Q` is orthogonal and `T` is quasi upper triangular:
+/// upper triangular except for `2 x 2` diagonal blocks holding complex conjugate eigenvalue pairs
+#[derive(Debug, Clone)]
+pub struct Schur<F> {
+    q: Matrix<F>,
+    t: Matrix<F>,
+}
+impl<F: Float> Schur<F> {
+    /// The orthogonal `Q`
+    pub fn q(&self) -> &Matrix<F> {
+        return &self.q;
+    }
+    /// The quasi upper triangular `T`
+    pub fn t(&self) -> &Matrix<F> {
+        return &self.t;
+    }
+    /// Take ownership of `(Q, T)`
+    pub fn into_parts(self) -> (Matrix<F>, Matrix<F>) {
+        return (self.q, self.t);
+    }
+    /// The eigenvalues read off the diagonal blocks of `T`, in diagonal order
+    pub fn eigenvalues(&self) -> Vec<Complex<F>> {
+        let size = self.t.height();
+        let mut eigenvalues = Vec::with_capacity(size);
+
+        let mut index = 0;
+        while index < size {
+            if index + 1 < size && !self.t[index + 1][index].is_zero() {
+                let (a, b) = (self.t[index][index], self.t[index][index + 1]);
+                let (c, d) = (self.t[index + 1][index], self.t[index + 1][index + 1]);
+                let two = F::one() + F::one();
+                let real = (a + d) / two;
+                let imaginary = (-((a - d) / two).powi(2) - b * c).max(F::zero()).sqrt();
+                eigenvalues.push(Complex::new(real, imaginary));
+                eigenvalues.push(Complex::new(real, -imaginary));
+                index += 2;
+            } else {
+                eigenvalues.push(Complex::new(self.t[index][index], F::zero()));
+                index += 1;
+            }
+        }
+
+        return eigenvalues;
+    }
+}
+
+impl<F: Float> Matrix<F> {
+    /// Calculate the eigendecomposition of a symmetric matrix using the cyclic Jacobi method <br>
+    /// `self` == `V * diag(λ) * Vᵀ`
+    /// ## Returns
+    /// - A [SymmetricEigen] with the eigenvalues sorted in ascending order.
+    /// ## Errors
+    /// - [MatrixError::Decomposition]
+    ///   - if `self` is not square or not symmetric
+    ///   - if the off diagonal elements do not vanish after [MAX_JACOBI_SWEEPS] sweeps
+    pub fn symmetric_eigen(&self) -> Result<SymmetricEigen<F>, MatrixError> {
+        MatrixError::symmetric_eigen(self)?;
+
+        let size = self.height();
+        let mut a = self.clone();
+        let mut v = Matrix::identity(self.height_nonzero());
+
+        let total = a
+            .elements()
+            .fold(F::zero(), |sum, &element| sum + element * element);
+
+        let mut converged = false;
+        for _ in 0..MAX_JACOBI_SWEEPS {
+            let mut off_diagonal = F::zero();
+            for (index, &element) in a.elements_enumerated() {
+                if index.row() != index.column() {
+                    off_diagonal = off_diagonal + element * element;
+                }
+            }
+            if off_diagonal <= F::epsilon() * F::epsilon() * total {
+                converged = true;
+                break;
+            }
+
+            for p in 0..size {
+                for q in (p + 1)..size {
+                    if a[p][q].is_zero() {
+                        continue;
+                    }
+
+                    let two = F::one() + F::one();
+                    let theta = (a[q][q] - a[p][p]) / (two * a[p][q]);
+                    let t = theta.signum() / (theta.abs() + (theta * theta + F::one()).sqrt());
+                    let c = F::one() / (t * t + F::one()).sqrt();
+                    let s = t * c;
+
+                    // a = a * J
+                    for k in 0..size {
+                        let (akp, akq) = (a[k][p], a[k][q]);
+                        a[k][p] = c * akp - s * akq;
+                        a[k][q] = s * akp + c * akq;
+                    }
+                    // a = Jᵀ * a
+                    for k in 0..size {
+                        let (apk, aqk) = (a[p][k], a[q][k]);
+                        a[p][k] = c * apk - s * aqk;
+                        a[q][k] = s * apk + c * aqk;
+                    }
+                    // v = v * J
+                    for k in 0..size {
+                        let (vkp, vkq) = (v[k][p], v[k][q]);
+                        v[k][p] = c * vkp - s * vkq;
+                        v[k][q] = s * vkp + c * vkq;
+                    }
+                }
+            }
+        }
+        if !converged {
+            Err(DecompositionError::NoConvergence(MAX_JACOBI_SWEEPS))?
+        }
+
+        let mut order = (0..size).collect::<Vec<_>>();
+        order.sort_by(|&i, &j| compare_nan_last(&a[i][i], &a[j][j], SortDirection::Ascending));
+
+        let eigenvalues = order.iter().map(|&i| a[i][i]).collect();
+        let mut eigenvectors = Matrix::zeros(self.shape());
+        for (new_column, &old_column) in order.iter().enumerate() {
+            for row in 0..size {
+                eigenvectors[row][new_column] = v[row][old_column];
+            }
+        }
+
+        return Ok(SymmetricEigen {
+            eigenvalues,
+            eigenvectors,
+        });
+    }
+
+    /// Calculate the Cholesky factor of a symmetric positive definite matrix <br>
+    /// `self` == `L * Lᵀ`
+    /// ## Returns
+    /// - The lower triangular `L` with a positive diagonal.
+    /// ## Errors
+    /// - [MatrixError::Decomposition]
+    ///   - if `self` is not square or not symmetric
+    ///   - if `self` is not positive definite
+    pub fn cholesky(&self) -> Result<Self, MatrixError> {
+        MatrixError::symmetric_eigen(self)?;
+
+        let size = self.height();
+        let mut lower = Matrix::zeros(self.shape());
+
+        for row in 0..size {
+            for column in 0..=row {
+                let mut sum = self[row][column];
+                for k in 0..column {
+                    sum = sum - lower[row][k] * lower[column][k];
+                }
+
+                if row == column {
+                    if sum <= F::zero() || sum.is_nan() {
+                        Err(DecompositionError::NotPositiveDefinite)?
+                    }
+                    lower[row][column] = sum.sqrt();
+                } else {
+                    lower[row][column] = sum / lower[column][column];
+                }
+            }
+        }
+
+        return Ok(lower);
+    }
+
+    /// Calculate the LU factorization with partial pivoting <br>
+    /// At each column the row with the largest magnitude pivot is swapped into place.
+    /// A singular matrix still factors, it just has a `0` pivot.
+    /// ## Returns
+    /// - The [Lu] factorization.
+    /// ## Errors
+    /// - [MatrixError::Decomposition]
+    ///   - if `self` is not square
+    pub fn lu(&self) -> Result<Lu<F>, MatrixError> {
+        if self.width() != self.height() {
+            Err(DecompositionError::from(DimensionError::NotSquare))?
+        }
+
+        return Ok(Lu::factor(self.clone()));
+    }
+
+    /// Calculate the sign and natural log of the absolute value of the determinant <br>
+    /// Computed from the pivots of [Matrix::lu] so it does not overflow or underflow
+    /// for large or badly scaled matrices the way [Matrix::determinant] does.
+    /// ## Returns
+    /// - `(sign, ln|det|)` where `sign` is `-1`, `0`, or `1`.
+    ///   A singular matrix gives `(0, -∞)`.
+    /// ## Errors
+    /// - [MatrixError::Determinant]
+    ///   - if `self` is not square
+    pub fn log_determinant(&self) -> Result<(F, F), MatrixError> {
+        MatrixError::determinant(self)?;
+
+        return Ok(self.lu()?.log_determinant());
+    }
+
+    /// Reduce to upper Hessenberg form (zero below the first subdiagonal) with Householder reflectors <br>
+    /// The eigenvalues are unchanged so this is the first step of the QR algorithm.
+    /// ## Returns
+    /// - The [OrthogonalReduction] where `R` is upper Hessenberg.
+    /// ## Errors
+    /// - [MatrixError::Decomposition]
+    ///   - if `self` is not square
+    pub fn hessenberg(&self) -> Result<OrthogonalReduction<F>, MatrixError> {
+        if self.width() != self.height() {
+            Err(DecompositionError::from(DimensionError::NotSquare))?
+        }
+
+        let size = self.height();
+        let mut reduced = self.clone();
+        let mut transform = Matrix::identity(self.height_nonzero());
+
+        for column in 0..size.saturating_sub(2) {
+            let below = ((column + 1)..size)
+                .map(|row| reduced[row][column])
+                .collect::<Vec<_>>();
+            let (reflector, _) = Householder::zeroing(&below, column + 1)?;
+
+            reflector.apply_left(&mut reduced)?;
+            reflector.apply_right(&mut reduced)?;
+            reflector.apply_right(&mut transform)?;
+
+            // these are zero up to rounding error
+            for row in (column + 2)..size {
+                reduced[row][column] = F::zero();
+            }
+        }
+
+        return Ok(OrthogonalReduction { reduced, transform });
+    }
+
+    /// Reduce a symmetric matrix to symmetric tridiagonal form with Householder reflectors
+    /// ## Returns
+    /// - The [OrthogonalReduction] where `R` is symmetric tridiagonal.
+    /// ## Errors
+    /// - [MatrixError::Decomposition]
+    ///   - if `self` is not square or not symmetric
+    pub fn tridiagonal(&self) -> Result<OrthogonalReduction<F>, MatrixError> {
+        MatrixError::symmetric_eigen(self)?;
+
+        let (mut reduced, transform) = self.hessenberg()?.into_parts();
+
+        // symmetry is preserved so above the superdiagonal is also zero up to rounding error
+        for (index, element) in reduced.elements_mut_enumerated() {
+            if index.column() > index.row() + 1 {
+                *element = F::zero();
+            }
+        }
+        for row in 1..self.height() {
+            reduced[row - 1][row] = reduced[row][row - 1];
+        }
+
+        return Ok(OrthogonalReduction { reduced, transform });
+    }
+
+    /// Calculate the real Schur decomposition with the Francis double shift QR algorithm <br>
+    /// `self` is first reduced with [Matrix::hessenberg].
+    /// Any `2 x 2` diagonal block of `T` with real eigenvalues is split so only
+    /// complex conjugate pairs remain as blocks.
+    /// ## Returns
+    /// - The [Schur] decomposition.
+    /// ## Errors
+    /// - [MatrixError::Decomposition]
+    ///   - if `self` is not square
+    ///   - if an eigenvalue does not converge after [MAX_SCHUR_ITERATIONS] steps
+    pub fn schur(&self) -> Result<Schur<F>, MatrixError> {
+        let (mut t, mut q) = self.hessenberg()?.into_parts();
+        let size = self.height();
+
+        let norm = t
+            .elements()
+            .fold(F::zero(), |sum, &element| sum + element * element)
+            .sqrt();
+
+        let mut end = size - 1;
+        let mut iterations = 0;
+        while end > 0 {
+            // find the start of the unreduced block that ends at `end`
+            let mut start = end;
+            while start > 0 {
+                let mut scale = t[start - 1][start - 1].abs() + t[start][start].abs();
+                if scale.is_zero() {
+                    scale = norm;
+                }
+                if t[start][start - 1].abs() <= F::epsilon() * scale {
+                    t[start][start - 1] = F::zero();
+                    break;
+                }
+                start -= 1;
+            }
+
+            if start == end {
+                end -= 1;
+                iterations = 0;
+                continue;
+            }
+            if start + 1 == end {
+                split_real_block(&mut t, &mut q, start)?;
+                end = end.saturating_sub(2);
+                iterations = 0;
+                continue;
+            }
+
+            iterations += 1;
+            if iterations > MAX_SCHUR_ITERATIONS {
+                Err(DecompositionError::NoConvergence(MAX_SCHUR_ITERATIONS))?
+            }
+
+            francis_step(&mut t, &mut q, start, end, iterations)?;
+        }
+
+        return Ok(Schur { q, t });
+    }
+}
+
+/// One implicit double shift QR step on the unreduced Hessenberg block `t[start..=end]`
+fn francis_step<F: Float>(
+    t: &mut Matrix<F>,
+    q: &mut Matrix<F>,
+    start: usize,
+    end: usize,
+    iterations: usize,
+) -> Result<(), MatrixError> {
+    let middle = end - 1;
+
+    // the shifts are the eigenvalues of the trailing 2 x 2 block, given by their sum and product
+    let (mut sum, mut product) = (
+        t[middle][middle] + t[end][end],
+        t[middle][middle] * t[end][end] - t[middle][end] * t[end][middle],
+    );
+    // an exceptional shift breaks cycles that the standard shift can get stuck in
+    if iterations.is_multiple_of(10) {
+        let exceptional = t[end][middle].abs() + t[middle][middle.saturating_sub(1)].abs();
+        sum = F::from(1.5).expect("1.5 is a float") * exceptional;
+        product = exceptional * exceptional;
+    }
+
+    let mut x = t[start][start] * t[start][start] + t[start][start + 1] * t[start + 1][start]
+        - sum * t[start][start]
+        + product;
+    let mut y = t[start + 1][start] * (t[start][start] + t[start + 1][start + 1] - sum);
+    let mut z = t[start + 1][start] * t[start + 2][start + 1];
+
+    for k in start..(end - 1) {
+        let (reflector, _) = Householder::zeroing(&[x, y, z], k)?;
+        reflector.apply_left(t)?;
+        reflector.apply_right(t)?;
+        reflector.apply_right(q)?;
+
+        x = t[k + 1][k];
+        y = t[k + 2][k];
+        if k + 3 <= end {
+            z = t[k + 3][k];
+        }
+    }
+
+    let (reflector, _) = Householder::zeroing(&[x, y], end - 1)?;
+    reflector.apply_left(t)?;
+    reflector.apply_right(t)?;
+    reflector.apply_right(q)?;
+
+    // the bulge has been chased off so anything below the subdiagonal is rounding error
+    for (index, element) in t.elements_mut_enumerated() {
+        if index.row() > index.column() + 1 {
+            *element = F::zero();
+        }
+    }
+
+    return Ok(());
+}
+
+/// Rotate the `2 x 2` diagonal block at `t[index..=index + 1]` to upper triangular if its eigenvalues are real
+fn split_real_block<F: Float>(
+    t: &mut Matrix<F>,
+    q: &mut Matrix<F>,
+    index: usize,
+) -> Result<(), MatrixError> {
+    let next = index + 1;
+    let (a, b) = (t[index][index], t[index][next]);
+    let (c, d) = (t[next][index], t[next][next]);
+
+    let two = F::one() + F::one();
+    let half_difference = (a - d) / two;
+    let discriminant = half_difference * half_difference + b * c;
+    if discriminant < F::zero() {
+        return Ok(());
+    }
+
+    // the eigenvalue of larger magnitude avoids cancellation
+    let root = discriminant.sqrt();
+    let eigenvalue = (a + d) / two
+        + if half_difference >= F::zero() {
+            root
+        } else {
+            -root
+        };
+
+    // an eigenvector for `eigenvalue` becomes the first column of the rotation
+    let (v0, v1) = if (eigenvalue - a).abs() + b.abs() >= (eigenvalue - d).abs() + c.abs() {
+        (b, eigenvalue - a)
+    } else {
+        (eigenvalue - d, c)
+    };
+    if v0.is_zero() && v1.is_zero() {
+        return Ok(());
+    }
+
+    let rotation = Givens::zeroing(index, next, v0, -v1);
+    rotation.transpose().apply_left(t)?;
+    rotation.apply_right(t)?;
+    rotation.apply_right(q)?;
+    t[next][index] = F::zero();
+
+    return Ok(());
+}
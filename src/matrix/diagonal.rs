@@ -0,0 +1,56 @@
+use num::Num;
+
+use crate::Matrix;
+
+impl<E> Matrix<E> {
+    /// The elements `self[i][i]`, top left to bottom right
+    pub fn diagonal_iter(&self) -> impl Iterator<Item = &E> {
+        return self.k_diagonal_iter(0);
+    }
+    pub fn diagonal_iter_mut(&mut self) -> impl Iterator<Item = &mut E> {
+        return self.k_diagonal_iter_mut(0);
+    }
+
+    /// The `k`th diagonal, top left to bottom right <br>
+    /// `k` == `0` is the main diagonal, `k` > `0` is above it (`self[i][i + k]`),
+    /// and `k` < `0` is below it (`self[i - k][i]`).
+    /// Empty if the diagonal is outside `self`.
+    pub fn k_diagonal_iter(&self, k: isize) -> impl Iterator<Item = &E> {
+        // each row holds at most one element of a diagonal
+        return self
+            .rows()
+            .enumerate()
+            .filter_map(move |(row_index, row)| row.get(row_index.checked_add_signed(k)?));
+    }
+    pub fn k_diagonal_iter_mut(&mut self, k: isize) -> impl Iterator<Item = &mut E> {
+        return self
+            .rows_mut()
+            .enumerate()
+            .filter_map(move |(row_index, row)| row.get_mut(row_index.checked_add_signed(k)?));
+    }
+
+    /// The elements `self[i][width - 1 - i]`, top right to bottom left
+    pub fn anti_diagonal_iter(&self) -> impl Iterator<Item = &E> {
+        let width = self.width();
+        return self
+            .rows()
+            .enumerate()
+            .filter_map(move |(row_index, row)| row.get(width.checked_sub(row_index + 1)?));
+    }
+    pub fn anti_diagonal_iter_mut(&mut self) -> impl Iterator<Item = &mut E> {
+        let width = self.width();
+        return self
+            .rows_mut()
+            .enumerate()
+            .filter_map(move |(row_index, row)| row.get_mut(width.checked_sub(row_index + 1)?));
+    }
+}
+
+impl<E: Num + Clone> Matrix<E> {
+    /// The sum of the main diagonal
+    pub fn trace(&self) -> E {
+        return self
+            .diagonal_iter()
+            .fold(E::zero(), |sum, element| sum + element.clone());
+    }
+}
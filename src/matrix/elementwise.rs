@@ -0,0 +1,50 @@
+use num::Float;
+
+use crate::Matrix;
+
+impl<F: Float> Matrix<F> {
+    /// The absolute value of every element
+    pub fn abs(&self) -> Self {
+        return self.map(|element| element.abs());
+    }
+
+    /// Limit every element to the interval `[min, max]` <br>
+    /// `NaN` elements stay `NaN`.
+    /// ## Panics
+    /// - if `min` > `max` or either is `NaN`
+    pub fn clamp(&self, min: F, max: F) -> Self {
+        assert!(min <= max, "clamp requires min <= max");
+        return self.map(|&element| {
+            if element < min {
+                min
+            } else if element > max {
+                max
+            } else {
+                element
+            }
+        });
+    }
+
+    /// Round every element to `decimals` digits after the decimal point <br>
+    /// Ties round away from zero and a negative `decimals` rounds to tens, hundreds, and so on.
+    /// Elements are left unchanged when `10^|decimals|` or the scaled element overflows,
+    /// since the element already has no digits past that precision.
+    pub fn round_to(&self, decimals: i32) -> Self {
+        let ten = F::from(10).expect("10 fits in a float");
+        let scale = ten.powi(decimals.abs());
+        return self.map(|&element| {
+            let scaled = if decimals >= 0 {
+                element * scale
+            } else {
+                element / scale
+            };
+            if !scale.is_finite() || !scaled.is_finite() {
+                element
+            } else if decimals >= 0 {
+                scaled.round() / scale
+            } else {
+                scaled.round() * scale
+            }
+        });
+    }
+}
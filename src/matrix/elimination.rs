@@ -0,0 +1,328 @@
+use std::{cmp::Ordering, num::NonZeroUsize};
+
+use num::Float;
+
+use crate::{ColumnVector, DimensionError, Matrix, MatrixError, Shape};
+
+/// How many solutions a linear system has
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Consistency {
+    /// Exactly one solution
+    Unique,
+    /// Infinitely many solutions, the system has free variables
+    Infinite,
+    /// No solution, some equation reduces to `0 == nonzero`
+    Inconsistent,
+}
+
+/// The result of [Matrix::solve_augmented]
+#[derive(Debug, Clone)]
+pub struct AugmentedSolution<F> {
+    consistency: Consistency,
+    rank: usize,
+    solution: Option<Matrix<F>>,
+}
+impl<F> AugmentedSolution<F> {
+    pub fn consistency(&self) -> Consistency {
+        return self.consistency;
+    }
+    /// The rank of the coefficient block `A`
+    pub fn rank(&self) -> usize {
+        return self.rank;
+    }
+    /// The unique solution, or for [Consistency::Infinite] the particular solution with every free variable `0` <br>
+    /// [None] if the system is [Consistency::Inconsistent].
+    pub fn solution(&self) -> Option<&Matrix<F>> {
+        return self.solution.as_ref();
+    }
+    pub fn into_solution(self) -> Option<Matrix<F>> {
+        return self.solution;
+    }
+}
+
+/// Every solution of `A * X` == `B`, the result of [Matrix::solve_general]
+#[derive(Debug, Clone)]
+pub enum SolutionSet<F> {
+    /// The only solution
+    Unique(Matrix<F>),
+    /// Every `particular + Σ tᵢ * null_space[i]` (added to each column) is a solution for any scalars `tᵢ`
+    Infinite {
+        particular: Matrix<F>,
+        /// A basis of the null space of `A`, one vector per free variable
+        null_space: Vec<ColumnVector<F>>,
+    },
+    /// No solution
+    Inconsistent,
+}
+impl<F> SolutionSet<F> {
+    pub fn consistency(&self) -> Consistency {
+        return match self {
+            SolutionSet::Unique(_) => Consistency::Unique,
+            SolutionSet::Infinite { .. } => Consistency::Infinite,
+            SolutionSet::Inconsistent => Consistency::Inconsistent,
+        };
+    }
+    /// The unique solution or a particular solution, [None] if the system is inconsistent
+    pub fn particular(&self) -> Option<&Matrix<F>> {
+        return match self {
+            SolutionSet::Unique(solution) => Some(solution),
+            SolutionSet::Infinite { particular, .. } => Some(particular),
+            SolutionSet::Inconsistent => None,
+        };
+    }
+    /// A basis of the null space of `A`, empty unless there are infinitely many solutions
+    pub fn null_space(&self) -> &[ColumnVector<F>] {
+        return match self {
+            SolutionSet::Infinite { null_space, .. } => null_space,
+            _ => &[],
+        };
+    }
+}
+
+impl<F: Float> Matrix<F> {
+    /// Describe every solution of `self * X` == `b` <br>
+    /// Unlike solving with [Matrix::inverse] this handles non square and rank deficient systems,
+    /// describing an underdetermined system by a particular solution plus a null space basis.
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if `b.height()` != `self.height()`
+    pub fn solve_general(&self, b: &Self) -> Result<SolutionSet<F>, MatrixError> {
+        if b.height() != self.height() {
+            return Err(DimensionError::UnexpectedHeight {
+                expected: self.height(),
+                actual: b.shape(),
+            }
+            .into());
+        }
+
+        let augmented = Matrix {
+            elements: self
+                .rows()
+                .zip(b.rows())
+                .map(|(a, b)| a.iter().chain(b).copied().collect())
+                .collect(),
+        };
+        let solved = augmented.solve_augmented(b.width())?;
+        let Some(particular) = solved.solution else {
+            return Ok(SolutionSet::Inconsistent);
+        };
+        if solved.consistency == Consistency::Unique {
+            return Ok(SolutionSet::Unique(particular));
+        }
+
+        // each free variable set to 1 with the others 0 fixes the pivot variables
+        let (reduced, pivot_columns) = augmented.reduce_row_echelon(self.width());
+        let null_space = (0..self.width())
+            .filter(|column| !pivot_columns.contains(column))
+            .map(|free| {
+                let mut vector = vec![F::zero(); self.width()];
+                vector[free] = F::one();
+                for (row, &pivot_column) in pivot_columns.iter().enumerate() {
+                    vector[pivot_column] = -reduced[row][free];
+                }
+                ColumnVector::try_from(vector)
+            })
+            .collect::<Result<_, _>>()?;
+
+        return Ok(SolutionSet::Infinite {
+            particular,
+            null_space,
+        });
+    }
+
+    /// Solve the system written as the augmented matrix `[A | B]` <br>
+    /// `self` is reduced to reduced row echelon form by Gauss-Jordan elimination with partial pivoting,
+    /// treating pivots smaller than a tolerance relative to the largest element as `0`.
+    /// ## Parameters
+    /// - `rhs_columns`: how many columns on the right are `B` (`1` for `[A | b]`).
+    /// ## Returns
+    /// - The [Consistency] of the system and the solution `X` of `A * X` == `B` if there is one.
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if `rhs_columns` leaves no columns for `A`
+    pub fn solve_augmented(&self, rhs_columns: usize) -> Result<AugmentedSolution<F>, MatrixError> {
+        if rhs_columns == 0 || rhs_columns >= self.width() {
+            return Err(DimensionError::TooSmall.into());
+        }
+        let variables = self.width() - rhs_columns;
+
+        let (reduced, pivot_columns) = self.reduce_row_echelon(variables);
+        let rank = pivot_columns.len();
+
+        // a zero row of A with a nonzero right hand side is `0 == nonzero`
+        let tolerance = self.elimination_tolerance();
+        let inconsistent = reduced
+            .rows()
+            .skip(rank)
+            .any(|row| row[variables..].iter().any(|b| b.abs() > tolerance));
+        if inconsistent {
+            return Ok(AugmentedSolution {
+                consistency: Consistency::Inconsistent,
+                rank,
+                solution: None,
+            });
+        }
+
+        let height = NonZeroUsize::new(variables).expect("variables > 0");
+        let width = NonZeroUsize::new(rhs_columns).expect("rhs_columns > 0");
+        let mut solution = Matrix::zeros(Shape {
+            rows: height,
+            cols: width,
+        });
+        for (row, &pivot_column) in pivot_columns.iter().enumerate() {
+            solution[pivot_column].copy_from_slice(&reduced[row][variables..]);
+        }
+
+        let consistency = if rank == variables {
+            Consistency::Unique
+        } else {
+            Consistency::Infinite
+        };
+
+        return Ok(AugmentedSolution {
+            consistency,
+            rank,
+            solution: Some(solution),
+        });
+    }
+
+    /// The reduced row echelon form by Gauss-Jordan elimination with partial pivoting <br>
+    /// Every pivot is `1` and is the only nonzero element in its column.
+    /// Elements within rounding error of `0` are treated as `0` so rank deficient matrices reduce cleanly.
+    /// ## Returns
+    /// - The reduced matrix and the column of each pivot, in row order.
+    ///   The number of pivots is the rank.
+    pub fn rref(&self) -> (Self, Vec<usize>) {
+        return self.reduce_row_echelon(self.width());
+    }
+
+    /// The numerical rank, the number of pivots found by Gauss-Jordan elimination with partial pivoting
+    /// ## Parameters
+    /// - `tolerance`: pivots with an absolute value at or below this count as `0`.
+    ///   `ε * max(height, width) * max|aᵢⱼ|`, what [Matrix::rref] uses, is a reasonable choice.
+    ///   Larger values also treat nearly dependent rows, like nearly collinear regressors, as dependent.
+    pub fn rank(&self, tolerance: F) -> usize {
+        return self
+            .reduce_row_echelon_within(self.width(), tolerance)
+            .1
+            .len();
+    }
+
+    /// A row echelon form by Gaussian elimination with partial pivoting <br>
+    /// Each pivot is the first nonzero element of its row, right of the pivot above it,
+    /// with only zeros below it. Unlike [Matrix::rref] the pivots are not scaled to `1`
+    /// and the elements above them are left alone.
+    /// ## Returns
+    /// - The reduced matrix and the column of each pivot, in row order.
+    pub fn row_echelon(&self) -> (Self, Vec<usize>) {
+        let tolerance = self.elimination_tolerance();
+        let mut reduced = self.clone();
+        let mut pivot_columns = Vec::new();
+
+        for column in 0..reduced.width() {
+            let pivot_row = pivot_columns.len();
+            if pivot_row == reduced.height() {
+                break;
+            }
+
+            let largest_row = (pivot_row..reduced.height())
+                .max_by(|&i, &j| {
+                    reduced[i][column]
+                        .abs()
+                        .partial_cmp(&reduced[j][column].abs())
+                        .unwrap_or(Ordering::Equal)
+                })
+                .expect("pivot_row is less than the height");
+            if reduced[largest_row][column].abs() <= tolerance {
+                for row in pivot_row..reduced.height() {
+                    reduced[row][column] = F::zero();
+                }
+                continue;
+            }
+            reduced.elements.swap(pivot_row, largest_row);
+
+            let pivot = reduced[pivot_row][column];
+            for row in pivot_row + 1..reduced.height() {
+                let factor = reduced[row][column] / pivot;
+                if factor.is_zero() {
+                    continue;
+                }
+                for k in column..reduced.width() {
+                    reduced[row][k] = reduced[row][k] - factor * reduced[pivot_row][k];
+                }
+                // exactly zero rather than rounding error
+                reduced[row][column] = F::zero();
+            }
+
+            pivot_columns.push(column);
+        }
+
+        return (reduced, pivot_columns);
+    }
+
+    /// Pivots with an absolute value at or below this are treated as `0`
+    fn elimination_tolerance(&self) -> F {
+        let largest = self
+            .elements()
+            .fold(F::zero(), |largest, element| largest.max(element.abs()));
+        let size = F::from(self.height().max(self.width())).expect("size fits in a float");
+        return F::epsilon() * size * largest;
+    }
+
+    /// Gauss-Jordan elimination with partial pivoting over the first `columns` columns <br>
+    /// The remaining columns are carried along as right hand sides.
+    /// ## Returns
+    /// - The reduced row echelon form and the column of each pivot, in row order.
+    pub(crate) fn reduce_row_echelon(&self, columns: usize) -> (Self, Vec<usize>) {
+        return self.reduce_row_echelon_within(columns, self.elimination_tolerance());
+    }
+
+    /// [Matrix::reduce_row_echelon] treating pivots at or below `tolerance` as `0`
+    fn reduce_row_echelon_within(&self, columns: usize, tolerance: F) -> (Self, Vec<usize>) {
+        let mut reduced = self.clone();
+        let mut pivot_columns = Vec::new();
+
+        for column in 0..columns {
+            let pivot_row = pivot_columns.len();
+            if pivot_row == reduced.height() {
+                break;
+            }
+
+            let (largest_row, largest) = (pivot_row..reduced.height())
+                .map(|row| (row, reduced[row][column].abs()))
+                .fold((pivot_row, F::zero()), |best, candidate| {
+                    if candidate.1 > best.1 {
+                        candidate
+                    } else {
+                        best
+                    }
+                });
+            if largest <= tolerance {
+                for row in pivot_row..reduced.height() {
+                    reduced[row][column] = F::zero();
+                }
+                continue;
+            }
+            reduced.elements.swap(pivot_row, largest_row);
+
+            let pivot = reduced[pivot_row][column];
+            for element in reduced[pivot_row].iter_mut() {
+                *element = *element / pivot;
+            }
+
+            for row in 0..reduced.height() {
+                let factor = reduced[row][column];
+                if row == pivot_row || factor.is_zero() {
+                    continue;
+                }
+                for k in 0..reduced.width() {
+                    reduced[row][k] = reduced[row][k] - factor * reduced[pivot_row][k];
+                }
+            }
+
+            pivot_columns.push(column);
+        }
+
+        return (reduced, pivot_columns);
+    }
+}
@@ -0,0 +1,143 @@
+use std::ops::Neg;
+
+use num::{BigInt, BigRational, Num};
+
+use crate::{DimensionError, InverseError, Matrix, MatrixError};
+
+impl<E: Copy + Into<BigInt>> Matrix<E> {
+    /// Convert each element to an arbitrary precision integer
+    pub fn to_bigint(&self) -> Matrix<BigInt> {
+        return self.map(|&element| element.into());
+    }
+    /// Convert each element to an arbitrary precision rational
+    pub fn to_bigrational(&self) -> Matrix<BigRational> {
+        return self.map(|&element| BigRational::from_integer(element.into()));
+    }
+}
+
+/// Exact arithmetic for element types that are [Clone] but not [Copy],
+/// such as [BigInt] and [BigRational] <br>
+/// Nothing is rounded, so these are slower than their `Copy` counterparts but give exact results.
+impl<E: Num + Neg<Output = E> + Clone> Matrix<E> {
+    /// The determinant by fraction-free (Bareiss) elimination, see [Matrix::determinant_bareiss]
+    /// ## Errors
+    /// - Same as [Matrix::determinant]
+    pub fn determinant_exact(&self) -> Result<E, MatrixError> {
+        return self.determinant_bareiss();
+    }
+
+    /// The reduced row echelon form by Gauss-Jordan elimination <br>
+    /// Any non zero pivot is used, which is exact for a field such as [BigRational]
+    /// but not for integers where the division truncates.
+    /// ## Returns
+    /// - The reduced matrix and the column of each pivot, in row order.
+    pub fn rref_exact(&self) -> (Self, Vec<usize>) {
+        let mut reduced = self.clone();
+        let mut pivot_columns = Vec::new();
+
+        for column in 0..reduced.width() {
+            let pivot_row = pivot_columns.len();
+            if pivot_row == reduced.height() {
+                break;
+            }
+            let Some(swap) =
+                (pivot_row..reduced.height()).find(|&row| !reduced[row][column].is_zero())
+            else {
+                continue;
+            };
+            reduced.elements.swap(pivot_row, swap);
+
+            let pivot = reduced[pivot_row][column].clone();
+            for element in reduced[pivot_row].iter_mut() {
+                *element = element.clone() / pivot.clone();
+            }
+
+            for row in 0..reduced.height() {
+                let factor = reduced[row][column].clone();
+                if row == pivot_row || factor.is_zero() {
+                    continue;
+                }
+                for k in 0..reduced.width() {
+                    let removed = factor.clone() * reduced[pivot_row][k].clone();
+                    reduced[row][k] = reduced[row][k].clone() - removed;
+                }
+            }
+
+            pivot_columns.push(column);
+        }
+
+        return (reduced, pivot_columns);
+    }
+
+    /// The exact rank by fraction-free (Bareiss) elimination <br>
+    /// Every division is exact, so unlike [Matrix::rref_exact] this is also correct for integers.
+    pub fn rank_exact(&self) -> usize {
+        let mut reduced = self.clone();
+        let mut previous_pivot = E::one();
+        let mut rank = 0;
+
+        for column in 0..reduced.width() {
+            if rank == reduced.height() {
+                break;
+            }
+            let Some(swap) = (rank..reduced.height()).find(|&row| !reduced[row][column].is_zero())
+            else {
+                continue;
+            };
+            reduced.elements.swap(rank, swap);
+
+            let pivot = reduced[rank][column].clone();
+            for row in rank + 1..reduced.height() {
+                let factor = reduced[row][column].clone();
+                for k in column..reduced.width() {
+                    let kept = reduced[row][k].clone() * pivot.clone();
+                    let removed = factor.clone() * reduced[rank][k].clone();
+                    reduced[row][k] = (kept - removed) / previous_pivot.clone();
+                }
+            }
+
+            previous_pivot = pivot;
+            rank += 1;
+        }
+
+        return rank;
+    }
+
+    /// The inverse by Gauss-Jordan elimination of `[self | I]`, exact for a field such as [BigRational]
+    /// ## Errors
+    /// - [MatrixError::Inverse]
+    ///   - if `self` is not square
+    ///   - if the determinant of `self` is `0`
+    pub fn inverse_exact(&self) -> Result<Self, MatrixError> {
+        if self.width() != self.height() {
+            return Err(InverseError::DimensionError(DimensionError::NotSquare).into());
+        }
+
+        let size = self.width();
+        let augmented = Matrix {
+            elements: self
+                .rows()
+                .enumerate()
+                .map(|(row_index, row)| {
+                    let identity_row = (0..size).map(|column| {
+                        if column == row_index {
+                            E::one()
+                        } else {
+                            E::zero()
+                        }
+                    });
+                    row.iter().cloned().chain(identity_row).collect()
+                })
+                .collect(),
+        };
+
+        let (reduced, pivot_columns) = augmented.rref_exact();
+        if pivot_columns.len() < size || pivot_columns[size - 1] >= size {
+            return Err(InverseError::DeterminantZero.into());
+        }
+
+        return Ok(Matrix {
+            elements: reduced.rows().map(|row| row[size..].into()).collect(),
+        });
+    }
+}
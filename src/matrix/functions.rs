@@ -0,0 +1,280 @@
+use num::{Float, Num};
+
+use crate::{DecompositionError, DimensionError, Matrix, MatrixError};
+
+/// The most square roots [Matrix::log] takes to bring its argument close to the identity
+const MAX_LOGARITHM_SQUARE_ROOTS: usize = 64;
+
+/// The most iterations of the Denman–Beavers square root
+const MAX_SQUARE_ROOT_ITERATIONS: usize = 100;
+
+/// The most terms of the series `log(I + X)` == `X - X²/2 + X³/3 - ...`
+const MAX_LOGARITHM_SERIES_TERMS: usize = 200;
+
+/// The coefficients `b₀, ..., bₘ` of the `[m/m]` Padé approximant of `eˣ` and the largest 1-norm it is
+/// accurate to double precision for, from Higham's "The Scaling and Squaring Method for the Matrix Exponential Revisited"
+const EXPONENTIAL_PADE: [(f64, &[f64]); 5] = [
+    (1.495585217958292e-2, &[120.0, 60.0, 12.0, 1.0]),
+    (
+        2.53939833006323e-1,
+        &[30240.0, 15120.0, 3360.0, 420.0, 30.0, 1.0],
+    ),
+    (
+        9.504178996162932e-1,
+        &[
+            17297280.0, 8648640.0, 1995840.0, 277200.0, 25200.0, 1512.0, 56.0, 1.0,
+        ],
+    ),
+    (
+        2.097847961257068e0,
+        &[
+            17643225600.0,
+            8821612800.0,
+            2075673600.0,
+            302702400.0,
+            30270240.0,
+            2162160.0,
+            110880.0,
+            3960.0,
+            90.0,
+            1.0,
+        ],
+    ),
+    (
+        5.371920351148152e0,
+        &[
+            64764752532480000.0,
+            32382376266240000.0,
+            7771770303897600.0,
+            1187353796428800.0,
+            129060195264000.0,
+            10559470521600.0,
+            670442572800.0,
+            33522128640.0,
+            1323241920.0,
+            40840800.0,
+            960960.0,
+            16380.0,
+            182.0,
+            1.0,
+        ],
+    ),
+];
+
+impl<E: Num + Clone> Matrix<E> {
+    /// Evaluate the polynomial `p(x)` == `c₀ + c₁x + ... + cₙxⁿ` at `x` == `self` using Horner's method <br>
+    /// `p(A)` == `c₀I + c₁A + ... + cₙAⁿ` with `n` matrix multiplications.
+    /// ## Parameters
+    /// - `coefficients`: `[c₀, c₁, ..., cₙ]`, lowest power first like [crate::Regression::polynomial_regression].
+    /// ## Returns
+    /// - The zero matrix if `coefficients` is empty.
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if `self` is not square
+    pub fn polyval(&self, coefficients: &[E]) -> Result<Self, MatrixError> {
+        if !self.shape().is_square() {
+            return Err(DimensionError::NotSquare.into());
+        }
+
+        let mut result = Matrix::zeros(self.shape());
+        for (index, coefficient) in coefficients.iter().enumerate().rev() {
+            if index + 1 != coefficients.len() {
+                result = result.matrix_multiply(self)?;
+            }
+            for element in result.diagonal_iter_mut() {
+                *element = element.clone() + coefficient.clone();
+            }
+        }
+
+        return Ok(result);
+    }
+}
+
+impl<F: Float> Matrix<F> {
+    /// Calculate the principal square root of a symmetric positive semidefinite matrix <br>
+    /// `self` == `V * diag(λ) * Vᵀ` so `sqrt(self)` == `V * diag(√λ) * Vᵀ`
+    /// ## Returns
+    /// - The unique symmetric positive semidefinite `S` where `S * S` == `self`.
+    /// ## Errors
+    /// - [MatrixError::Decomposition]
+    ///   - if `self` is not square or not symmetric
+    ///   - if `self` has an eigenvalue that is negative beyond rounding error
+    pub fn sqrt(&self) -> Result<Self, MatrixError> {
+        let eigen = self.symmetric_eigen()?;
+
+        let largest = eigen
+            .eigenvalues()
+            .iter()
+            .fold(F::zero(), |largest, &eigenvalue| {
+                largest.max(eigenvalue.abs())
+            });
+        let size = F::from(self.height()).expect("height fits in a float");
+        let tolerance = F::epsilon() * size * largest;
+
+        let mut scaled_vectors = eigen.eigenvectors().clone();
+        for row in scaled_vectors.rows_mut() {
+            for (element, &eigenvalue) in row.iter_mut().zip(eigen.eigenvalues()) {
+                if eigenvalue < -tolerance {
+                    Err(DecompositionError::NotPositiveSemidefinite)?
+                }
+                *element = *element * eigenvalue.max(F::zero()).sqrt();
+            }
+        }
+
+        let root = scaled_vectors.matrix_multiply(&eigen.eigenvectors().transpose())?;
+
+        return Ok(root);
+    }
+
+    /// Calculate the matrix exponential `eᴬ` == `I + A + A²/2! + A³/3! + ...` <br>
+    /// Uses scaling and squaring: `A` is divided by `2ˢ` until its 1-norm is small enough for a
+    /// Padé approximant `q(A)⁻¹p(A)` of degree at most `13`, and the result is squared `s` times.
+    /// `x(t)` == `e^(At) * x(0)` solves the linear system of ODEs `x'` == `A * x`.
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if `self` is not square
+    /// - [MatrixError::Decomposition]
+    ///   - if the denominator `q(A)` is singular, which only happens when an element is not finite
+    pub fn exp(&self) -> Result<Self, MatrixError> {
+        if !self.shape().is_square() {
+            return Err(DimensionError::NotSquare.into());
+        }
+
+        let norm = self.one_norm();
+        let from = |value: f64| F::from(value).expect("f64 converts to a float");
+        let (largest_norm, largest_coefficients) = EXPONENTIAL_PADE[EXPONENTIAL_PADE.len() - 1];
+        let (squarings, coefficients) = match EXPONENTIAL_PADE
+            .iter()
+            .find(|(max_norm, _)| norm <= from(*max_norm))
+        {
+            Some(&(_, coefficients)) => (0, coefficients),
+            None => {
+                let squarings = (norm / from(largest_norm)).log2().ceil();
+                (squarings.to_i32().unwrap_or(0), largest_coefficients)
+            }
+        };
+        let two = F::one() + F::one();
+        let scaled = self.scalar_multiply(F::one() / two.powi(squarings));
+
+        // p(A) == V + U and q(A) == V - U where U holds the odd powers and V the even powers
+        let identity = Matrix::identity(self.height_nonzero());
+        let square = scaled.matrix_multiply(&scaled)?;
+        let mut even_power = identity;
+        let mut odd = Matrix::zeros(self.shape());
+        let mut even = Matrix::zeros(self.shape());
+        for (index, pair) in coefficients.chunks(2).enumerate() {
+            if index > 0 {
+                even_power = even_power.matrix_multiply(&square)?;
+            }
+            even = even.add(&even_power.scalar_multiply(from(pair[0])))?;
+            if let Some(&coefficient) = pair.get(1) {
+                odd = odd.add(&even_power.scalar_multiply(from(coefficient)))?;
+            }
+        }
+        let odd = scaled.matrix_multiply(&odd)?;
+
+        let numerator = even.add(&odd)?;
+        let denominator = even.add(&odd.scalar_multiply(-F::one()))?;
+        let mut exponential = denominator.solve(&numerator)?;
+        for _ in 0..squarings {
+            exponential = exponential.matrix_multiply(&exponential)?;
+        }
+
+        return Ok(exponential);
+    }
+
+    /// The largest sum of the absolute values in a column
+    fn one_norm(&self) -> F {
+        return self
+            .columns()
+            .map(|column| column.fold(F::zero(), |sum, element| sum + element.abs()))
+            .fold(F::zero(), F::max);
+    }
+
+    /// Calculate the principal logarithm, the inverse of the matrix exponential <br>
+    /// Uses inverse scaling and squaring: square roots are taken until `self` is close to `I`,
+    /// `log(I + X)` is summed as a series, then scaled back up.
+    /// `log(A)` == `2ˢ * log(A^(1/2ˢ))`
+    /// ## Returns
+    /// - The unique real `L` whose eigenvalues have imaginary parts in `(-π, π)` where `exp(L)` == `self`.
+    /// ## Errors
+    /// - [MatrixError::Decomposition]
+    ///   - if `self` is not square
+    ///   - if `self` has an eigenvalue that is `0` or real and negative,
+    ///     then no real principal logarithm exists
+    ///   - if the Schur decomposition or a square root does not converge
+    pub fn log(&self) -> Result<Self, MatrixError> {
+        let eigenvalues = self.schur()?.eigenvalues();
+        let singular = F::epsilon() * self.frobenius_norm();
+        // only the closed negative real axis has no principal logarithm,
+        // small positive eigenvalues like those of `diag(1e-10, 1)` are fine
+        if eigenvalues.iter().any(|eigenvalue| {
+            let magnitude = eigenvalue.norm();
+            let real = eigenvalue.im.abs() <= F::epsilon().sqrt() * magnitude;
+            magnitude <= singular || (real && eigenvalue.re <= F::zero())
+        }) {
+            Err(DecompositionError::NoPrincipalLogarithm)?
+        }
+
+        let identity = Matrix::identity(self.height_nonzero());
+        let threshold = F::from(0.25).expect("0.25 is a float");
+
+        let mut root = self.clone();
+        let mut square_roots = 0;
+        let mut difference = root.add(&identity.scalar_multiply(-F::one()))?;
+        while difference.frobenius_norm() > threshold {
+            if square_roots == MAX_LOGARITHM_SQUARE_ROOTS {
+                Err(DecompositionError::NoConvergence(
+                    MAX_LOGARITHM_SQUARE_ROOTS,
+                ))?
+            }
+            root = root.denman_beavers_sqrt()?;
+            square_roots += 1;
+            difference = root.add(&identity.scalar_multiply(-F::one()))?;
+        }
+
+        // log(I + X) == X - X²/2 + X³/3 - ...
+        let mut logarithm = difference.clone();
+        let mut power = difference.clone();
+        for term in 2..=MAX_LOGARITHM_SERIES_TERMS {
+            power = power.matrix_multiply(&difference)?;
+            let sign = if term % 2 == 0 { -F::one() } else { F::one() };
+            let coefficient = sign / F::from(term).expect("term count fits in a float");
+            let update = power.scalar_multiply(coefficient);
+            logarithm = logarithm.add(&update)?;
+            if update.frobenius_norm() <= F::epsilon() * logarithm.frobenius_norm() {
+                break;
+            }
+        }
+
+        let two = F::one() + F::one();
+        return Ok(logarithm.scalar_multiply(two.powi(square_roots as i32)));
+    }
+
+    /// The principal square root of a matrix with no eigenvalues on the closed negative real axis <br>
+    /// Denman–Beavers iteration: `Y₀` == `A`, `Z₀` == `I`,
+    /// `Yₖ₊₁` == `(Yₖ + Zₖ⁻¹) / 2`, `Zₖ₊₁` == `(Zₖ + Yₖ⁻¹) / 2` so `Yₖ` → `√A` and `Zₖ` → `√A⁻¹`
+    fn denman_beavers_sqrt(&self) -> Result<Self, MatrixError> {
+        let identity = Matrix::identity(self.height_nonzero());
+        let half = F::one() / (F::one() + F::one());
+        let tolerance = F::epsilon() * F::from(self.height()).expect("height fits in a float");
+
+        let (mut y, mut z) = (self.clone(), identity.clone());
+        for _ in 0..MAX_SQUARE_ROOT_ITERATIONS {
+            let y_inverse = y.lu()?.solve(&identity)?;
+            let z_inverse = z.lu()?.solve(&identity)?;
+            let next = y.add(&z_inverse)?.scalar_multiply(half);
+            z = z.add(&y_inverse)?.scalar_multiply(half);
+
+            let change = next.add(&y.scalar_multiply(-F::one()))?.frobenius_norm();
+            y = next;
+            if change <= tolerance * y.frobenius_norm() {
+                return Ok(y);
+            }
+        }
+
+        Err(DecompositionError::NoConvergence(
+            MAX_SQUARE_ROOT_ITERATIONS,
+        ))?
+    }
+}
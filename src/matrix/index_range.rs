@@ -0,0 +1,85 @@
+use crate::{Matrix, MatrixIndex, Shape};
+
+/// The order a 2d range of indexes is visited in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Order {
+    /// Every column of a row before moving to the next row
+    #[default]
+    RowMajor,
+    /// Every row of a column before moving to the next column
+    ColumnMajor,
+}
+
+/// A rectangular block of indexes with a top left corner and a [Shape]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IndexRange {
+    top_left: MatrixIndex,
+    shape: Shape,
+}
+impl IndexRange {
+    pub fn new(top_left: impl Into<MatrixIndex>, shape: Shape) -> Self {
+        return IndexRange {
+            top_left: top_left.into(),
+            shape,
+        };
+    }
+
+    pub fn top_left(&self) -> MatrixIndex {
+        return self.top_left;
+    }
+    pub fn shape(&self) -> Shape {
+        return self.shape;
+    }
+    /// The last index in the block (inclusive)
+    /// ## Returns
+    /// - [None] if the block extends past `usize::MAX`
+    pub fn bottom_right(&self) -> Option<MatrixIndex> {
+        let row = self.top_left.row().checked_add(self.shape.rows() - 1)?;
+        let column = self.top_left.column().checked_add(self.shape.cols() - 1)?;
+        return Some(MatrixIndex::from((row, column)));
+    }
+
+    /// `true` if `index` is inside the block
+    pub fn contains(&self, index: impl Into<MatrixIndex>) -> bool {
+        let index = index.into();
+        let inside = |index: usize, start: usize, length: usize| {
+            index
+                .checked_sub(start)
+                .is_some_and(|offset| offset < length)
+        };
+        return inside(index.row(), self.top_left.row(), self.shape.rows())
+            && inside(index.column(), self.top_left.column(), self.shape.cols());
+    }
+
+    /// Every index in the block visited in `order`
+    pub fn iter(&self, order: Order) -> impl Iterator<Item = MatrixIndex> {
+        let (top, left) = (self.top_left.row(), self.top_left.column());
+        let (rows, columns) = (self.shape.rows(), self.shape.cols());
+
+        let (outer, inner) = match order {
+            Order::RowMajor => (rows, columns),
+            Order::ColumnMajor => (columns, rows),
+        };
+        return (0..outer).flat_map(move |outer_index| {
+            (0..inner).map(move |inner_index| {
+                let (row, column) = match order {
+                    Order::RowMajor => (outer_index, inner_index),
+                    Order::ColumnMajor => (inner_index, outer_index),
+                };
+                MatrixIndex::from((top + row, left + column))
+            })
+        });
+    }
+}
+
+impl<E> Matrix<E> {
+    /// The [IndexRange] that covers every element
+    pub fn index_range(&self) -> IndexRange {
+        return IndexRange::new((0, 0), self.shape());
+    }
+
+    /// Every index visited in `order`
+    pub fn indexes(&self, order: Order) -> impl Iterator<Item = MatrixIndex> {
+        return self.index_range().iter(order);
+    }
+}
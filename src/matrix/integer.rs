@@ -0,0 +1,247 @@
+use num::{CheckedDiv, CheckedMul, CheckedSub, Integer, Signed};
+
+use crate::{Matrix, MatrixError};
+
+/// The Smith normal form `S` == `U * A * V` of an integer matrix `A` <br>
+/// `S` is diagonal with non negative entries where each diagonal entry divides the next,
+/// and `U` and `V` are unimodular (integer matrices with determinant `±1`).
+#[derive(Debug, Clone)]
+pub struct SmithNormalForm<I> {
+    u: Matrix<I>,
+    s: Matrix<I>,
+    v: Matrix<I>,
+}
+impl<I> SmithNormalForm<I> {
+    /// The `height x height` row transform
+    pub fn u(&self) -> &Matrix<I> {
+        return &self.u;
+    }
+    /// The diagonal `height x width` normal form
+    pub fn s(&self) -> &Matrix<I> {
+        return &self.s;
+    }
+    /// The `width x width` column transform
+    pub fn v(&self) -> &Matrix<I> {
+        return &self.v;
+    }
+    /// `(U, S, V)`
+    pub fn into_parts(self) -> (Matrix<I>, Matrix<I>, Matrix<I>) {
+        return (self.u, self.s, self.v);
+    }
+}
+impl<I: Integer + Copy> SmithNormalForm<I> {
+    /// The invariant factors, the diagonal of `S` up to its last non zero entry
+    pub fn invariant_factors(&self) -> Vec<I> {
+        return self
+            .s
+            .diagonal_iter()
+            .copied()
+            .take_while(|factor| !factor.is_zero())
+            .collect();
+    }
+}
+
+/// The row style Hermite normal form `H` == `U * A` of an integer matrix `A` <br>
+/// `H` is in row echelon form, each pivot is positive, and the entries above a pivot
+/// are in `[0, pivot)`. `U` is unimodular.
+#[derive(Debug, Clone)]
+pub struct HermiteNormalForm<I> {
+    h: Matrix<I>,
+    u: Matrix<I>,
+    pivot_columns: Vec<usize>,
+}
+impl<I> HermiteNormalForm<I> {
+    /// The `height x width` normal form
+    pub fn h(&self) -> &Matrix<I> {
+        return &self.h;
+    }
+    /// The `height x height` row transform
+    pub fn u(&self) -> &Matrix<I> {
+        return &self.u;
+    }
+    /// The column of the pivot in each non zero row of `H`
+    pub fn pivot_columns(&self) -> &[usize] {
+        return &self.pivot_columns;
+    }
+    /// The number of non zero rows of `H`
+    pub fn rank(&self) -> usize {
+        return self.pivot_columns.len();
+    }
+    /// `(H, U)`
+    pub fn into_parts(self) -> (Matrix<I>, Matrix<I>) {
+        return (self.h, self.u);
+    }
+}
+
+impl<I: Integer + Signed + Copy> Matrix<I> {
+    /// Constructs the [HermiteNormalForm] using integer row operations <br>
+    /// Each column is cleared below the pivot by the euclidean algorithm on its rows,
+    /// then the entries above the pivot are reduced modulo it.
+    /// Intermediate values can grow, so use a wide element type (or `BigInt`) for large matrices.
+    pub fn hermite_normal_form(&self) -> HermiteNormalForm<I> {
+        let height = self.height();
+        let mut h = self.clone();
+        let mut u = Matrix::identity(self.height_nonzero());
+        let mut pivot_columns = Vec::new();
+
+        for column in 0..self.width() {
+            let pivot_row = pivot_columns.len();
+            if pivot_row == height {
+                break;
+            }
+
+            while let Some(smallest) = (pivot_row..height)
+                .filter(|&row| !h[row][column].is_zero())
+                .min_by_key(|&row| h[row][column].abs())
+            {
+                h.swap_rows(pivot_row, smallest);
+                u.swap_rows(pivot_row, smallest);
+
+                let pivot = h[pivot_row][column];
+                for row in pivot_row + 1..height {
+                    let quotient = h[row][column] / pivot;
+                    h.subtract_row_multiple(row, pivot_row, quotient);
+                    u.subtract_row_multiple(row, pivot_row, quotient);
+                }
+
+                if (pivot_row + 1..height).all(|row| h[row][column].is_zero()) {
+                    break;
+                }
+            }
+            if h[pivot_row][column].is_zero() {
+                continue;
+            }
+
+            if h[pivot_row][column].is_negative() {
+                h.negate_row(pivot_row);
+                u.negate_row(pivot_row);
+            }
+            let pivot = h[pivot_row][column];
+            for row in 0..pivot_row {
+                let quotient = h[row][column].div_floor(&pivot);
+                h.subtract_row_multiple(row, pivot_row, quotient);
+                u.subtract_row_multiple(row, pivot_row, quotient);
+            }
+
+            pivot_columns.push(column);
+        }
+
+        return HermiteNormalForm {
+            h,
+            u,
+            pivot_columns,
+        };
+    }
+
+    /// Constructs the [SmithNormalForm] by repeatedly moving the smallest non zero entry to the pivot
+    /// and clearing its row and column with integer row and column operations. <br>
+    /// Intermediate values can grow, so use a wide element type (or `BigInt`) for large matrices.
+    pub fn smith_normal_form(&self) -> SmithNormalForm<I> {
+        let (height, width) = (self.height(), self.width());
+        let mut s = self.clone();
+        let mut u = Matrix::identity(self.height_nonzero());
+        let mut v = Matrix::identity(self.width_nonzero());
+
+        for t in 0..height.min(width) {
+            loop {
+                // the smallest non zero entry of the trailing block becomes the pivot
+                let Some((row, column)) = (t..height)
+                    .flat_map(|row| (t..width).map(move |column| (row, column)))
+                    .filter(|&(row, column)| !s[row][column].is_zero())
+                    .min_by_key(|&(row, column)| s[row][column].abs())
+                else {
+                    return SmithNormalForm { u, s, v };
+                };
+                s.swap_rows(t, row);
+                u.swap_rows(t, row);
+                s.swap_columns(t, column);
+                v.swap_columns(t, column);
+
+                let pivot = s[t][t];
+                for row in t + 1..height {
+                    let quotient = s[row][t] / pivot;
+                    s.subtract_row_multiple(row, t, quotient);
+                    u.subtract_row_multiple(row, t, quotient);
+                }
+                for column in t + 1..width {
+                    let quotient = s[t][column] / pivot;
+                    s.subtract_column_multiple(column, t, quotient);
+                    v.subtract_column_multiple(column, t, quotient);
+                }
+
+                // a remainder is smaller than the pivot so the next pass picks it
+                let row_cleared = (t + 1..height).all(|row| s[row][t].is_zero());
+                let column_cleared = (t + 1..width).all(|column| s[t][column].is_zero());
+                if !row_cleared || !column_cleared {
+                    continue;
+                }
+
+                // the pivot must divide the rest of the block,
+                // adding an offending row brings a remainder into the pivot row
+                let offending_row = (t + 1..height).find(|&row| {
+                    (t + 1..width).any(|column| !s[row][column].is_multiple_of(&pivot))
+                });
+                match offending_row {
+                    Some(row) => {
+                        s.subtract_row_multiple(t, row, -I::one());
+                        u.subtract_row_multiple(t, row, -I::one());
+                    }
+                    None => break,
+                }
+            }
+
+            if s[t][t].is_negative() {
+                s.negate_row(t);
+                u.negate_row(t);
+            }
+        }
+
+        return SmithNormalForm { u, s, v };
+    }
+}
+
+impl<I: Integer + Signed + CheckedMul + CheckedSub + CheckedDiv + Copy> Matrix<I> {
+    /// [Matrix::determinant_bareiss] that reports overflow instead of wrapping or panicking
+    /// ## Errors
+    /// - [MatrixError::Determinant]
+    ///   - if `self` is not square
+    /// - [MatrixError::Overflow]
+    ///   - if an intermediate value does not fit in the element type
+    pub fn checked_determinant(&self) -> Result<I, MatrixError> {
+        MatrixError::determinant(self)?;
+
+        return self.bareiss(|kept, pivot, below, right, previous| {
+            kept.checked_mul(pivot)
+                .zip(below.checked_mul(right))
+                .and_then(|(kept, removed)| kept.checked_sub(&removed))
+                .and_then(|numerator| numerator.checked_div(previous))
+                .ok_or(MatrixError::Overflow)
+        });
+    }
+}
+
+impl<I: Integer + Signed + Copy> Matrix<I> {
+    fn negate_row(&mut self, row: usize) {
+        for element in self[row].iter_mut() {
+            *element = -*element;
+        }
+    }
+    /// row `target` -= `factor` * row `source`
+    fn subtract_row_multiple(&mut self, target: usize, source: usize, factor: I) {
+        if factor.is_zero() {
+            return;
+        }
+        for column in 0..self.width() {
+            self[target][column] = self[target][column] - factor * self[source][column];
+        }
+    }
+    /// column `target` -= `factor` * column `source`
+    fn subtract_column_multiple(&mut self, target: usize, source: usize, factor: I) {
+        if factor.is_zero() {
+            return;
+        }
+        for row in self.rows_mut() {
+            row[target] = row[target] - factor * row[source];
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use crate::{DimensionError, Matrix, MatrixError, MatrixIndex, Shape};
+
+/// Decides which elements of a [Matrix] a masked operation such as [Matrix::assign_where] touches <br>
+/// Implemented for predicates `Fn(&E) -> bool` and for `&Matrix<bool>` masks of the same shape.
+pub trait ElementMask<E> {
+    /// `true` if the element at `index` is selected
+    fn selects(&self, index: MatrixIndex, element: &E) -> bool;
+
+    /// Check the mask can be applied to a matrix of `shape`
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if the mask has a fixed shape different from `shape`
+    fn check_shape(&self, _shape: Shape) -> Result<(), MatrixError> {
+        return Ok(());
+    }
+}
+
+impl<E, P: Fn(&E) -> bool> ElementMask<E> for P {
+    fn selects(&self, _index: MatrixIndex, element: &E) -> bool {
+        return self(element);
+    }
+}
+
+impl<E> ElementMask<E> for &Matrix<bool> {
+    fn selects(&self, index: MatrixIndex, _element: &E) -> bool {
+        return self[index];
+    }
+
+    fn check_shape(&self, shape: Shape) -> Result<(), MatrixError> {
+        return same_shape(shape, self.shape());
+    }
+}
+
+impl<E> Matrix<E> {
+    /// The index of the first element where `predicate` is `true`, in row major order
+    pub fn find(&self, predicate: impl Fn(&E) -> bool) -> Option<MatrixIndex> {
+        return self.positions(predicate).next();
+    }
+
+    /// The index of every element where `predicate` is `true`, in row major order
+    pub fn positions<'a>(
+        &'a self,
+        predicate: impl Fn(&E) -> bool + 'a,
+    ) -> impl Iterator<Item = MatrixIndex> + 'a {
+        return self
+            .elements_enumerated()
+            .filter(move |(_, element)| predicate(element))
+            .map(|(index, _)| index);
+    }
+
+    /// The number of elements where `predicate` is `true`
+    pub fn count_where(&self, predicate: impl Fn(&E) -> bool) -> usize {
+        return self.elements().filter(|element| predicate(element)).count();
+    }
+}
+
+impl<E: Clone> Matrix<E> {
+    /// The indices and values of every element where `predicate` is `true`, in row major order
+    pub fn select_where(&self, predicate: impl Fn(&E) -> bool) -> (Vec<MatrixIndex>, Vec<E>) {
+        return self
+            .elements_enumerated()
+            .filter(|(_, element)| predicate(element))
+            .map(|(index, element)| (index, element.clone()))
+            .unzip();
+    }
+
+    /// Overwrite every element selected by `mask` with `value`
+    /// ## Parameters
+    /// - `mask`: a predicate on the elements or a `&Matrix<bool>` of the same shape as `self`.
+    /// - `value`: the new value of the selected elements.
+    /// ## Returns
+    /// - The number of elements that were overwritten.
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if `mask` is a matrix with a different shape than `self`
+    pub fn assign_where(
+        &mut self,
+        mask: impl ElementMask<E>,
+        value: E,
+    ) -> Result<usize, MatrixError> {
+        mask.check_shape(self.shape())?;
+
+        let mut count = 0;
+        for (index, element) in self.elements_mut_enumerated() {
+            if mask.selects(index, element) {
+                *element = value.clone();
+                count += 1;
+            }
+        }
+
+        return Ok(count);
+    }
+}
+
+impl<E: PartialOrd> Matrix<E> {
+    /// `result[i][j]` == `self[i][j] == rhs[i][j]`
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if `self` and `rhs` have different shapes
+    pub fn eq_elementwise(&self, rhs: &Self) -> Result<Matrix<bool>, MatrixError> {
+        return self.compare_elementwise(rhs, |a, b| a == b);
+    }
+    /// `result[i][j]` == `self[i][j] != rhs[i][j]`
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if `self` and `rhs` have different shapes
+    pub fn ne_elementwise(&self, rhs: &Self) -> Result<Matrix<bool>, MatrixError> {
+        return self.compare_elementwise(rhs, |a, b| a != b);
+    }
+    /// `result[i][j]` == `self[i][j] < rhs[i][j]`
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if `self` and `rhs` have different shapes
+    pub fn lt_elementwise(&self, rhs: &Self) -> Result<Matrix<bool>, MatrixError> {
+        return self.compare_elementwise(rhs, |a, b| a < b);
+    }
+    /// `result[i][j]` == `self[i][j] <= rhs[i][j]`
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if `self` and `rhs` have different shapes
+    pub fn le_elementwise(&self, rhs: &Self) -> Result<Matrix<bool>, MatrixError> {
+        return self.compare_elementwise(rhs, |a, b| a <= b);
+    }
+    /// `result[i][j]` == `self[i][j] > rhs[i][j]`
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if `self` and `rhs` have different shapes
+    pub fn gt_elementwise(&self, rhs: &Self) -> Result<Matrix<bool>, MatrixError> {
+        return self.compare_elementwise(rhs, |a, b| a > b);
+    }
+    /// `result[i][j]` == `self[i][j] >= rhs[i][j]`
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if `self` and `rhs` have different shapes
+    pub fn ge_elementwise(&self, rhs: &Self) -> Result<Matrix<bool>, MatrixError> {
+        return self.compare_elementwise(rhs, |a, b| a >= b);
+    }
+
+    fn compare_elementwise(
+        &self,
+        rhs: &Self,
+        compare: impl Fn(&E, &E) -> bool,
+    ) -> Result<Matrix<bool>, MatrixError> {
+        same_shape(self.shape(), rhs.shape())?;
+        return Ok(Matrix {
+            elements: self
+                .rows()
+                .zip(rhs.rows())
+                .map(|(lhs, rhs)| lhs.iter().zip(rhs).map(|(a, b)| compare(a, b)).collect())
+                .collect(),
+        });
+    }
+}
+
+impl Matrix<bool> {
+    /// `true` if every element is `true`
+    pub fn all(&self) -> bool {
+        return self.elements().all(|&element| element);
+    }
+    /// `true` if any element is `true`
+    pub fn any(&self) -> bool {
+        return self.elements().any(|&element| element);
+    }
+}
+
+fn same_shape(lhs: Shape, rhs: Shape) -> Result<(), MatrixError> {
+    if lhs != rhs {
+        return Err(DimensionError::DifferentDimensions { lhs, rhs }.into());
+    }
+    return Ok(());
+}
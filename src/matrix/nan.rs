@@ -0,0 +1,49 @@
+use num::Float;
+
+use crate::{Matrix, MatrixIndex};
+
+/// NaN policy for float matrices <br>
+/// The derived [PartialEq] for [Matrix] compares elements with IEEE 754 semantics,
+/// so a matrix containing `NaN` is never equal to anything, not even itself.
+/// Use [Matrix::eq_ignoring_nan] when two `NaN`s in the same position should compare equal,
+/// and [Matrix::contains_nan] or [Matrix::nan_indices] to find where a `NaN` came from
+/// before it propagates through an operation.
+impl<F: Float> Matrix<F> {
+    /// `true` if any element is `NaN`
+    pub fn contains_nan(&self) -> bool {
+        return self.elements().any(|element| element.is_nan());
+    }
+
+    /// The index of every `NaN` element in row-major order
+    pub fn nan_indices(&self) -> impl Iterator<Item = MatrixIndex> + '_ {
+        return self.positions(|element| element.is_nan());
+    }
+
+    /// Set every `NaN` element to `value`
+    /// ## Returns
+    /// - The number of elements that were replaced.
+    pub fn replace_nan(&mut self, value: F) -> usize {
+        let mut replaced = 0;
+        for element in self.elements_mut() {
+            if element.is_nan() {
+                *element = value;
+                replaced += 1;
+            }
+        }
+        return replaced;
+    }
+
+    /// Like `==` except a `NaN` equals a `NaN` in the same position
+    /// ## Returns
+    /// - `false` if the dimensions differ.
+    pub fn eq_ignoring_nan(&self, other: &Self) -> bool {
+        if self.width() != other.width() || self.height() != other.height() {
+            return false;
+        }
+
+        return self
+            .elements()
+            .zip(other.elements())
+            .all(|(lhs, rhs)| lhs == rhs || (lhs.is_nan() && rhs.is_nan()));
+    }
+}
@@ -1,19 +1,25 @@
-use std::{
-    num::NonZeroUsize,
-    ops::Neg,
-};
+use std::{cmp::Ordering, mem, num::NonZeroUsize, ops::Neg};
 
 use num::{Float, Num};
 
-use crate::{Matrix, MatrixError, MatrixIndex};
+use crate::{
+    kernels, ArithmeticOperation, DimensionError, InverseError, Matrix, MatrixError, MatrixIndex,
+    MatrixOperand, Shape,
+};
+
+/// The largest size [Matrix::determinant] computes by cofactor expansion
+const COFACTOR_DETERMINANT_MAX_SIZE: usize = 4;
 
-impl<E: Num + Copy> Matrix<E> {
+/// The largest size [Matrix::inverse] uses closed form expressions for
+const SMALL_INVERSE_MAX_SIZE: usize = 4;
+
+impl<E: Num + Clone> Matrix<E> {
     pub fn transpose(&self) -> Self {
-        let mut transpose = Matrix::zeros(self.width_nonzero(), self.height_nonzero());
+        let mut transpose = Matrix::zeros(self.shape().transpose());
 
         transpose
             .elements_mut_enumerated()
-            .for_each(|(index, element)| *element = self[index.transpose()]);
+            .for_each(|(index, element)| *element = self[index.transpose()].clone());
 
         return transpose;
     }
@@ -30,7 +36,14 @@ impl<E: Num + Copy> Matrix<E> {
     pub fn matrix_multiply(&self, rhs: &Self) -> Result<Self, MatrixError> {
         MatrixError::multiplication(self, rhs)?;
 
-        let mut product = Matrix::zeros(self.height_nonzero(), rhs.width_nonzero());
+        if let Some(product) = self.small_multiply(rhs) {
+            return Ok(product);
+        }
+
+        let mut product = Matrix::zeros(Shape {
+            rows: self.height_nonzero(),
+            cols: rhs.width_nonzero(),
+        });
 
         for lhs_row_index in 0..self.height() {
             for rhs_col_index in 0..rhs.width() {
@@ -38,8 +51,8 @@ impl<E: Num + Copy> Matrix<E> {
 
                 // I could have used rhs.height() since [MatrixError::multiplication] will return [Result::Err] if `self.width` != `rhs.height`
                 for element_index in 0..self.width() {
-                    let lhs_element = self[lhs_row_index][element_index];
-                    let rhs_element = rhs[element_index][rhs_col_index];
+                    let lhs_element = self[lhs_row_index][element_index].clone();
+                    let rhs_element = rhs[element_index][rhs_col_index].clone();
 
                     let element_product = lhs_element * rhs_element;
 
@@ -53,13 +66,236 @@ impl<E: Num + Copy> Matrix<E> {
         return Ok(product);
     }
 
+    /// Calculate the matrix-vector product `self * vector` without wrapping `vector` in a `n x 1` [Matrix] <br>
+    /// Each element is the dot product of a row of `self` with `vector`.
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if `vector` is empty
+    /// - [MatrixError::Arithmetic]
+    ///   - if `self.width()` != `vector.len()`
+    pub fn mul_vector(&self, vector: &[E]) -> Result<Vec<E>, MatrixError> {
+        let column = Shape::try_new(vector.len(), 1)?;
+        if !self.shape().can_multiply(&column) {
+            return Err(MatrixError::Arithmetic {
+                operation: ArithmeticOperation::Multiplication,
+                dimension_error: DimensionError::LhsWidthNotEqualToRhsHeight {
+                    lhs: self.shape(),
+                    rhs: column,
+                },
+            });
+        }
+
+        return self.rows().map(|row| kernels::dot(row, vector)).collect();
+    }
+
+    /// Calculate the outer product `column * row`, a `column.len() x row.len()` [Matrix]
+    /// where element `[i][j]` is `column[i] * row[j]`
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if `column` or `row` is empty
+    pub fn outer_product(column: &[E], row: &[E]) -> Result<Self, MatrixError> {
+        let height = NonZeroUsize::new(column.len()).ok_or(DimensionError::Zero)?;
+        let width = NonZeroUsize::new(row.len()).ok_or(DimensionError::Zero)?;
+
+        let mut product = Matrix::zeros(Shape {
+            rows: height,
+            cols: width,
+        });
+        for (product_row, column_element) in product.rows_mut().zip(column) {
+            for (element, row_element) in product_row.iter_mut().zip(row) {
+                *element = column_element.clone() * row_element.clone();
+            }
+        }
+
+        return Ok(product);
+    }
+
+    /// Calculate the matrix product of any two [MatrixOperand]s <br>
+    /// Lets `Aᵀ * B` use [Matrix::transposed_view] instead of copying `A` into its transpose.
+    /// ## Parameters
+    /// - `lhs`: left hand side of product matrix.
+    /// - `rhs`: right hand side of product matrix.
+    /// ## Returns
+    /// - The product [Matrix].
+    /// ## Errors
+    /// - [MatrixError::Arithmetic]
+    ///   - if `lhs.width()` != `rhs.height()`
+    pub fn product(
+        lhs: &impl MatrixOperand<E>,
+        rhs: &impl MatrixOperand<E>,
+    ) -> Result<Self, MatrixError> {
+        MatrixError::multiplication(lhs, rhs)?;
+
+        let mut product = Matrix::zeros(Shape {
+            rows: lhs.shape().rows,
+            cols: rhs.shape().cols,
+        });
+        for row in 0..lhs.height() {
+            for column in 0..rhs.width() {
+                product[row][column] = (0..lhs.width()).fold(E::zero(), |sum, k| {
+                    sum + lhs.element(row, k).clone() * rhs.element(k, column).clone()
+                });
+            }
+        }
+
+        return Ok(product);
+    }
+
+    /// Calculate the product of a chain of matrices in the cheapest order <br>
+    /// The association is chosen by the dynamic programming matrix chain order algorithm,
+    /// which minimizes the number of scalar multiplications before any are performed.
+    /// ## Parameters
+    /// - `matrices`: the factors from left to right.
+    /// ## Returns
+    /// - The product [Matrix].
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if `matrices` is empty
+    /// - [MatrixError::Arithmetic]
+    ///   - if the width of a factor != the height of the next one
+    pub fn multi_multiply(matrices: &[&Self]) -> Result<Self, MatrixError> {
+        if matrices.is_empty() {
+            Err(DimensionError::Zero)?
+        }
+        for pair in matrices.windows(2) {
+            MatrixError::multiplication(pair[0], pair[1])?;
+        }
+
+        // factor i is dimensions[i] x dimensions[i + 1]
+        let dimensions = std::iter::once(matrices[0].height())
+            .chain(matrices.iter().map(|matrix| matrix.width()))
+            .collect::<Vec<_>>();
+
+        // cost[i][j] is the fewest scalar multiplications for factors i..=j and split[i][j] is
+        // the last factor of the left hand side in that association
+        let count = matrices.len();
+        let mut cost = vec![vec![0usize; count]; count];
+        let mut split = vec![vec![0usize; count]; count];
+        for length in 2..=count {
+            for i in 0..=count - length {
+                let j = i + length - 1;
+                cost[i][j] = usize::MAX;
+                for k in i..j {
+                    let candidate = cost[i][k]
+                        + cost[k + 1][j]
+                        + dimensions[i] * dimensions[k + 1] * dimensions[j + 1];
+                    if candidate < cost[i][j] {
+                        cost[i][j] = candidate;
+                        split[i][j] = k;
+                    }
+                }
+            }
+        }
+
+        return Ok(Self::chain_product(matrices, &split, 0, count - 1));
+    }
+
+    /// The product of factors `first..=last` associated as `split` says
+    fn chain_product(matrices: &[&Self], split: &[Vec<usize>], first: usize, last: usize) -> Self {
+        if first == last {
+            return matrices[first].clone();
+        }
+        let k = split[first][last];
+        let lhs = Self::chain_product(matrices, split, first, k);
+        let rhs = Self::chain_product(matrices, split, k + 1, last);
+        return lhs
+            .matrix_multiply(&rhs)
+            .expect("adjacent factors were checked");
+    }
+
+    /// Accumulate a scaled matrix product into `self` in place, `self` = `alpha * a * b + beta * self` <br>
+    /// No product is allocated so loops can reuse the same output buffer.
+    /// When `beta` is zero the old contents of `self` are ignored rather than scaled, so they may be `NaN`.
+    /// ## Parameters
+    /// - `alpha`: scales the product `a * b`.
+    /// - `a`: left hand side of the product.
+    /// - `b`: right hand side of the product.
+    /// - `beta`: scales the current contents of `self`.
+    /// ## Errors
+    /// - [MatrixError::Arithmetic]
+    ///   - if `a.width()` != `b.height()`
+    /// - [MatrixError::DimensionError]
+    ///   - if `self` is not `a.height() x b.width()`
+    pub fn gemm(
+        &mut self,
+        alpha: E,
+        a: &impl MatrixOperand<E>,
+        b: &impl MatrixOperand<E>,
+        beta: E,
+    ) -> Result<(), MatrixError> {
+        MatrixError::multiplication(a, b)?;
+        if self.height() != a.height() {
+            Err(DimensionError::UnexpectedHeight {
+                expected: a.height(),
+                actual: self.shape(),
+            })?
+        }
+        if self.width() != b.width() {
+            Err(DimensionError::UnexpectedWidth {
+                expected: b.width(),
+                actual: self.shape(),
+            })?
+        }
+
+        for (row, output_row) in self.elements.iter_mut().enumerate() {
+            for (column, output) in output_row.iter_mut().enumerate() {
+                let dot_product = (0..a.width()).fold(E::zero(), |sum, k| {
+                    sum + a.element(row, k).clone() * b.element(k, column).clone()
+                });
+                let scaled_output = if beta.is_zero() {
+                    E::zero()
+                } else {
+                    beta.clone() * output.clone()
+                };
+                *output = alpha.clone() * dot_product + scaled_output;
+            }
+        }
+
+        return Ok(());
+    }
+
+    /// Calculate the Gram matrix `selfᵀ * self` <br>
+    /// Only the upper triangle is computed and mirrored since the result is symmetric.
+    /// ## Returns
+    /// - The symmetric `self.width() x self.width()` [Matrix] of column dot products.
+    pub fn gram(&self) -> Self {
+        let mut gram = Matrix::zeros(Shape::square(self.width_nonzero()));
+        for i in 0..self.width() {
+            for j in i..self.width() {
+                let dot_product = self
+                    .rows()
+                    .fold(E::zero(), |sum, row| sum + row[i].clone() * row[j].clone());
+                gram[j][i] = dot_product.clone();
+                gram[i][j] = dot_product;
+            }
+        }
+        return gram;
+    }
+
+    /// Calculate the outer Gram matrix `self * selfᵀ` <br>
+    /// Only the upper triangle is computed and mirrored since the result is symmetric.
+    /// ## Returns
+    /// - The symmetric `self.height() x self.height()` [Matrix] of row dot products.
+    pub fn outer_gram(&self) -> Self {
+        let mut gram = Matrix::zeros(Shape::square(self.height_nonzero()));
+        for i in 0..self.height() {
+            for j in i..self.height() {
+                let dot_product =
+                    kernels::dot(&self[i], &self[j]).expect("rows have the width of the matrix");
+                gram[j][i] = dot_product.clone();
+                gram[i][j] = dot_product;
+            }
+        }
+        return gram;
+    }
+
     pub fn hadamard_multiply(&self, rhs: &Self) -> Result<Self, MatrixError> {
         MatrixError::hadamard_product(self, rhs)?;
 
-        let mut product = Matrix::zeros(self.height_nonzero(), self.width_nonzero());
+        let mut product = Matrix::zeros(self.shape());
 
         for (index, product_element) in product.elements_mut_enumerated() {
-            *product_element = self[index] * rhs[index];
+            *product_element = self[index].clone() * rhs[index].clone();
         }
 
         return Ok(product);
@@ -72,10 +308,10 @@ impl<E: Num + Copy> Matrix<E> {
     /// ## Returns
     /// - The scalar product [Matrix].
     pub fn scalar_multiply(&self, scalar: E) -> Self {
-        let mut product = Matrix::zeros(self.height_nonzero(), self.width_nonzero());
+        let mut product = Matrix::zeros(self.shape());
 
         for (index, product_element) in product.elements_mut_enumerated() {
-            *product_element = self[index] * scalar;
+            *product_element = self[index].clone() * scalar.clone();
         }
 
         return product;
@@ -93,15 +329,42 @@ impl<E: Num + Copy> Matrix<E> {
     pub fn add(&self, rhs: &Self) -> Result<Self, MatrixError> {
         MatrixError::addition(self, rhs)?;
 
-        let mut sum = Matrix::zeros(self.height_nonzero(), self.width_nonzero());
+        let mut sum = Matrix::zeros(self.shape());
 
         for (index, sum_element) in sum.elements_mut_enumerated() {
-            *sum_element = self[index] + rhs[index];
+            *sum_element = self[index].clone() + rhs[index].clone();
         }
 
         return Ok(sum);
     }
 
+    /// Calculate `self` multiplied by itself `exponent` times by repeated squaring <br>
+    /// Takes `O(log n)` matrix products, for example to step a Markov chain `n` times
+    /// or jump ahead in a linear recurrence. `self⁰` is the identity.
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if `self` is not square
+    pub fn pow(&self, exponent: u32) -> Result<Self, MatrixError> {
+        if self.width() != self.height() {
+            Err(DimensionError::NotSquare)?
+        }
+
+        let mut power = Matrix::identity(self.height_nonzero());
+        let mut square = self.clone();
+        let mut remaining = exponent;
+        while remaining > 0 {
+            if remaining & 1 == 1 {
+                power = power.matrix_multiply(&square)?;
+            }
+            remaining >>= 1;
+            if remaining > 0 {
+                square = square.matrix_multiply(&square)?;
+            }
+        }
+
+        return Ok(power);
+    }
+
     /// Constructs the minor <br>
     /// The matrix that remains after excluding a row and excluding a column.
     /// ## Returns
@@ -115,10 +378,10 @@ impl<E: Num + Copy> Matrix<E> {
 
         MatrixError::minor(self, excluded_index)?;
 
-        let mut minor = Matrix::zeros(
-            NonZeroUsize::new(self.height() - 1).expect("height cannot be zero"),
-            NonZeroUsize::new(self.width() - 1).expect("width cannot be zero"),
-        );
+        let mut minor = Matrix::zeros(Shape {
+            rows: NonZeroUsize::new(self.height() - 1).expect("height cannot be zero"),
+            cols: NonZeroUsize::new(self.width() - 1).expect("width cannot be zero"),
+        });
 
         let mut minor_index = MatrixIndex::from((0, 0));
         for self_row_index in 0..self.height() {
@@ -132,7 +395,7 @@ impl<E: Num + Copy> Matrix<E> {
                     continue;
                 }
 
-                minor.set_element(minor_index, self[self_row_index][self_column_index]);
+                minor.set_element(minor_index, self[self_row_index][self_column_index].clone());
 
                 minor_index.increment_column();
             }
@@ -144,7 +407,7 @@ impl<E: Num + Copy> Matrix<E> {
     }
 }
 
-impl<E: Num + Neg<Output = E> + Copy> Matrix<E> {
+impl<E: Num + Neg<Output = E> + Clone> Matrix<E> {
     /// Constructs the cofactor <br>
     /// <img src="https://i.imgur.com/0mAVFR3.png" width()=50% height=50%> <br>
     /// - `cofactor` == `(-1)ⁱ⁺ʲ * Mᵢⱼ`
@@ -169,7 +432,7 @@ impl<E: Num + Neg<Output = E> + Copy> Matrix<E> {
     }
 
     pub fn cofactor_matrix(&self) -> Result<Self, MatrixError> {
-        let mut cofactor_matrix = Matrix::zeros(self.height_nonzero(), self.width_nonzero());
+        let mut cofactor_matrix = Matrix::zeros(self.shape());
 
         for (index, _) in self.elements_enumerated() {
             cofactor_matrix[index] = self.cofactor(index)?;
@@ -179,10 +442,13 @@ impl<E: Num + Neg<Output = E> + Copy> Matrix<E> {
     }
 
     /// Constructs the determinant <br>
-    /// <img src="https://i.imgur.com/0mAVFR3.png" width=50% height=50%> <br>
-    /// - `determinant` == `Σ(1..=n) { (-1)ⁱ⁺ʲ * Mᵢⱼ * aᵢⱼ }`
-    /// - `(-1)ⁱ⁺ʲ * Mᵢⱼ` == `self.cofactor(i, j)`
-    /// - `aᵢⱼ` == element at `self[i][j]`
+    /// Matrices up to `4 x 4` use closed form cofactor expansions.
+    /// Larger matrices use fraction-free (Bareiss) elimination, which is `O(n³)` and exact for integers,
+    /// so the recursive minor expansion is never entered. Use [Matrix::determinant_by_cofactor] for that.
+    /// For floating point elements prefer [Matrix::determinant_lu], which pivots on magnitude and loses fewer digits.
+    /// This method is generic over any ring with negation (integers, rationals, floats), and stable Rust
+    /// has no specialization to give `Float` elements a different body, so the pivoted version is a
+    /// separate method rather than a change of algorithm here. Bareiss only swaps rows on an exact `0` pivot.
     /// ## Returns
     /// - The determinant.
     /// ## Errors
@@ -194,36 +460,129 @@ impl<E: Num + Neg<Output = E> + Copy> Matrix<E> {
     pub fn determinant(&self) -> Result<E, MatrixError> {
         MatrixError::determinant(self)?;
 
+        if let Some(determinant) = self.small_determinant() {
+            return Ok(determinant);
+        }
+
+        if self.width() <= COFACTOR_DETERMINANT_MAX_SIZE {
+            return self.determinant_by_cofactor();
+        }
+
+        return self.determinant_bareiss();
+    }
+
+    /// Constructs the determinant by recursive cofactor expansion along the first row <br>
+    /// <img src="https://i.imgur.com/0mAVFR3.png" width=50% height=50%> <br>
+    /// - `determinant` == `Σ(1..=n) { (-1)ⁱ⁺ʲ * Mᵢⱼ * aᵢⱼ }`
+    /// - `(-1)ⁱ⁺ʲ * Mᵢⱼ` == `self.cofactor(i, j)`
+    /// - `aᵢⱼ` == element at `self[i][j]`
+    ///
+    /// This is `O(n!)`, a `15 x 15` matrix will effectively never finish. Prefer [Matrix::determinant].
+    /// ## Errors
+    /// - Same as [Matrix::determinant]
+    pub fn determinant_by_cofactor(&self) -> Result<E, MatrixError> {
+        MatrixError::determinant(self)?;
+
         if self.width() == 2 && self.height() == 2 {
-            let determinant = self[0][0] * self[1][1] - self[0][1] * self[1][0];
+            let determinant =
+                self[0][0].clone() * self[1][1].clone() - self[0][1].clone() * self[1][0].clone();
             return Ok(determinant);
         }
 
         if self.width() == 1 && self.height() == 1 {
-            return Ok(self[0][0]);
+            return Ok(self[0][0].clone());
         }
 
         let mut sum = E::zero();
 
         const FIRST_ROW_INDEX: usize = 0;
         for column_index in 0..self.width() {
-            let element = self[FIRST_ROW_INDEX][column_index];
-            let cofactor = self.cofactor((FIRST_ROW_INDEX, column_index))?;
-            sum = sum + (cofactor * element);
+            let element = self[FIRST_ROW_INDEX][column_index].clone();
+            let sign = if column_index % 2 == 0 {
+                E::one()
+            } else {
+                -E::one()
+            };
+            let minor_determinant = self
+                .minor((FIRST_ROW_INDEX, column_index))?
+                .determinant_by_cofactor()?;
+            sum = sum + sign * minor_determinant * element;
         }
 
         return Ok(sum);
     }
-}
 
-impl<E: Float> Matrix<E> {
-    /// Constructs the inverse (by matrix multiplication) <br>
+    /// Constructs the determinant by fraction-free (Bareiss) elimination at any size <br>
+    /// Every division is exact so integer types get the exact determinant in `O(n³)`,
+    /// and every intermediate value is the determinant of a minor so they stay as small as possible.
+    /// Use [Matrix::determinant_widened] or [Matrix::checked_determinant] if those can still overflow.
+    /// ## Errors
+    /// - Same as [Matrix::determinant]
+    pub fn determinant_bareiss(&self) -> Result<E, MatrixError> {
+        MatrixError::determinant(self)?;
+
+        return self.bareiss(|kept, pivot, below, right, previous| {
+            Ok((kept.clone() * pivot.clone() - below.clone() * right.clone()) / previous.clone())
+        });
+    }
+
+    /// Fraction-free elimination of the square, non empty `self` <br>
+    /// `update(kept, pivot, below, right, previous)` computes `(kept * pivot - below * right) / previous`,
+    /// where the division is always exact, so [Matrix::checked_determinant] can check each step.
+    /// ## Returns
+    /// - The determinant of `self`.
+    /// ## Errors
+    /// - The first error from `update`
+    pub(crate) fn bareiss(
+        &self,
+        update: impl Fn(&E, &E, &E, &E, &E) -> Result<E, MatrixError>,
+    ) -> Result<E, MatrixError> {
+        let size = self.width();
+        let mut reduced = self.clone();
+        let mut sign = E::one();
+        let mut previous_pivot = E::one();
+
+        for pivot in 0..size - 1 {
+            if reduced[pivot][pivot].is_zero() {
+                let Some(swap) = (pivot + 1..size).find(|&row| !reduced[row][pivot].is_zero())
+                else {
+                    return Ok(E::zero());
+                };
+                reduced.swap_rows(pivot, swap);
+                sign = -sign;
+            }
+
+            for row in pivot + 1..size {
+                for column in pivot + 1..size {
+                    reduced[row][column] = update(
+                        &reduced[row][column],
+                        &reduced[pivot][pivot],
+                        &reduced[row][pivot],
+                        &reduced[pivot][column],
+                        &previous_pivot,
+                    )?;
+                }
+            }
+            previous_pivot = reduced[pivot][pivot].clone();
+        }
+
+        return Ok(sign * reduced[size - 1][size - 1].clone());
+    }
+
+    /// Constructs the inverse from the adjugate, `C_T / det(self)` <br>
     /// <img src="https://i.imgur.com/Gi79uxo.png" width=50% height=50%> <br>
     /// `C`: Cofactor Matrix. A matrix with the same size as `self` and each element is equal to the cofactor of `self` at that same index <br>
     /// <img src="https://i.imgur.com/s16kLKs.png" width=25% height=25%> <br>
     /// `T`: Transpose operator <br>
-    /// `det(A)`: determinant of matrix A
-    pub fn inverse(&self) -> Result<Self, MatrixError> {
+    /// `det(A)`: determinant of matrix A <br>
+    /// Every cofactor is a determinant so this is far slower than [Matrix::inverse] past `4 x 4`.
+    /// Each element is divided by the determinant, so integer elements are only exact
+    /// when the determinant divides every cofactor. Use [Matrix::inverse_exact] with rationals.
+    /// ## Errors
+    /// - [MatrixError::Inverse]
+    ///   - if `self` is not square
+    ///   - if the determinant of `self` is `0`
+    pub fn inverse_by_adjugate(&self) -> Result<Self, MatrixError> {
         MatrixError::inverse(self)?;
 
         let determinant = self.determinant()?;
@@ -232,8 +591,159 @@ impl<E: Float> Matrix<E> {
 
         let inverse = cofactor_matrix
             .transpose()
-            .scalar_multiply(E::one() / determinant);
+            .map(|cofactor| cofactor.clone() / determinant.clone());
 
         return Ok(inverse);
     }
+
+    /// [Matrix::determinant_bareiss] computed in the wider type `W` (e.g. `i32` to `i128`)
+    /// ## Errors
+    /// - Same as [Matrix::determinant]
+    pub fn determinant_widened<W>(&self) -> Result<W, MatrixError>
+    where
+        W: Num + Neg<Output = W> + Clone + From<E>,
+    {
+        return self.cast::<W>().determinant_bareiss();
+    }
+}
+
+impl<E: Float> Matrix<E> {
+    /// Constructs the determinant from the LU factorization with partial pivoting <br>
+    /// `O(n³)` like [Matrix::determinant_bareiss], but swapping the largest magnitude pivot into place
+    /// at each step keeps rounding errors small, which Bareiss elimination does not attempt.
+    /// Matrices up to `4 x 4` still use the closed form expansions.
+    /// This is the floating point counterpart of [Matrix::determinant], kept separate because
+    /// [Matrix::determinant] must also work for integers and rationals where there is no magnitude to pivot on.
+    /// The command line `det` and the `det` expression function use it.
+    /// ## Errors
+    /// - Same as [Matrix::determinant]
+    pub fn determinant_lu(&self) -> Result<E, MatrixError> {
+        MatrixError::determinant(self)?;
+
+        if let Some(determinant) = self.small_determinant() {
+            return Ok(determinant);
+        }
+
+        return Ok(self.lu()?.determinant());
+    }
+
+    /// Constructs the inverse (by matrix multiplication) <br>
+    /// Matrices up to `4 x 4` use closed form expressions.
+    /// Larger matrices use Gauss-Jordan elimination of `[self | I]` with partial pivoting, which is `O(n³)`.
+    /// [Matrix::inverse_by_adjugate] keeps the cofactor construction,
+    /// and [Matrix::inverse_exact] is exact for types such as [num::BigRational].
+    /// ## Errors
+    /// - [MatrixError::Inverse]
+    ///   - if `self` is not square
+    ///   - if `self` is singular, the largest pivot left in a column is within rounding error of `0`
+    ///     (for the closed forms, the determinant is as small as such a pivot would make it)
+    pub fn inverse(&self) -> Result<Self, MatrixError> {
+        if self.width() == self.height() && self.width() <= SMALL_INVERSE_MAX_SIZE {
+            // the closed form determinant is cheap enough to check first
+            MatrixError::inverse(self)?;
+            if let Some(determinant) = self.small_determinant() {
+                // a pivot within the Gauss-Jordan tolerance bounds |det| by tolerance * largestⁿ⁻¹
+                let bound = self.singularity_tolerance()
+                    * self.largest_magnitude().powi(self.width() as i32 - 1);
+                if determinant.abs() <= bound {
+                    return Err(InverseError::DeterminantZero.into());
+                }
+            }
+            if let Some(inverse) = self.small_inverse() {
+                return Ok(inverse);
+            }
+        }
+
+        return self.inverse_gauss_jordan();
+    }
+
+    fn inverse_gauss_jordan(&self) -> Result<Self, MatrixError> {
+        if self.width() != self.height() {
+            return Err(InverseError::DimensionError(DimensionError::NotSquare).into());
+        }
+
+        let mut reduced = self.clone();
+        let mut inverse = Matrix::identity(self.height_nonzero());
+        Self::gauss_jordan(&mut reduced, &mut inverse)?;
+
+        return Ok(inverse);
+    }
+
+    /// The largest absolute value of any element
+    fn largest_magnitude(&self) -> E {
+        return self
+            .elements()
+            .fold(E::zero(), |largest, element| largest.max(element.abs()));
+    }
+
+    /// `largest * ε * n`, pivots this small relative to the elements are rounding error left from cancellation
+    fn singularity_tolerance(&self) -> E {
+        return self.largest_magnitude()
+            * E::epsilon()
+            * E::from(self.height()).expect("usize converts to a float");
+    }
+
+    /// Reduce the square `reduced` to `I` while applying the same row operations to `inverse` <br>
+    /// Starting from `inverse` == `I` this leaves the inverse of the original `reduced` in `inverse`.
+    /// ## Errors
+    /// - [MatrixError::Inverse]
+    ///   - if `reduced` is singular
+    pub(crate) fn gauss_jordan(reduced: &mut Self, inverse: &mut Self) -> Result<(), MatrixError> {
+        let size = reduced.height();
+        let tolerance = reduced.singularity_tolerance();
+
+        for column in 0..size {
+            let pivot_row = (column..size)
+                .max_by(|&i, &j| {
+                    reduced[i][column]
+                        .abs()
+                        .partial_cmp(&reduced[j][column].abs())
+                        .unwrap_or(Ordering::Equal)
+                })
+                .expect("column is less than size");
+            if reduced[pivot_row][column].abs() <= tolerance {
+                return Err(InverseError::DeterminantZero.into());
+            }
+            reduced.swap_rows(column, pivot_row);
+            inverse.swap_rows(column, pivot_row);
+
+            let scale = E::one() / reduced[column][column];
+            kernels::scale(scale, &mut reduced[column]);
+            kernels::scale(scale, &mut inverse[column]);
+
+            // move the pivot rows out so the other rows can borrow mutably without copying them
+            let pivot = mem::take(&mut reduced.elements[column]);
+            let pivot_inverse = mem::take(&mut inverse.elements[column]);
+            for row in (0..size).filter(|&row| row != column) {
+                let factor = reduced[row][column];
+                if factor.is_zero() {
+                    continue;
+                }
+                kernels::axpy(-factor, &pivot, &mut reduced[row])
+                    .expect("rows have the width of the matrix");
+                kernels::axpy(-factor, &pivot_inverse, &mut inverse[row])
+                    .expect("rows have the width of the matrix");
+            }
+            reduced.elements[column] = pivot;
+            inverse.elements[column] = pivot_inverse;
+        }
+
+        return Ok(());
+    }
+
+    /// [Matrix::pow] that also accepts negative exponents, `self⁻ⁿ` == `(self⁻¹)ⁿ`
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if `self` is not square
+    /// - [MatrixError::Inverse]
+    ///   - if `exponent` is negative and `self` is singular, see [Matrix::inverse]
+    pub fn powi(&self, exponent: i32) -> Result<Self, MatrixError> {
+        if exponent >= 0 {
+            return self.pow(exponent.unsigned_abs());
+        }
+        if self.width() != self.height() {
+            Err(DimensionError::NotSquare)?
+        }
+        return self.inverse()?.pow(exponent.unsigned_abs());
+    }
 }
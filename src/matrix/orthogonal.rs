@@ -0,0 +1,272 @@
+use std::num::NonZeroUsize;
+
+use num::Float;
+
+use crate::{kernels, DimensionError, Matrix, MatrixError};
+
+/// A rotation by `θ` in the plane spanned by axes `i` and `j` <br>
+/// Equal to the identity except `G[i][i]` == `G[j][j]` == `cos θ`,
+/// `G[i][j]` == `-sin θ`, and `G[j][i]` == `sin θ`.
+/// It is applied to a [Matrix] in `O(n)` without building `G`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Givens<F> {
+    i: usize,
+    j: usize,
+    cos: F,
+    sin: F,
+}
+impl<F: Float> Givens<F> {
+    /// The rotation by `theta` radians from axis `i` towards axis `j`
+    pub fn new(i: usize, j: usize, theta: F) -> Self {
+        return Givens {
+            i,
+            j,
+            cos: theta.cos(),
+            sin: theta.sin(),
+        };
+    }
+
+    /// The rotation that zeroes the `j` component of a vector whose `i` and `j` components are `a` and `b` <br>
+    /// `G * [.., a, .., b, ..]ᵀ` == `[.., r, .., 0, ..]ᵀ` where `r` == `√(a² + b²)`
+    pub fn zeroing(i: usize, j: usize, a: F, b: F) -> Self {
+        let r = a.hypot(b);
+        let (cos, sin) = if r.is_zero() {
+            (F::one(), F::zero())
+        } else {
+            (a / r, -b / r)
+        };
+        return Givens { i, j, cos, sin };
+    }
+
+    pub fn i(&self) -> usize {
+        return self.i;
+    }
+    pub fn j(&self) -> usize {
+        return self.j;
+    }
+    pub fn cos(&self) -> F {
+        return self.cos;
+    }
+    pub fn sin(&self) -> F {
+        return self.sin;
+    }
+
+    /// The rotation in the opposite direction, `Gᵀ`
+    pub fn transpose(&self) -> Self {
+        return Givens {
+            sin: -self.sin,
+            ..*self
+        };
+    }
+
+    /// The `size x size` matrix `G`
+    /// ## Errors
+    /// - [MatrixError::IndexOutOfBounds]
+    ///   - if `i` or `j` is not less than `size`
+    pub fn to_matrix(&self, size: NonZeroUsize) -> Result<Matrix<F>, MatrixError> {
+        self.check_bounds(size.get())?;
+
+        let mut matrix = Matrix::identity(size);
+        matrix[self.i][self.i] = self.cos;
+        matrix[self.j][self.j] = self.cos;
+        matrix[self.i][self.j] = -self.sin;
+        matrix[self.j][self.i] = self.sin;
+
+        return Ok(matrix);
+    }
+
+    /// `matrix` = `G * matrix`, only rows `i` and `j` change
+    /// ## Errors
+    /// - [MatrixError::IndexOutOfBounds]
+    ///   - if `i` or `j` is not less than `matrix.height()`
+    pub fn apply_left(&self, matrix: &mut Matrix<F>) -> Result<(), MatrixError> {
+        self.check_bounds(matrix.height())?;
+
+        for column in 0..matrix.width() {
+            let (a, b) = (matrix[self.i][column], matrix[self.j][column]);
+            matrix[self.i][column] = self.cos * a - self.sin * b;
+            matrix[self.j][column] = self.sin * a + self.cos * b;
+        }
+
+        return Ok(());
+    }
+
+    /// `matrix` = `matrix * G`, only columns `i` and `j` change
+    /// ## Errors
+    /// - [MatrixError::IndexOutOfBounds]
+    ///   - if `i` or `j` is not less than `matrix.width()`
+    pub fn apply_right(&self, matrix: &mut Matrix<F>) -> Result<(), MatrixError> {
+        self.check_bounds(matrix.width())?;
+
+        for row in matrix.rows_mut() {
+            let (a, b) = (row[self.i], row[self.j]);
+            row[self.i] = self.cos * a + self.sin * b;
+            row[self.j] = -self.sin * a + self.cos * b;
+        }
+
+        return Ok(());
+    }
+
+    fn check_bounds(&self, length: usize) -> Result<(), MatrixError> {
+        for index in [self.i, self.j] {
+            if index >= length {
+                return Err(MatrixError::IndexOutOfBounds { index, length });
+            }
+        }
+        return Ok(());
+    }
+}
+
+/// A reflection across the hyperplane perpendicular to `v` <br>
+/// `H` == `I - τ * v * vᵀ` where `τ` == `2 / (vᵀ * v)`.
+/// `v` acts on the rows (or columns) starting at `offset` so a reflector for a trailing
+/// block can be applied to the whole matrix, as QR and Hessenberg reduction do.
+/// It is applied to a [Matrix] without building `H`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Householder<F> {
+    vector: Vec<F>,
+    tau: F,
+    offset: usize,
+}
+impl<F: Float> Householder<F> {
+    /// The reflector `I - 2 * v * vᵀ / (vᵀ * v)`
+    /// ## Errors
+    /// - [MatrixError::ZeroVector]
+    ///   - if `v` is all zeros
+    pub fn new(v: &[F], offset: usize) -> Result<Self, MatrixError> {
+        let norm_squared = v.iter().fold(F::zero(), |sum, &e| sum + e * e);
+        if norm_squared.is_zero() {
+            return Err(MatrixError::ZeroVector);
+        }
+
+        return Ok(Householder {
+            vector: v.to_vec(),
+            tau: (F::one() + F::one()) / norm_squared,
+            offset,
+        });
+    }
+
+    /// The reflector that maps `x` onto a multiple of the first axis <br>
+    /// `H * x` == `[β, 0, ..., 0]ᵀ` where `|β|` == `|x|`.
+    /// The sign of `β` is chosen opposite to `x[0]` to avoid cancellation.
+    /// ## Returns
+    /// - The reflector and `β`.
+    ///   If `x` is already a multiple of the first axis the reflector is the identity (`τ` == `0`).
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if `x` is empty
+    pub fn zeroing(x: &[F], offset: usize) -> Result<(Self, F), MatrixError> {
+        let Some((&first, rest)) = x.split_first() else {
+            return Err(DimensionError::Zero.into());
+        };
+
+        let rest_norm_squared = rest.iter().fold(F::zero(), |sum, &e| sum + e * e);
+        if rest_norm_squared.is_zero() {
+            let identity = Householder {
+                vector: vec![F::zero(); x.len()],
+                tau: F::zero(),
+                offset,
+            };
+            return Ok((identity, first));
+        }
+
+        let norm = (first * first + rest_norm_squared).sqrt();
+        let beta = if first >= F::zero() { -norm } else { norm };
+
+        let mut vector = x.to_vec();
+        vector[0] = first - beta;
+
+        return Ok((Householder::new(&vector, offset)?, beta));
+    }
+
+    pub fn vector(&self) -> &[F] {
+        return &self.vector;
+    }
+    pub fn tau(&self) -> F {
+        return self.tau;
+    }
+    pub fn offset(&self) -> usize {
+        return self.offset;
+    }
+
+    /// The `size x size` matrix `H`
+    /// ## Errors
+    /// - [MatrixError::IndexOutOfBounds]
+    ///   - if `offset + v.len()` is greater than `size`
+    pub fn to_matrix(&self, size: NonZeroUsize) -> Result<Matrix<F>, MatrixError> {
+        let mut matrix = Matrix::identity(size);
+        self.apply_left(&mut matrix)?;
+        return Ok(matrix);
+    }
+
+    /// `matrix` = `H * matrix`
+    /// ## Errors
+    /// - [MatrixError::IndexOutOfBounds]
+    ///   - if `offset + v.len()` is greater than `matrix.height()`
+    pub fn apply_left(&self, matrix: &mut Matrix<F>) -> Result<(), MatrixError> {
+        self.check_bounds(matrix.height())?;
+
+        for column in 0..matrix.width() {
+            let mut projection = F::zero();
+            for (k, &v) in self.vector.iter().enumerate() {
+                projection = projection + v * matrix[self.offset + k][column];
+            }
+            let projection = projection * self.tau;
+            for (k, &v) in self.vector.iter().enumerate() {
+                let element = &mut matrix[self.offset + k][column];
+                *element = *element - projection * v;
+            }
+        }
+
+        return Ok(());
+    }
+
+    /// `matrix` = `matrix * H`
+    /// ## Errors
+    /// - [MatrixError::IndexOutOfBounds]
+    ///   - if `offset + v.len()` is greater than `matrix.width()`
+    pub fn apply_right(&self, matrix: &mut Matrix<F>) -> Result<(), MatrixError> {
+        self.check_bounds(matrix.width())?;
+
+        for row in matrix.rows_mut() {
+            let block = &mut row[self.offset..self.offset + self.vector.len()];
+            let projection = kernels::dot(block, &self.vector)
+                .expect("the block has the length of the reflector")
+                * self.tau;
+            kernels::axpy(-projection, &self.vector, block)
+                .expect("the block has the length of the reflector");
+        }
+
+        return Ok(());
+    }
+
+    fn check_bounds(&self, length: usize) -> Result<(), MatrixError> {
+        let end = self.offset + self.vector.len();
+        if end > length {
+            return Err(MatrixError::IndexOutOfBounds {
+                index: end - 1,
+                length,
+            });
+        }
+        return Ok(());
+    }
+}
+
+impl<F: Float> Matrix<F> {
+    /// Constructs the `size x size` [Givens] rotation by `theta` radians in the `(i, j)` plane
+    /// ## Errors
+    /// - [MatrixError::IndexOutOfBounds]
+    ///   - if `i` or `j` is not less than `size`
+    pub fn givens(size: NonZeroUsize, i: usize, j: usize, theta: F) -> Result<Self, MatrixError> {
+        return Givens::new(i, j, theta).to_matrix(size);
+    }
+
+    /// Constructs the [Householder] reflector `I - 2 * v * vᵀ / (vᵀ * v)`
+    /// ## Errors
+    /// - [MatrixError::ZeroVector]
+    ///   - if `v` is all zeros
+    pub fn householder(v: &[F]) -> Result<Self, MatrixError> {
+        let size = NonZeroUsize::new(v.len()).ok_or(MatrixError::ZeroVector)?;
+        return Householder::new(v, 0)?.to_matrix(size);
+    }
+}
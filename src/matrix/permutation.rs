@@ -0,0 +1,138 @@
+use crate::Matrix;
+
+/// Row and column orderings that make one matrix equal to another <br>
+/// `lhs[i][j]` == `rhs[rows[i]][columns[j]]`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MatrixPermutation {
+    rows: Vec<usize>,
+    columns: Vec<usize>,
+}
+impl MatrixPermutation {
+    /// `rows[i]` is the row of the other matrix that matches row `i`
+    pub fn rows(&self) -> &[usize] {
+        return &self.rows;
+    }
+    /// `columns[j]` is the column of the other matrix that matches column `j`
+    pub fn columns(&self) -> &[usize] {
+        return &self.columns;
+    }
+}
+
+impl<E: PartialEq> Matrix<E> {
+    /// Check whether `self` equals `other` after reordering rows <br>
+    /// `self[i]` == `other[permutation[i]]`
+    /// ## Returns
+    /// - [None] if the shapes differ or no row ordering works
+    pub fn is_row_permutation_of(&self, other: &Self) -> Option<Vec<usize>> {
+        if self.shape() != other.shape() {
+            return None;
+        }
+        let columns = (0..self.width()).collect::<Vec<_>>();
+        return match_rows(self, other, &columns);
+    }
+
+    /// Check whether `self` equals `other` after reordering columns <br>
+    /// `self[i][j]` == `other[i][permutation[j]]`
+    /// ## Returns
+    /// - [None] if the shapes differ or no column ordering works
+    pub fn is_column_permutation_of(&self, other: &Self) -> Option<Vec<usize>> {
+        if self.shape() != other.shape() {
+            return None;
+        }
+
+        // equality is transitive so the first unused match is always safe to take
+        let mut used = vec![false; self.width()];
+        let mut permutation = Vec::with_capacity(self.width());
+        for column in 0..self.width() {
+            let found = (0..other.width()).find(|&candidate| {
+                !used[candidate] && self.column(column).eq(other.column(candidate))
+            })?;
+            used[found] = true;
+            permutation.push(found);
+        }
+        return Some(permutation);
+    }
+
+    /// Check whether `self` equals `other` after reordering both rows and columns <br>
+    /// Columns are paired by backtracking over columns with the same elements in any order,
+    /// then the rows are matched. This is fast unless many columns hold the same elements.
+    /// ## Returns
+    /// - [None] if the shapes differ or no ordering works
+    pub fn is_permutation_of(&self, other: &Self) -> Option<MatrixPermutation> {
+        if self.shape() != other.shape() {
+            return None;
+        }
+
+        let mut columns = Vec::with_capacity(self.width());
+        let mut used = vec![false; self.width()];
+        let rows = search_columns(self, other, &mut columns, &mut used)?;
+
+        return Some(MatrixPermutation { rows, columns });
+    }
+}
+
+/// Extend the partial column pairing `columns` until the rows also match
+fn search_columns<E: PartialEq>(
+    lhs: &Matrix<E>,
+    rhs: &Matrix<E>,
+    columns: &mut Vec<usize>,
+    used: &mut [bool],
+) -> Option<Vec<usize>> {
+    let column = columns.len();
+    if column == lhs.width() {
+        return match_rows(lhs, rhs, columns);
+    }
+
+    for candidate in 0..rhs.width() {
+        if used[candidate] || !same_elements(lhs.column(column), rhs.column(candidate)) {
+            continue;
+        }
+
+        used[candidate] = true;
+        columns.push(candidate);
+        if let Some(rows) = search_columns(lhs, rhs, columns, used) {
+            return Some(rows);
+        }
+        columns.pop();
+        used[candidate] = false;
+    }
+
+    return None;
+}
+
+/// Pair each row of `lhs` with an unused equal row of `rhs` once `rhs` columns are reordered by `columns`
+fn match_rows<E: PartialEq>(
+    lhs: &Matrix<E>,
+    rhs: &Matrix<E>,
+    columns: &[usize],
+) -> Option<Vec<usize>> {
+    let mut used = vec![false; rhs.height()];
+    let mut rows = Vec::with_capacity(lhs.height());
+    for lhs_row in lhs.rows() {
+        let found = (0..rhs.height()).find(|&candidate| {
+            !used[candidate]
+                && lhs_row
+                    .iter()
+                    .zip(columns)
+                    .all(|(element, &column)| *element == rhs[candidate][column])
+        })?;
+        used[found] = true;
+        rows.push(found);
+    }
+    return Some(rows);
+}
+
+/// `true` if `lhs` and `rhs` hold the same elements in any order
+fn same_elements<'a, E: PartialEq + 'a>(
+    lhs: impl Iterator<Item = &'a E>,
+    rhs: impl Iterator<Item = &'a E>,
+) -> bool {
+    let mut remaining = rhs.collect::<Vec<_>>();
+    for element in lhs {
+        let Some(position) = remaining.iter().position(|&other| other == element) else {
+            return false;
+        };
+        remaining.swap_remove(position);
+    }
+    return remaining.is_empty();
+}
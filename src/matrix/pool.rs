@@ -0,0 +1,203 @@
+use std::collections::HashMap;
+
+use num::{Float, Num};
+
+use crate::{
+    DecompositionError, DimensionError, InverseError, Lu, Matrix, MatrixError, MatrixOperand, Shape,
+};
+
+/// Recycles matrix buffers so hot loops do not allocate a fresh [Matrix] for every temporary <br>
+/// [MatrixPool::take] hands out a zeroed matrix, reusing a recycled buffer of the same
+/// dimensions when one is available, and [MatrixPool::recycle] gives a buffer back.
+/// The `*_pooled` operations draw their output and their temporaries from a pool:
+/// multiplication, addition, scaling, transposition, [Matrix::inverse_pooled],
+/// [Matrix::lu_pooled], [Lu::solve_pooled], and [Matrix::solve_pooled].
+/// The other decompositions ([Matrix::cholesky], [Matrix::symmetric_eigen], [Matrix::hessenberg],
+/// [Matrix::tridiagonal], and [Matrix::schur]) still allocate.
+#[derive(Debug, Clone)]
+pub struct MatrixPool<E> {
+    /// recycled buffers keyed by their shape
+    buffers: HashMap<Shape, Vec<Matrix<E>>>,
+}
+impl<E> Default for MatrixPool<E> {
+    fn default() -> Self {
+        return MatrixPool {
+            buffers: HashMap::new(),
+        };
+    }
+}
+impl<E> MatrixPool<E> {
+    pub fn new() -> Self {
+        return Self::default();
+    }
+    /// The number of recycled buffers waiting to be reused
+    pub fn len(&self) -> usize {
+        return self.buffers.values().map(Vec::len).sum();
+    }
+    pub fn is_empty(&self) -> bool {
+        return self.len() == 0;
+    }
+    /// Give `matrix` back so a later [MatrixPool::take] with the same dimensions can reuse it
+    pub fn recycle(&mut self, matrix: Matrix<E>) {
+        self.buffers.entry(matrix.shape()).or_default().push(matrix);
+    }
+    /// Drop every recycled buffer
+    pub fn clear(&mut self) {
+        self.buffers.clear();
+    }
+}
+impl<E: Num + Clone> MatrixPool<E> {
+    /// A matrix of zeros with `shape`, reusing a recycled buffer if there is one
+    pub fn take(&mut self, shape: Shape) -> Matrix<E> {
+        return match self.buffers.get_mut(&shape).and_then(Vec::pop) {
+            Some(mut matrix) => {
+                matrix.set_zero();
+                matrix
+            }
+            None => Matrix::zeros(shape),
+        };
+    }
+}
+
+impl<E: Num + Clone> Matrix<E> {
+    /// [Matrix::matrix_multiply] with the product taken from `pool`
+    /// ## Errors
+    /// - Same as [Matrix::matrix_multiply]
+    pub fn matrix_multiply_pooled(
+        &self,
+        rhs: &Self,
+        pool: &mut MatrixPool<E>,
+    ) -> Result<Self, MatrixError> {
+        MatrixError::multiplication(self, rhs)?;
+
+        let mut product = pool.take(Shape {
+            rows: self.height_nonzero(),
+            cols: rhs.width_nonzero(),
+        });
+        for (lhs_row, product_row) in self.rows().zip(product.rows_mut()) {
+            for (column, product_element) in product_row.iter_mut().enumerate() {
+                *product_element = lhs_row
+                    .iter()
+                    .zip(rhs.column(column))
+                    .fold(E::zero(), |sum, (lhs, rhs)| sum + lhs.clone() * rhs.clone());
+            }
+        }
+
+        return Ok(product);
+    }
+
+    /// [Matrix::add] with the sum taken from `pool`
+    /// ## Errors
+    /// - Same as [Matrix::add]
+    pub fn add_pooled(&self, rhs: &Self, pool: &mut MatrixPool<E>) -> Result<Self, MatrixError> {
+        MatrixError::addition(self, rhs)?;
+
+        let mut sum = pool.take(self.shape());
+        for (index, sum_element) in sum.elements_mut_enumerated() {
+            *sum_element = self[index].clone() + rhs[index].clone();
+        }
+
+        return Ok(sum);
+    }
+
+    /// [Matrix::scalar_multiply] with the product taken from `pool`
+    pub fn scalar_multiply_pooled(&self, scalar: E, pool: &mut MatrixPool<E>) -> Self {
+        let mut product = pool.take(self.shape());
+        for (index, product_element) in product.elements_mut_enumerated() {
+            *product_element = self[index].clone() * scalar.clone();
+        }
+        return product;
+    }
+
+    /// [Matrix::transpose] with the transpose taken from `pool`
+    pub fn transpose_pooled(&self, pool: &mut MatrixPool<E>) -> Self {
+        let mut transpose = pool.take(self.shape().transpose());
+        for (index, element) in transpose.elements_mut_enumerated() {
+            *element = self[index.transpose()].clone();
+        }
+        return transpose;
+    }
+}
+
+impl<F: Float> Matrix<F> {
+    /// [Matrix::inverse] with the inverse and the reduced copy of `self` taken from `pool` <br>
+    /// Always uses Gauss-Jordan elimination, even for the sizes [Matrix::inverse] has closed forms for.
+    /// The reduced copy is recycled before returning.
+    /// ## Errors
+    /// - Same as [Matrix::inverse]
+    pub fn inverse_pooled(&self, pool: &mut MatrixPool<F>) -> Result<Self, MatrixError> {
+        if self.width() != self.height() {
+            return Err(InverseError::DimensionError(DimensionError::NotSquare).into());
+        }
+
+        let mut reduced = pool.take(self.shape());
+        for (index, element) in reduced.elements_mut_enumerated() {
+            *element = self[index];
+        }
+        let mut inverse = pool.take(self.shape());
+        for index in 0..self.height() {
+            inverse[index][index] = F::one();
+        }
+
+        let reduction = Self::gauss_jordan(&mut reduced, &mut inverse);
+        pool.recycle(reduced);
+        if let Err(error) = reduction {
+            pool.recycle(inverse);
+            return Err(error);
+        }
+
+        return Ok(inverse);
+    }
+
+    /// [Matrix::lu] with the factors taken from `pool` <br>
+    /// Give the factors back with [Lu::recycle] once the factorization is no longer needed.
+    /// ## Errors
+    /// - Same as [Matrix::lu]
+    pub fn lu_pooled(&self, pool: &mut MatrixPool<F>) -> Result<Lu<F>, MatrixError> {
+        if self.width() != self.height() {
+            Err(DecompositionError::from(DimensionError::NotSquare))?
+        }
+
+        let mut factors = pool.take(self.shape());
+        for (index, element) in factors.elements_mut_enumerated() {
+            *element = self[index];
+        }
+
+        return Ok(Lu::factor(factors));
+    }
+
+    /// [Matrix::solve] with the factors and the solution taken from `pool` <br>
+    /// The factors are recycled before returning.
+    /// ## Errors
+    /// - Same as [Matrix::solve]
+    pub fn solve_pooled(
+        &self,
+        b: &impl MatrixOperand<F>,
+        pool: &mut MatrixPool<F>,
+    ) -> Result<Self, MatrixError> {
+        let lu = self.lu_pooled(pool)?;
+        let solution = lu.solve_pooled(b, pool);
+        lu.recycle(pool);
+        return solution;
+    }
+}
+
+impl<F: Float> Lu<F> {
+    /// [Lu::solve] with the solution taken from `pool`
+    /// ## Errors
+    /// - Same as [Lu::solve]
+    pub fn solve_pooled(
+        &self,
+        b: &impl MatrixOperand<F>,
+        pool: &mut MatrixPool<F>,
+    ) -> Result<Matrix<F>, MatrixError> {
+        self.check_solvable(b)?;
+        let solution = pool.take(b.shape());
+        return Ok(self.substitute(b, solution));
+    }
+
+    /// Give the buffer holding the factors back to `pool`
+    pub fn recycle(self, pool: &mut MatrixPool<F>) {
+        pool.recycle(self.into_factors());
+    }
+}
@@ -0,0 +1,159 @@
+use num::{Float, Num};
+
+use crate::{Matrix, MatrixError, Shape};
+
+/// The length of the blocks a long slice is split into before reducing in parallel <br>
+/// It is fixed, not derived from the number of threads, so results do not depend on the thread pool.
+#[cfg(feature = "rayon")]
+pub(crate) const REDUCTION_BLOCK_LENGTH: usize = 4096;
+
+/// Add `values` by recursively summing each half <br>
+/// The order of additions only depends on `values.len()` so the result is reproducible,
+/// and the rounding error grows with `log n` instead of `n`.
+pub(crate) fn pairwise_sum<E: Num + Clone>(values: &[E]) -> E {
+    return match values {
+        [] => E::zero(),
+        [value] => value.clone(),
+        _ => {
+            let (left, right) = values.split_at(values.len() / 2);
+            pairwise_sum(left) + pairwise_sum(right)
+        }
+    };
+}
+
+/// Deterministic reductions <br>
+/// Each row is reduced in order and the row results are combined with [pairwise_sum].
+/// The `par_*` versions (with the `rayon` feature) reduce the rows on the rayon thread pool
+/// but combine them in exactly the same order, so they return bit for bit the same result
+/// as the sequential versions no matter how many threads run.
+impl<E: Num + Clone> Matrix<E> {
+    /// The sum of every element
+    pub fn sum(&self) -> E {
+        let row_sums = self.rows().map(row_sum).collect::<Vec<_>>();
+        return pairwise_sum(&row_sums);
+    }
+}
+
+impl<F: Float> Matrix<F> {
+    /// The square root of the sum of the squares of every element
+    pub fn frobenius_norm(&self) -> F {
+        let row_sums = self.rows().map(row_sum_squares).collect::<Vec<_>>();
+        return pairwise_sum(&row_sums).sqrt();
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<E: Num + Clone + Send + Sync> Matrix<E> {
+    /// [Matrix::sum] with the rows summed in parallel, the result is identical
+    pub fn par_sum(&self) -> E {
+        use rayon::prelude::*;
+
+        let row_sums = self
+            .elements
+            .par_iter()
+            .map(|row| row_sum(row))
+            .collect::<Vec<_>>();
+        return pairwise_sum(&row_sums);
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<F: Float + Send + Sync> Matrix<F> {
+    /// [Matrix::frobenius_norm] with the rows reduced in parallel, the result is identical
+    pub fn par_frobenius_norm(&self) -> F {
+        use rayon::prelude::*;
+
+        let row_sums = self
+            .elements
+            .par_iter()
+            .map(|row| row_sum_squares(row))
+            .collect::<Vec<_>>();
+        return pairwise_sum(&row_sums).sqrt();
+    }
+}
+
+fn row_sum<E: Num + Clone>(row: &[E]) -> E {
+    return row
+        .iter()
+        .fold(E::zero(), |sum, element| sum + element.clone());
+}
+
+fn row_sum_squares<E: Num + Clone>(row: &[E]) -> E {
+    return row.iter().fold(E::zero(), |sum, element| {
+        sum + element.clone() * element.clone()
+    });
+}
+
+/// How a long sum of floats is accumulated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Summation {
+    /// Add each value to a running total, the rounding error grows with `n`
+    #[default]
+    Naive,
+    /// Recursively add each half, the rounding error grows with `log n`
+    Pairwise,
+    /// Carry a running compensation for the low order bits lost by each addition (Neumaier's variant of Kahan),
+    /// the rounding error does not grow with `n`
+    Kahan,
+}
+impl Summation {
+    /// Add `values` using this strategy
+    pub fn sum<F: Float>(self, values: impl IntoIterator<Item = F>) -> F {
+        return match self {
+            Summation::Naive => values.into_iter().fold(F::zero(), |sum, value| sum + value),
+            Summation::Pairwise => pairwise_sum(&values.into_iter().collect::<Vec<_>>()),
+            Summation::Kahan => {
+                let (sum, compensation) = values.into_iter().fold(
+                    (F::zero(), F::zero()),
+                    |(sum, compensation), value| {
+                        let total = sum + value;
+                        let lost = if sum.abs() >= value.abs() {
+                            (sum - total) + value
+                        } else {
+                            (value - total) + sum
+                        };
+                        (total, compensation + lost)
+                    },
+                );
+                sum + compensation
+            }
+        };
+    }
+}
+
+impl<F: Float> Matrix<F> {
+    /// The sum of every element accumulated with `summation`
+    pub fn sum_with(&self, summation: Summation) -> F {
+        return summation.sum(self.elements().copied());
+    }
+
+    /// [Matrix::matrix_multiply] with each dot product accumulated with `summation` <br>
+    /// Use [Summation::Kahan] or [Summation::Pairwise] when `self` has very long rows,
+    /// such as the normal equations of a regression on many points.
+    /// ## Errors
+    /// - Same as [Matrix::matrix_multiply]
+    pub fn matrix_multiply_with(
+        &self,
+        rhs: &Self,
+        summation: Summation,
+    ) -> Result<Self, MatrixError> {
+        MatrixError::multiplication(self, rhs)?;
+
+        let mut product = Matrix::zeros(Shape {
+            rows: self.height_nonzero(),
+            cols: rhs.width_nonzero(),
+        });
+        for (lhs_row, product_row) in self.rows().zip(product.rows_mut()) {
+            for (column, product_element) in product_row.iter_mut().enumerate() {
+                *product_element = summation.sum(
+                    lhs_row
+                        .iter()
+                        .zip(rhs.column(column))
+                        .map(|(&lhs, &rhs)| lhs * rhs),
+                );
+            }
+        }
+
+        return Ok(product);
+    }
+}
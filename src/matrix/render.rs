@@ -0,0 +1,103 @@
+use std::fmt::Write;
+
+use num::Float;
+
+use crate::Matrix;
+
+/// The characters [Matrix::render_ascii] draws magnitudes with, from smallest to largest
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    levels: Vec<char>,
+    ansi_colors: bool,
+}
+impl Default for Palette {
+    fn default() -> Self {
+        return Palette {
+            levels: " .:-=+*#%@".chars().collect(),
+            ansi_colors: false,
+        };
+    }
+}
+impl Palette {
+    /// A palette with one level per character of `levels`
+    /// ## Returns
+    /// - [None] if `levels` is empty
+    pub fn new(levels: &str) -> Option<Self> {
+        let levels = levels.chars().collect::<Vec<_>>();
+        if levels.is_empty() {
+            return None;
+        }
+        return Some(Palette {
+            levels,
+            ansi_colors: false,
+        });
+    }
+    /// Also shade every character with an ANSI 256 color grayscale escape code
+    pub fn with_ansi_colors(self) -> Self {
+        return Palette {
+            ansi_colors: true,
+            ..self
+        };
+    }
+    pub fn levels(&self) -> &[char] {
+        return &self.levels;
+    }
+    pub fn ansi_colors(&self) -> bool {
+        return self.ansi_colors;
+    }
+}
+
+/// The first and last codes of the ANSI 256 color grayscale ramp
+const ANSI_GRAYSCALE: (usize, usize) = (232, 255);
+
+impl<F: Float> Matrix<F> {
+    /// Draw the magnitude of every element as one character of a terminal heatmap <br>
+    /// Magnitudes are scaled by the largest one so zero uses the first level of `palette`
+    /// and the largest magnitude uses the last. `NaN` is drawn as `?`.
+    /// ## Returns
+    /// - One line per row, each ending with a newline.
+    pub fn render_ascii(&self, palette: &Palette) -> String {
+        let largest = self
+            .elements()
+            .filter(|element| !element.is_nan())
+            .fold(F::zero(), |largest, element| largest.max(element.abs()));
+        let top_level = palette.levels.len() - 1;
+
+        let mut rendered = String::new();
+        for row in self.rows() {
+            for element in row {
+                if element.is_nan() {
+                    rendered.push('?');
+                    continue;
+                }
+
+                let fraction = if largest.is_zero() {
+                    F::zero()
+                } else {
+                    (element.abs() / largest).min(F::one())
+                };
+                let level = (fraction * F::from(top_level).expect("level fits in a float"))
+                    .round()
+                    .to_usize()
+                    .expect("the fraction is in [0, 1]");
+
+                if palette.ansi_colors {
+                    let (darkest, lightest) = ANSI_GRAYSCALE;
+                    let shade = (fraction * F::from(lightest - darkest).expect("fits in a float"))
+                        .round()
+                        .to_usize()
+                        .expect("the fraction is in [0, 1]");
+                    write!(rendered, "\x1b[38;5;{}m", darkest + shade)
+                        .expect("writing to a String cannot fail");
+                }
+                rendered.push(palette.levels[level]);
+            }
+            if palette.ansi_colors {
+                rendered.push_str("\x1b[0m");
+            }
+            rendered.push('\n');
+        }
+
+        return rendered;
+    }
+}
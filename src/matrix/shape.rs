@@ -0,0 +1,84 @@
+use std::{fmt::Display, num::NonZeroUsize};
+
+use crate::{DimensionError, Matrix};
+
+/// The dimensions of a [Matrix] <br>
+/// The fields are named so `Shape { rows, cols }` cannot silently swap the height and width
+/// the way two positional arguments can.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Shape {
+    /// The height
+    pub rows: NonZeroUsize,
+    /// The width
+    pub cols: NonZeroUsize,
+}
+impl Shape {
+    /// The shape with `rows` rows and `cols` columns
+    /// ## Errors
+    /// - [DimensionError::Zero]
+    ///   - if `rows` or `cols` is `0`
+    pub fn try_new(rows: usize, cols: usize) -> Result<Self, DimensionError> {
+        let (Some(rows), Some(cols)) = (NonZeroUsize::new(rows), NonZeroUsize::new(cols)) else {
+            return Err(DimensionError::Zero);
+        };
+        return Ok(Shape { rows, cols });
+    }
+    /// The `size x size` shape
+    pub fn square(size: NonZeroUsize) -> Self {
+        return Shape {
+            rows: size,
+            cols: size,
+        };
+    }
+
+    pub fn rows(&self) -> usize {
+        return self.rows.get();
+    }
+    pub fn cols(&self) -> usize {
+        return self.cols.get();
+    }
+    /// The number of elements
+    pub fn len(&self) -> usize {
+        return self.rows() * self.cols();
+    }
+    /// Always `false`, a [Shape] has at least one element
+    pub fn is_empty(&self) -> bool {
+        return self.len() == 0;
+    }
+    pub fn is_square(&self) -> bool {
+        return self.rows == self.cols;
+    }
+    /// The shape with rows and columns swapped
+    pub fn transpose(&self) -> Self {
+        return Shape {
+            rows: self.cols,
+            cols: self.rows,
+        };
+    }
+    /// `true` if a matrix of this shape can be multiplied on the right by a matrix of shape `rhs`
+    pub fn can_multiply(&self, rhs: &Self) -> bool {
+        return self.cols == rhs.rows;
+    }
+    /// The shape of the product with `rhs` if they can be multiplied
+    pub fn product(&self, rhs: &Self) -> Option<Self> {
+        return self.can_multiply(rhs).then_some(Shape {
+            rows: self.rows,
+            cols: rhs.cols,
+        });
+    }
+}
+
+impl Display for Shape {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return write!(f, "{}x{}", self.rows, self.cols);
+    }
+}
+
+impl<E> Matrix<E> {
+    pub fn shape(&self) -> Shape {
+        return Shape {
+            rows: self.height_nonzero(),
+            cols: self.width_nonzero(),
+        };
+    }
+}
@@ -0,0 +1,195 @@
+// Closed form fast paths for the 2 x 2, 3 x 3, and 4 x 4 matrices that dominate graphics workloads.
+// `determinant`, `inverse`, and `matrix_multiply` dispatch here automatically.
+
+use std::num::NonZeroUsize;
+
+use num::{Float, Num};
+
+use crate::{Matrix, Shape};
+
+impl<E: Num + Clone> Matrix<E> {
+    /// The size of `self` if it is square and small enough to have a fast path
+    fn small_size(&self) -> Option<usize> {
+        return match (self.height(), self.width()) {
+            (size @ 2..=4, width) if width == size => Some(size),
+            _ => None,
+        };
+    }
+
+    fn to_array<const N: usize>(&self) -> [[E; N]; N] {
+        return std::array::from_fn(|row| std::array::from_fn(|column| self[row][column].clone()));
+    }
+
+    fn from_array<const N: usize>(array: [[E; N]; N]) -> Self {
+        let size = NonZeroUsize::new(N).expect("small sizes are not zero");
+        let mut matrix = Matrix::zeros(Shape::square(size));
+        for (row, array_row) in matrix.rows_mut().zip(array) {
+            for (element, value) in row.iter_mut().zip(array_row) {
+                *element = value;
+            }
+        }
+        return matrix;
+    }
+
+    /// The closed form determinant if `self` is `2 x 2`, `3 x 3`, or `4 x 4`
+    pub(crate) fn small_determinant(&self) -> Option<E> {
+        return match self.small_size()? {
+            2 => Some(determinant_2x2(self.to_array())),
+            3 => Some(determinant_3x3(self.to_array())),
+            _ => Some(determinant_4x4(self.to_array())),
+        };
+    }
+
+    /// The unrolled product if `self` and `rhs` are both `2 x 2`, `3 x 3`, or `4 x 4`
+    pub(crate) fn small_multiply(&self, rhs: &Self) -> Option<Self> {
+        let size = self.small_size()?;
+        if rhs.small_size()? != size {
+            return None;
+        }
+        return match size {
+            2 => Some(Matrix::from_array(multiply::<E, 2>(
+                self.to_array(),
+                rhs.to_array(),
+            ))),
+            3 => Some(Matrix::from_array(multiply::<E, 3>(
+                self.to_array(),
+                rhs.to_array(),
+            ))),
+            _ => Some(Matrix::from_array(multiply::<E, 4>(
+                self.to_array(),
+                rhs.to_array(),
+            ))),
+        };
+    }
+}
+
+impl<F: Float> Matrix<F> {
+    /// The closed form inverse if `self` is `2 x 2`, `3 x 3`, or `4 x 4` <br>
+    /// The caller has already checked that the determinant is not `0`.
+    pub(crate) fn small_inverse(&self) -> Option<Self> {
+        return match self.small_size()? {
+            2 => Some(Matrix::from_array(inverse_2x2(self.to_array()))),
+            3 => Some(Matrix::from_array(inverse_3x3(self.to_array()))),
+            _ => Some(Matrix::from_array(inverse_4x4(self.to_array()))),
+        };
+    }
+}
+
+fn multiply<E: Num + Clone, const N: usize>(lhs: [[E; N]; N], rhs: [[E; N]; N]) -> [[E; N]; N] {
+    return std::array::from_fn(|row| {
+        std::array::from_fn(|column| {
+            lhs[row]
+                .iter()
+                .zip(rhs.iter())
+                .fold(E::zero(), |sum, (lhs, rhs_row)| {
+                    sum + lhs.clone() * rhs_row[column].clone()
+                })
+        })
+    });
+}
+
+fn determinant_2x2<E: Num + Clone>(m: [[E; 2]; 2]) -> E {
+    let [[a, b], [c, d]] = m;
+    return a * d - b * c;
+}
+
+fn determinant_3x3<E: Num + Clone>(m: [[E; 3]; 3]) -> E {
+    let e = |row: usize, column: usize| m[row][column].clone();
+    return e(0, 0) * (e(1, 1) * e(2, 2) - e(1, 2) * e(2, 1))
+        - e(0, 1) * (e(1, 0) * e(2, 2) - e(1, 2) * e(2, 0))
+        + e(0, 2) * (e(1, 0) * e(2, 1) - e(1, 1) * e(2, 0));
+}
+
+/// The `2 x 2` minors of the top two rows (`s`) and bottom two rows (`c`) <br>
+/// Both the `4 x 4` determinant and inverse are built from these.
+fn minors_4x4<E: Num + Clone>(m: &[[E; 4]; 4]) -> ([E; 6], [E; 6]) {
+    let pair = |top: usize, bottom: usize, i: usize, j: usize| {
+        return m[top][i].clone() * m[bottom][j].clone() - m[top][j].clone() * m[bottom][i].clone();
+    };
+    let s = [
+        pair(0, 1, 0, 1),
+        pair(0, 1, 0, 2),
+        pair(0, 1, 0, 3),
+        pair(0, 1, 1, 2),
+        pair(0, 1, 1, 3),
+        pair(0, 1, 2, 3),
+    ];
+    let c = [
+        pair(2, 3, 0, 1),
+        pair(2, 3, 0, 2),
+        pair(2, 3, 0, 3),
+        pair(2, 3, 1, 2),
+        pair(2, 3, 1, 3),
+        pair(2, 3, 2, 3),
+    ];
+    return (s, c);
+}
+
+/// Laplace expansion along the top two rows
+fn determinant_4x4<E: Num + Clone>(m: [[E; 4]; 4]) -> E {
+    let ([s0, s1, s2, s3, s4, s5], [c0, c1, c2, c3, c4, c5]) = minors_4x4(&m);
+    return s0 * c5 - s1 * c4 + s2 * c3 + s3 * c2 - s4 * c1 + s5 * c0;
+}
+
+fn inverse_2x2<F: Float>(m: [[F; 2]; 2]) -> [[F; 2]; 2] {
+    let reciprocal = F::one() / determinant_2x2(m);
+    return [
+        [m[1][1] * reciprocal, -m[0][1] * reciprocal],
+        [-m[1][0] * reciprocal, m[0][0] * reciprocal],
+    ];
+}
+
+fn inverse_3x3<F: Float>(m: [[F; 3]; 3]) -> [[F; 3]; 3] {
+    let adjugate = [
+        [
+            m[1][1] * m[2][2] - m[1][2] * m[2][1],
+            m[0][2] * m[2][1] - m[0][1] * m[2][2],
+            m[0][1] * m[1][2] - m[0][2] * m[1][1],
+        ],
+        [
+            m[1][2] * m[2][0] - m[1][0] * m[2][2],
+            m[0][0] * m[2][2] - m[0][2] * m[2][0],
+            m[0][2] * m[1][0] - m[0][0] * m[1][2],
+        ],
+        [
+            m[1][0] * m[2][1] - m[1][1] * m[2][0],
+            m[0][1] * m[2][0] - m[0][0] * m[2][1],
+            m[0][0] * m[1][1] - m[0][1] * m[1][0],
+        ],
+    ];
+    let determinant =
+        m[0][0] * adjugate[0][0] + m[0][1] * adjugate[1][0] + m[0][2] * adjugate[2][0];
+    return adjugate.map(|row| row.map(|element| element / determinant));
+}
+
+fn inverse_4x4<F: Float>(m: [[F; 4]; 4]) -> [[F; 4]; 4] {
+    let (s, c) = minors_4x4(&m);
+    let determinant = determinant_4x4(m);
+    let adjugate = [
+        [
+            m[1][1] * c[5] - m[1][2] * c[4] + m[1][3] * c[3],
+            -m[0][1] * c[5] + m[0][2] * c[4] - m[0][3] * c[3],
+            m[3][1] * s[5] - m[3][2] * s[4] + m[3][3] * s[3],
+            -m[2][1] * s[5] + m[2][2] * s[4] - m[2][3] * s[3],
+        ],
+        [
+            -m[1][0] * c[5] + m[1][2] * c[2] - m[1][3] * c[1],
+            m[0][0] * c[5] - m[0][2] * c[2] + m[0][3] * c[1],
+            -m[3][0] * s[5] + m[3][2] * s[2] - m[3][3] * s[1],
+            m[2][0] * s[5] - m[2][2] * s[2] + m[2][3] * s[1],
+        ],
+        [
+            m[1][0] * c[4] - m[1][1] * c[2] + m[1][3] * c[0],
+            -m[0][0] * c[4] + m[0][1] * c[2] - m[0][3] * c[0],
+            m[3][0] * s[4] - m[3][1] * s[2] + m[3][3] * s[0],
+            -m[2][0] * s[4] + m[2][1] * s[2] - m[2][3] * s[0],
+        ],
+        [
+            -m[1][0] * c[3] + m[1][1] * c[1] - m[1][2] * c[0],
+            m[0][0] * c[3] - m[0][1] * c[1] + m[0][2] * c[0],
+            -m[3][0] * s[3] + m[3][1] * s[1] - m[3][2] * s[0],
+            m[2][0] * s[3] - m[2][1] * s[1] + m[2][2] * s[0],
+        ],
+    ];
+    return adjugate.map(|row| row.map(|element| element / determinant));
+}
@@ -0,0 +1,120 @@
+use num::Float;
+
+use crate::{
+    DecompositionError, DimensionError, Householder, Matrix, MatrixError, MatrixOperand, Shape,
+};
+
+impl<F: Float> Matrix<F> {
+    /// Solve `self * X` == `b` by LU factorization with partial pivoting <br>
+    /// Cheaper and more accurate than multiplying `b` by [Matrix::inverse].
+    /// ## Parameters
+    /// - `b`: the right hand side, one system per column. Any [MatrixOperand] such as a [crate::TransposedView] is accepted.
+    /// ## Returns
+    /// - `X` with the same dimensions as `b`.
+    /// ## Errors
+    /// - [MatrixError::Decomposition]
+    ///   - if `self` is not square or is singular
+    /// - [MatrixError::Arithmetic]
+    ///   - if `b.height()` != `self.width()`
+    pub fn solve(&self, b: &impl MatrixOperand<F>) -> Result<Self, MatrixError> {
+        return self.lu()?.solve(b);
+    }
+
+    /// Solve `self * X` == `b` then improve the solution by iterative refinement <br>
+    /// Each round computes the residual `r` == `b - self * X`, solves `self * D` == `r`
+    /// with the same LU factorization, and updates `X` += `D`.
+    /// This recovers digits lost to rounding on ill-conditioned systems.
+    /// ## Parameters
+    /// - `b`: the right hand side, one system per column.
+    /// - `max_refinements`: the maximum number of correction rounds.
+    ///   Stops early once a correction no longer changes `X`.
+    /// ## Returns
+    /// - The refined solution `X`.
+    /// ## Errors
+    /// - [MatrixError::Decomposition]
+    ///   - if `self` is not square or is singular
+    /// - [MatrixError::Arithmetic]
+    ///   - if `b.height()` != `self.width()`
+    pub fn solve_refined(&self, b: &Self, max_refinements: usize) -> Result<Self, MatrixError> {
+        let lu = self.lu()?;
+        let mut solution = lu.solve(b)?;
+        let mut residual = b.clone();
+
+        for _ in 0..max_refinements {
+            // residual = b - self * solution
+            residual.clone_from(b);
+            residual.gemm(-F::one(), self, &solution, F::one())?;
+
+            let correction = lu.solve(&residual)?;
+
+            let mut changed = false;
+            for (index, element) in solution.elements_mut_enumerated() {
+                let corrected = *element + correction[index];
+                changed |= corrected != *element;
+                *element = corrected;
+            }
+            if !changed {
+                break;
+            }
+        }
+
+        return Ok(solution);
+    }
+
+    /// Solve the overdetermined system `self * X` ≈ `b` in the least squares sense <br>
+    /// Minimizes `‖self * X - b‖` for each column of `b` using a Householder QR factorization,
+    /// which avoids squaring the condition number like the normal equations `(AᵀA)⁻¹Aᵀb` do.
+    /// ## Returns
+    /// - The `self.width() x b.width()` solution `X`.
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if `b.height()` != `self.height()`
+    ///   - if `self` has fewer rows than columns
+    /// - [MatrixError::Decomposition]
+    ///   - if the columns of `self` are linearly dependent
+    pub fn least_squares(&self, b: &Self) -> Result<Self, MatrixError> {
+        if b.height() != self.height() {
+            return Err(DimensionError::UnexpectedHeight {
+                expected: self.height(),
+                actual: b.shape(),
+            }
+            .into());
+        }
+        if self.height() < self.width() {
+            return Err(DimensionError::TooSmall.into());
+        }
+
+        // reduce self to R and apply the same reflections to b to form Qᵀb
+        let mut r = self.clone();
+        let mut qtb = b.clone();
+        for column in 0..self.width() {
+            let x = r.column(column).skip(column).copied().collect::<Vec<_>>();
+            let (reflector, _) = Householder::zeroing(&x, column)?;
+            reflector.apply_left(&mut r)?;
+            reflector.apply_left(&mut qtb)?;
+        }
+
+        let tolerance = F::epsilon()
+            * F::from(self.height()).expect("height fits in a float")
+            * r.elements()
+                .fold(F::zero(), |largest, e| largest.max(e.abs()));
+        if r.diagonal_iter().any(|pivot| pivot.abs() <= tolerance) {
+            Err(DecompositionError::Singular)?
+        }
+
+        // back substitution with the upper triangular top block of R
+        let mut solution = Matrix::zeros(Shape {
+            rows: self.width_nonzero(),
+            cols: b.width_nonzero(),
+        });
+        for column in 0..b.width() {
+            for row in (0..self.width()).rev() {
+                let known = (row + 1..self.width())
+                    .fold(F::zero(), |sum, k| sum + r[row][k] * solution[k][column]);
+                solution[row][column] = (qtb[row][column] - known) / r[row][row];
+            }
+        }
+
+        return Ok(solution);
+    }
+}
@@ -0,0 +1,121 @@
+use std::cmp::Ordering;
+
+use num::Float;
+
+use crate::{Matrix, MatrixError};
+
+/// Whether smaller keys come first or last when sorting
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortDirection {
+    #[default]
+    Ascending,
+    Descending,
+}
+impl SortDirection {
+    /// Apply the direction to the [Ordering] of two keys in ascending order
+    pub fn apply(&self, ordering: Ordering) -> Ordering {
+        return match self {
+            SortDirection::Ascending => ordering,
+            SortDirection::Descending => ordering.reverse(),
+        };
+    }
+}
+
+impl<E> Matrix<E> {
+    /// Reorder the rows so their keys are ascending <br>
+    /// The sort is stable and keys that cannot be compared, such as `NaN`, go last.
+    /// ## Parameters
+    /// - `key`: computes the sort key of a row.
+    pub fn sort_rows_by<K: PartialOrd>(&mut self, mut key: impl FnMut(&[E]) -> K) {
+        self.elements
+            .sort_by(|a, b| compare_nan_last(&key(a), &key(b), SortDirection::Ascending));
+    }
+
+    /// Remove each row that `same` says repeats the row kept before it, like [Vec::dedup_by] <br>
+    /// Only consecutive repeats are removed, sort the rows first to remove every repeat.
+    /// The first row is always kept so the matrix never becomes empty.
+    pub fn dedup_rows_by(&mut self, mut same: impl FnMut(&[E], &[E]) -> bool) {
+        let mut rows = std::mem::take(&mut self.elements).into_vec();
+        rows.dedup_by(|row, kept| same(kept, row));
+        self.elements = rows.into();
+    }
+}
+
+impl<E: Clone> Matrix<E> {
+    /// Copy the first occurrence of every distinct row, keeping their order <br>
+    /// Rows are distinct when `same` is `false` for every row already kept.
+    pub fn unique_rows_by(&self, mut same: impl FnMut(&[E], &[E]) -> bool) -> Self {
+        let mut unique: Vec<Box<[E]>> = Vec::new();
+        for row in self.rows() {
+            if !unique.iter().any(|kept| same(kept, row)) {
+                unique.push(row.into());
+            }
+        }
+        return Matrix {
+            elements: unique.into(),
+        };
+    }
+}
+
+impl<E: PartialEq + Clone> Matrix<E> {
+    /// Remove consecutive equal rows, see [Matrix::dedup_rows_by]
+    pub fn dedup_rows(&mut self) {
+        self.dedup_rows_by(|a, b| a == b);
+    }
+
+    /// Copy the first occurrence of every distinct row, see [Matrix::unique_rows_by]
+    pub fn unique_rows(&self) -> Self {
+        return self.unique_rows_by(|a, b| a == b);
+    }
+}
+
+impl<F: Float> Matrix<F> {
+    /// Remove consecutive rows whose elements all differ from the kept row by at most `tolerance`,
+    /// see [Matrix::dedup_rows_by]
+    pub fn dedup_rows_within(&mut self, tolerance: F) {
+        self.dedup_rows_by(|a, b| rows_within(a, b, tolerance));
+    }
+
+    /// Copy the first row of every group whose elements all differ by at most `tolerance`,
+    /// see [Matrix::unique_rows_by]
+    pub fn unique_rows_within(&self, tolerance: F) -> Self {
+        return self.unique_rows_by(|a, b| rows_within(a, b, tolerance));
+    }
+}
+
+fn rows_within<F: Float>(a: &[F], b: &[F], tolerance: F) -> bool {
+    return a.iter().zip(b).all(|(&a, &b)| (a - b).abs() <= tolerance);
+}
+
+impl<E: PartialOrd> Matrix<E> {
+    /// Reorder the rows by the values in column `column_index` <br>
+    /// The sort is stable and values that cannot be compared, such as `NaN`, go last in either direction.
+    /// ## Errors
+    /// - [MatrixError::ElementOutOfBounds]
+    ///   - if `column_index` >= `self.width()`
+    pub fn sort_rows_by_column(
+        &mut self,
+        column_index: usize,
+        direction: SortDirection,
+    ) -> Result<(), MatrixError> {
+        self.check_index((0, column_index))?;
+
+        self.elements
+            .sort_by(|a, b| compare_nan_last(&a[column_index], &b[column_index], direction));
+
+        return Ok(());
+    }
+}
+
+/// A total order for sorting by a [PartialOrd] key: comparable values in `direction`,
+/// then the values that are not even comparable to themselves, such as `NaN`, which are equal to each other <br>
+/// `partial_cmp(..).unwrap_or(Ordering::Equal)` is not a total order and can make the standard sorts panic.
+pub(crate) fn compare_nan_last<T: PartialOrd>(a: &T, b: &T, direction: SortDirection) -> Ordering {
+    let incomparable = |value: &T| value.partial_cmp(value).is_none();
+    return match (incomparable(a), incomparable(b)) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => direction.apply(a.partial_cmp(b).unwrap_or(Ordering::Equal)),
+    };
+}
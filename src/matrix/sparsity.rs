@@ -0,0 +1,56 @@
+use num::Float;
+
+use crate::Matrix;
+
+/// How far the nonzero elements of a [Matrix] reach from the main diagonal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bandwidth {
+    /// The largest `i - j` of a nonzero `self[i][j]` below the diagonal
+    pub lower: usize,
+    /// The largest `j - i` of a nonzero `self[i][j]` above the diagonal
+    pub upper: usize,
+}
+impl Bandwidth {
+    /// `true` if only the main diagonal has nonzero elements
+    pub fn is_diagonal(&self) -> bool {
+        return self.lower == 0 && self.upper == 0;
+    }
+    /// `true` if only the main diagonal and the diagonals next to it have nonzero elements
+    pub fn is_tridiagonal(&self) -> bool {
+        return self.lower <= 1 && self.upper <= 1;
+    }
+}
+
+impl<F: Float> Matrix<F> {
+    /// The fraction of elements whose magnitude is at most `tolerance` <br>
+    /// A dense matrix is near `0` and a mostly zero matrix is near `1`. `NaN` counts as nonzero.
+    pub fn sparsity(&self, tolerance: F) -> F {
+        let zeros = self.count_where(|element| element.abs() <= tolerance);
+        let total = self.height() * self.width();
+        return F::from(zeros).expect("count fits in a float")
+            / F::from(total).expect("count fits in a float");
+    }
+
+    /// `true` where the magnitude of an element is greater than `tolerance` (or it is `NaN`)
+    pub fn nonzero_pattern(&self, tolerance: F) -> Matrix<bool> {
+        return self.map(|&element| is_nonzero(element, tolerance));
+    }
+
+    /// The lower and upper [Bandwidth] of the elements whose magnitude is greater than `tolerance`
+    pub fn bandwidth(&self, tolerance: F) -> Bandwidth {
+        let mut bandwidth = Bandwidth { lower: 0, upper: 0 };
+        for index in self.positions(|&element| is_nonzero(element, tolerance)) {
+            let (row, column) = (index.row(), index.column());
+            if row > column {
+                bandwidth.lower = bandwidth.lower.max(row - column);
+            } else {
+                bandwidth.upper = bandwidth.upper.max(column - row);
+            }
+        }
+        return bandwidth;
+    }
+}
+
+fn is_nonzero<F: Float>(element: F, tolerance: F) -> bool {
+    return element.is_nan() || element.abs() > tolerance;
+}
@@ -0,0 +1,237 @@
+use std::{num::NonZeroUsize, ops::Index};
+
+use crate::{IndexRange, Matrix, MatrixIndex, Order, Shape};
+
+/// Read only element access shared by [Matrix], [MatrixView], and [TransposedView] <br>
+/// Routines that take `&impl MatrixOperand<E>` work on any of them without copying.
+pub trait MatrixOperand<E> {
+    fn shape(&self) -> Shape;
+    /// The element at `row`, `column`
+    /// ## Panics
+    /// - if the index is outside the operand
+    fn element(&self, row: usize, column: usize) -> &E;
+
+    fn height(&self) -> usize {
+        return self.shape().rows();
+    }
+    fn width(&self) -> usize {
+        return self.shape().cols();
+    }
+}
+
+impl<E> MatrixOperand<E> for Matrix<E> {
+    fn shape(&self) -> Shape {
+        return Matrix::shape(self);
+    }
+    fn element(&self, row: usize, column: usize) -> &E {
+        return &self.elements[row][column];
+    }
+}
+
+impl<E> MatrixOperand<E> for MatrixView<'_, E> {
+    fn shape(&self) -> Shape {
+        return MatrixView::shape(self);
+    }
+    fn element(&self, row: usize, column: usize) -> &E {
+        return &self[(row, column)];
+    }
+}
+
+/// A borrowed [Matrix] indexed as its transpose <br>
+/// `view[(i, j)]` is `matrix[(j, i)]` and nothing is moved or copied.
+#[derive(Debug, Clone, Copy)]
+pub struct TransposedView<'a, E> {
+    matrix: &'a Matrix<E>,
+}
+impl<'a, E> TransposedView<'a, E> {
+    pub fn new(matrix: &'a Matrix<E>) -> Self {
+        return TransposedView { matrix };
+    }
+    /// The matrix that is viewed as transposed
+    pub fn matrix(&self) -> &'a Matrix<E> {
+        return self.matrix;
+    }
+    pub fn shape(&self) -> Shape {
+        return self.matrix.shape().transpose();
+    }
+    pub fn height(&self) -> usize {
+        return self.matrix.width();
+    }
+    pub fn width(&self) -> usize {
+        return self.matrix.height();
+    }
+    /// The rows of the transpose, which are the columns of the viewed matrix
+    pub fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = &'a E>> {
+        let matrix = self.matrix;
+        return (0..matrix.width()).map(move |column| matrix.column(column));
+    }
+}
+impl<E: Clone> TransposedView<'_, E> {
+    /// Copy the transpose into its own [Matrix]
+    pub fn to_matrix(&self) -> Matrix<E> {
+        return Matrix {
+            elements: self.rows().map(|row| row.cloned().collect()).collect(),
+        };
+    }
+}
+impl<E, I: Into<MatrixIndex>> Index<I> for TransposedView<'_, E> {
+    type Output = E;
+    /// ## Panics
+    /// - if `index` is outside the transpose
+    fn index(&self, index: I) -> &Self::Output {
+        return &self.matrix[index.into().transpose()];
+    }
+}
+impl<E> MatrixOperand<E> for TransposedView<'_, E> {
+    fn shape(&self) -> Shape {
+        return TransposedView::shape(self);
+    }
+    fn element(&self, row: usize, column: usize) -> &E {
+        return &self.matrix.elements[column][row];
+    }
+}
+
+/// A borrowed rectangular block of a [Matrix] <br>
+/// Indexing is relative to the top left of the block and nothing is copied
+/// until [MatrixView::to_matrix] is called.
+#[derive(Debug, Clone, Copy)]
+pub struct MatrixView<'a, E> {
+    matrix: &'a Matrix<E>,
+    range: IndexRange,
+}
+impl<'a, E> MatrixView<'a, E> {
+    /// The block of `matrix` covered by `range` <br>
+    /// ## Returns
+    /// - [None] if `range` is not inside `matrix`, including a `range` that extends past `usize::MAX`
+    pub fn new(matrix: &'a Matrix<E>, range: IndexRange) -> Option<Self> {
+        let inside = range
+            .bottom_right()
+            .is_some_and(|bottom_right| bottom_right.is_within(matrix.shape()));
+        if !inside {
+            return None;
+        }
+        return Some(MatrixView { matrix, range });
+    }
+
+    pub fn shape(&self) -> Shape {
+        return self.range.shape();
+    }
+    pub fn height(&self) -> usize {
+        return self.range.shape().rows();
+    }
+    pub fn width(&self) -> usize {
+        return self.range.shape().cols();
+    }
+    /// Where the block is in the viewed matrix
+    pub fn range(&self) -> IndexRange {
+        return self.range;
+    }
+
+    /// The rows of the block
+    pub fn rows(&self) -> impl Iterator<Item = &'a [E]> {
+        let top_left = self.range.top_left();
+        let columns = top_left.column()..top_left.column() + self.width();
+        return self.matrix.elements[top_left.row()..top_left.row() + self.height()]
+            .iter()
+            .map(move |row| &row[columns.clone()]);
+    }
+
+    /// The elements of the block in row-major order
+    pub fn elements(&self) -> impl Iterator<Item = &'a E> {
+        return self.rows().flatten();
+    }
+}
+impl<E: Clone> MatrixView<'_, E> {
+    /// Copy the block into its own [Matrix]
+    pub fn to_matrix(&self) -> Matrix<E> {
+        return Matrix {
+            elements: self.rows().map(|row| row.into()).collect(),
+        };
+    }
+}
+impl<E, I: Into<MatrixIndex>> Index<I> for MatrixView<'_, E> {
+    type Output = E;
+    /// ## Panics
+    /// - if `index` is outside the block
+    fn index(&self, index: I) -> &Self::Output {
+        let index = index.into();
+        assert!(
+            index.is_within(self.shape()),
+            "index out of bounds of the view"
+        );
+        let top_left = self.range.top_left();
+        return &self.matrix[(
+            top_left.row() + index.row(),
+            top_left.column() + index.column(),
+        )];
+    }
+}
+
+impl<E> Matrix<E> {
+    /// `self` indexed as its transpose without moving any elements, see [TransposedView]
+    pub fn transposed_view(&self) -> TransposedView<'_, E> {
+        return TransposedView::new(self);
+    }
+
+    /// The block covered by `range`, see [MatrixView::new]
+    pub fn view(&self, range: IndexRange) -> Option<MatrixView<'_, E>> {
+        return MatrixView::new(self, range);
+    }
+
+    /// Every overlapping `block_height x block_width` block, with top left corners in row-major order <br>
+    /// Empty if the block is larger than `self`.
+    pub fn windows(
+        &self,
+        block_height: NonZeroUsize,
+        block_width: NonZeroUsize,
+    ) -> impl Iterator<Item = MatrixView<'_, E>> {
+        let block = Shape {
+            rows: block_height,
+            cols: block_width,
+        };
+        // the top left corners that keep the whole block inside self
+        let corners = Shape::try_new(
+            (self.height() + 1).saturating_sub(block.rows()),
+            (self.width() + 1).saturating_sub(block.cols()),
+        )
+        .ok()
+        .map(|corners| IndexRange::new((0, 0), corners));
+
+        return corners
+            .into_iter()
+            .flat_map(|corners| corners.iter(Order::RowMajor))
+            .map(move |top_left| MatrixView {
+                matrix: self,
+                range: IndexRange::new(top_left, block),
+            });
+    }
+
+    /// Non overlapping `block_height x block_width` tiles covering `self`, in row-major order <br>
+    /// Like [slice::chunks] the tiles on the bottom and right edges are smaller
+    /// when the dimensions are not multiples of the block.
+    pub fn chunks(
+        &self,
+        block_height: NonZeroUsize,
+        block_width: NonZeroUsize,
+    ) -> impl Iterator<Item = MatrixView<'_, E>> {
+        let (height, width) = (self.height(), self.width());
+        return (0..height)
+            .step_by(block_height.get())
+            .flat_map(move |top| {
+                (0..width)
+                    .step_by(block_width.get())
+                    .map(move |left| (top, left))
+            })
+            .map(move |(top, left)| {
+                let shape = Shape::try_new(
+                    block_height.get().min(height - top),
+                    block_width.get().min(width - left),
+                )
+                .expect("top and left are inside self");
+                MatrixView {
+                    matrix: self,
+                    range: IndexRange::new((top, left), shape),
+                }
+            });
+    }
+}
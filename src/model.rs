@@ -0,0 +1,401 @@
+use std::{
+    collections::HashMap,
+    fmt::{Display, Write},
+    fs,
+    path::Path,
+    str::FromStr,
+};
+
+use num::Float;
+
+use crate::{
+    regression::{fourier::fourier_terms, isotonic::interpolate, segmented::segmented_terms},
+    FourierFit, IsotonicFit, Matrix, ModelError, SegmentedFit,
+};
+
+/// The version [Model::to_model_string] writes, increased whenever the format changes
+pub const MODEL_FORMAT_VERSION: u32 = 1;
+
+/// The terms of the input that the coefficients of a [Model] multiply
+#[derive(Debug, Clone, PartialEq)]
+pub enum Basis<F> {
+    /// `1, x, ..., xᵈ`, see [crate::DataSet::polynomial_fit]
+    Polynomial { degree: usize },
+    /// A continuous piecewise polynomial, see [SegmentedFit]
+    Segmented { degree: usize, breakpoints: Vec<F> },
+    /// `1, sin(ωx), cos(ωx), ...`, see [FourierFit]
+    Fourier { harmonics: usize, period: F },
+    /// Linear interpolation between the coefficients at ascending `inputs`, see [IsotonicFit]
+    Interpolated { inputs: Vec<F> },
+}
+impl<F> Basis<F> {
+    /// The name written in the model format
+    pub fn name(&self) -> &'static str {
+        return match self {
+            Basis::Polynomial { .. } => "polynomial",
+            Basis::Segmented { .. } => "segmented",
+            Basis::Fourier { .. } => "fourier",
+            Basis::Interpolated { .. } => "interpolated",
+        };
+    }
+    /// The fields the model format writes for the basis, besides the ones every basis has
+    fn field_names(&self) -> &'static [&'static str] {
+        return match self {
+            Basis::Polynomial { .. } => &["degree"],
+            Basis::Segmented { .. } => &["degree", "breakpoints"],
+            Basis::Fourier { .. } => &["harmonics", "period"],
+            Basis::Interpolated { .. } => &["inputs"],
+        };
+    }
+    /// The number of coefficients the basis needs
+    pub fn len(&self) -> usize {
+        return match self {
+            Basis::Polynomial { degree } => degree + 1,
+            Basis::Segmented {
+                degree,
+                breakpoints,
+            } => degree + 1 + degree * breakpoints.len(),
+            Basis::Fourier { harmonics, .. } => 1 + 2 * harmonics,
+            Basis::Interpolated { inputs } => inputs.len(),
+        };
+    }
+    /// `true` for an [Basis::Interpolated] basis with no inputs
+    pub fn is_empty(&self) -> bool {
+        return self.len() == 0;
+    }
+}
+
+/// `(x - offset) / scale`, applied to each input before the [Basis]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InputScaling<F> {
+    pub offset: F,
+    pub scale: F,
+}
+impl<F: Float> InputScaling<F> {
+    /// Leaves inputs unchanged
+    pub fn identity() -> Self {
+        return InputScaling {
+            offset: F::zero(),
+            scale: F::one(),
+        };
+    }
+    pub fn apply(&self, input: F) -> F {
+        return (input - self.offset) / self.scale;
+    }
+}
+impl<F: Float> Default for InputScaling<F> {
+    fn default() -> Self {
+        return InputScaling::identity();
+    }
+}
+
+/// A fitted univariate model that can be saved, loaded in another process, and evaluated <br>
+/// The text format is a versioned header then one `key value` line per field, for example
+/// ```text
+/// matrix-model 1
+/// basis fourier
+/// harmonics 1
+/// period 12
+/// input-offset 0
+/// input-scale 1
+/// coefficients 5, 2, -1
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Model<F> {
+    basis: Basis<F>,
+    coefficients: Vec<F>,
+    input_scaling: InputScaling<F>,
+}
+impl<F: Float> Model<F> {
+    /// ## Errors
+    /// - [ModelError::CoefficientCount]
+    ///   - if `coefficients.len()` != `basis.len()` or the basis needs no coefficients
+    pub fn new(basis: Basis<F>, coefficients: Vec<F>) -> Result<Self, ModelError> {
+        if coefficients.len() != basis.len() || basis.is_empty() {
+            return Err(ModelError::CoefficientCount {
+                basis: basis.name(),
+                expected: basis.len().max(1),
+                actual: coefficients.len(),
+            });
+        }
+        return Ok(Model {
+            basis,
+            coefficients,
+            input_scaling: InputScaling::identity(),
+        });
+    }
+
+    /// The polynomial `c₀ + c₁x + ... + cₙxⁿ` such as the coefficients of [crate::DataSet::polynomial_fit]
+    /// ## Errors
+    /// - [ModelError::CoefficientCount]
+    ///   - if `coefficients` is empty
+    pub fn polynomial(coefficients: impl IntoIterator<Item = F>) -> Result<Self, ModelError> {
+        let coefficients = coefficients.into_iter().collect::<Vec<_>>();
+        let basis = Basis::Polynomial {
+            degree: coefficients.len().saturating_sub(1),
+        };
+        return Model::new(basis, coefficients);
+    }
+
+    /// Apply `scaling` to inputs before the basis, for a model fitted on scaled inputs
+    pub fn with_input_scaling(self, scaling: InputScaling<F>) -> Self {
+        return Model {
+            input_scaling: scaling,
+            ..self
+        };
+    }
+
+    pub fn basis(&self) -> &Basis<F> {
+        return &self.basis;
+    }
+    pub fn coefficients(&self) -> &[F] {
+        return &self.coefficients;
+    }
+    pub fn input_scaling(&self) -> &InputScaling<F> {
+        return &self.input_scaling;
+    }
+
+    /// Evaluate the model at `input`
+    pub fn predict(&self, input: F) -> F {
+        let x = self.input_scaling.apply(input);
+        let dot = |terms: &mut dyn Iterator<Item = F>| {
+            terms
+                .zip(self.coefficients.iter())
+                .fold(F::zero(), |sum, (term, &coefficient)| {
+                    sum + term * coefficient
+                })
+        };
+        return match &self.basis {
+            Basis::Polynomial { .. } => self
+                .coefficients
+                .iter()
+                .rev()
+                .fold(F::zero(), |sum, &coefficient| sum * x + coefficient),
+            Basis::Segmented {
+                degree,
+                breakpoints,
+            } => dot(&mut segmented_terms(x, breakpoints, *degree)),
+            Basis::Fourier { harmonics, period } => dot(&mut fourier_terms(x, *period, *harmonics)),
+            Basis::Interpolated { inputs } => interpolate(inputs, &self.coefficients, x),
+        };
+    }
+
+    /// Evaluate the model at every element of `inputs` <br>
+    /// The model is univariate, so each element is an input and the prediction
+    /// is written to the same position of a matrix with the dimensions of `inputs`.
+    pub fn predict_matrix(&self, inputs: &Matrix<F>) -> Matrix<F> {
+        return inputs.map(|&input| self.predict(input));
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<F: Float + Send + Sync> Model<F> {
+    /// [Model::predict_matrix] with the rows split across the rayon thread pool <br>
+    /// Every prediction is independent, so the result is the same as [Model::predict_matrix].
+    pub fn par_predict_matrix(&self, inputs: &Matrix<F>) -> Matrix<F> {
+        use rayon::prelude::*;
+
+        let mut predictions = inputs.clone();
+        predictions
+            .rows_mut()
+            .collect::<Vec<_>>()
+            .into_par_iter()
+            .for_each(|row| {
+                for element in row.iter_mut() {
+                    *element = self.predict(*element);
+                }
+            });
+
+        return predictions;
+    }
+}
+
+impl<F: Float> SegmentedFit<F> {
+    pub fn to_model(&self) -> Model<F> {
+        let basis = Basis::Segmented {
+            degree: self.degree(),
+            breakpoints: self.breakpoints().to_vec(),
+        };
+        let coefficients = self.fit().coefficients().elements().copied().collect();
+        return Model::new(basis, coefficients).expect("the fit has one coefficient per term");
+    }
+}
+impl<F: Float> FourierFit<F> {
+    pub fn to_model(&self) -> Model<F> {
+        let basis = Basis::Fourier {
+            harmonics: self.harmonics(),
+            period: self.period(),
+        };
+        let coefficients = self.fit().coefficients().elements().copied().collect();
+        return Model::new(basis, coefficients).expect("the fit has one coefficient per term");
+    }
+}
+impl<F: Float> IsotonicFit<F> {
+    pub fn to_model(&self) -> Model<F> {
+        let basis = Basis::Interpolated {
+            inputs: self.inputs().to_vec(),
+        };
+        return Model::new(basis, self.fitted().to_vec()).expect("the fit has one value per input");
+    }
+}
+
+impl<F: Float + Display> Model<F> {
+    /// Write the model in the text format described on [Model]
+    pub fn to_model_string(&self) -> String {
+        let list = |values: &[F]| {
+            values
+                .iter()
+                .map(|value| value.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        let mut text = format!("matrix-model {MODEL_FORMAT_VERSION}\n");
+        // writing to a String can not fail
+        let _ = writeln!(text, "basis {}", self.basis.name());
+        let _ = match &self.basis {
+            Basis::Polynomial { degree } => writeln!(text, "degree {degree}"),
+            Basis::Segmented {
+                degree,
+                breakpoints,
+            } => writeln!(text, "degree {degree}\nbreakpoints {}", list(breakpoints)),
+            Basis::Fourier { harmonics, period } => {
+                writeln!(text, "harmonics {harmonics}\nperiod {period}")
+            }
+            Basis::Interpolated { inputs } => writeln!(text, "inputs {}", list(inputs)),
+        };
+        let _ = writeln!(text, "input-offset {}", self.input_scaling.offset);
+        let _ = writeln!(text, "input-scale {}", self.input_scaling.scale);
+        let _ = writeln!(text, "coefficients {}", list(&self.coefficients));
+        return text;
+    }
+
+    /// Write [Model::to_model_string] to `path`
+    /// ## Errors
+    /// - [ModelError::Io]
+    ///   - if the file can not be written
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), ModelError> {
+        let path = path.as_ref();
+        return fs::write(path, self.to_model_string()).map_err(|source| ModelError::Io {
+            path: path.to_path_buf(),
+            source,
+        });
+    }
+}
+
+impl<F: Float + FromStr> Model<F> {
+    /// Read a model written by [Model::save]
+    /// ## Errors
+    /// - [ModelError::Io]
+    ///   - if the file can not be read
+    /// - Same as [Model::from_str]
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ModelError> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path).map_err(|source| ModelError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        return text.parse();
+    }
+}
+
+impl<F: Float + FromStr> FromStr for Model<F> {
+    type Err = ModelError;
+    /// ## Errors
+    /// - [ModelError::MissingHeader] or [ModelError::UnsupportedVersion]
+    ///   - if the first line is not `matrix-model 1`
+    /// - [ModelError::MissingField] or [ModelError::InvalidField]
+    ///   - if a field the basis needs is absent or can not be parsed
+    ///   - if `input-scale` is `0` or not finite
+    /// - [ModelError::DuplicateField]
+    /// - [ModelError::UnknownField]
+    ///   - if a field is not used by the basis
+    /// - [ModelError::UnknownBasis]
+    /// - [ModelError::CoefficientCount]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines().map(str::trim).filter(|line| !line.is_empty());
+
+        let version = lines
+            .next()
+            .and_then(|header| header.strip_prefix("matrix-model "))
+            .ok_or(ModelError::MissingHeader)?
+            .trim();
+        if version != MODEL_FORMAT_VERSION.to_string() {
+            return Err(ModelError::UnsupportedVersion(version.to_string()));
+        }
+
+        let mut fields = HashMap::new();
+        for line in lines {
+            let (key, value) = line.split_once(' ').unwrap_or((line, ""));
+            if fields.insert(key, value.trim()).is_some() {
+                return Err(ModelError::DuplicateField(key.to_string()));
+            }
+        }
+        let field = |name: &'static str| {
+            fields
+                .get(name)
+                .copied()
+                .ok_or(ModelError::MissingField(name))
+        };
+        let invalid = |name: &str, value: &str| ModelError::InvalidField {
+            field: name.to_string(),
+            value: value.to_string(),
+        };
+        let parse = |name: &'static str| {
+            let value = field(name)?;
+            value.parse::<F>().map_err(|_| invalid(name, value))
+        };
+        let parse_count = |name: &'static str| {
+            let value = field(name)?;
+            value.parse::<usize>().map_err(|_| invalid(name, value))
+        };
+        let parse_list = |name: &'static str| {
+            let value = field(name)?;
+            value
+                .split(',')
+                .map(str::trim)
+                .filter(|element| !element.is_empty())
+                .map(|element| element.parse::<F>().map_err(|_| invalid(name, value)))
+                .collect::<Result<Vec<F>, _>>()
+        };
+
+        let basis = match field("basis")? {
+            "polynomial" => Basis::Polynomial {
+                degree: parse_count("degree")?,
+            },
+            "segmented" => Basis::Segmented {
+                degree: parse_count("degree")?,
+                breakpoints: parse_list("breakpoints")?,
+            },
+            "fourier" => Basis::Fourier {
+                harmonics: parse_count("harmonics")?,
+                period: parse("period")?,
+            },
+            "interpolated" => {
+                let inputs = parse_list("inputs")?;
+                if inputs.windows(2).any(|pair| pair[0] >= pair[1]) {
+                    return Err(invalid("inputs", field("inputs")?));
+                }
+                Basis::Interpolated { inputs }
+            }
+            unknown => return Err(ModelError::UnknownBasis(unknown.to_string())),
+        };
+
+        const COMMON_FIELDS: [&str; 4] = ["basis", "input-offset", "input-scale", "coefficients"];
+        if let Some(&unknown) = fields
+            .keys()
+            .find(|&&key| !COMMON_FIELDS.contains(&key) && !basis.field_names().contains(&key))
+        {
+            return Err(ModelError::UnknownField(unknown.to_string(), basis.name()));
+        }
+
+        let scaling = InputScaling {
+            offset: parse("input-offset")?,
+            scale: parse("input-scale")?,
+        };
+        if scaling.scale.is_zero() || !scaling.scale.is_finite() {
+            return Err(invalid("input-scale", field("input-scale")?));
+        }
+        return Ok(Model::new(basis, parse_list("coefficients")?)?.with_input_scaling(scaling));
+    }
+}
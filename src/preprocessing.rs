@@ -0,0 +1,328 @@
+use std::{fmt::Display, num::NonZeroUsize};
+
+use num::Float;
+
+use crate::{Axis, DimensionError, Matrix, MatrixError, Shape};
+
+/// Which whitening transform [Matrix::whiten] should build
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WhiteningKind {
+    /// Rotate onto the principal axes then scale each axis to unit variance
+    Pca,
+    /// Like [WhiteningKind::Pca] but rotated back so the result stays as close as possible to the input
+    Zca,
+}
+
+/// A fitted whitening transform that can be reused on new data
+#[derive(Debug, Clone)]
+pub struct Whitening<F> {
+    mean: Vec<F>,
+    transform: Matrix<F>,
+}
+impl<F: Float> Whitening<F> {
+    /// The column means that are subtracted before [Whitening::transform] is applied
+    pub fn mean(&self) -> &[F] {
+        return &self.mean;
+    }
+    /// The `width x width` matrix that whitened data is right multiplied by
+    pub fn transform(&self) -> &Matrix<F> {
+        return &self.transform;
+    }
+    /// Whiten `data` using the mean and transform fitted on the original data
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if `data` does not have the same number of columns as the fitted data
+    pub fn apply(&self, data: &Matrix<F>) -> Result<Matrix<F>, MatrixError> {
+        if data.width() != self.mean.len() {
+            return Err(DimensionError::UnexpectedWidth {
+                expected: self.mean.len(),
+                actual: data.shape(),
+            }
+            .into());
+        }
+
+        let centered = data.broadcast_op(Axis::Row, &self.mean, |e, mean| e - mean)?;
+        return centered.matrix_multiply(&self.transform);
+    }
+}
+
+/// The minimum, maximum, mean, and sample standard deviation of some elements
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Statistics<F> {
+    pub min: F,
+    pub max: F,
+    pub mean: F,
+    /// The sample standard deviation (divided by `count - 1`)
+    pub std: F,
+}
+impl<F: Float> Statistics<F> {
+    /// The statistics of `elements`
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if there are fewer than 2 elements
+    fn of(elements: &[F]) -> Result<Self, MatrixError> {
+        if elements.len() < 2 {
+            return Err(DimensionError::TooSmall.into());
+        }
+
+        let count = F::from(elements.len()).expect("length fits in a float");
+        let mean = elements.iter().fold(F::zero(), |sum, &e| sum + e) / count;
+        let variance = elements
+            .iter()
+            .fold(F::zero(), |sum, &e| sum + (e - mean) * (e - mean))
+            / (count - F::one());
+
+        return Ok(Statistics {
+            min: elements.iter().fold(F::infinity(), |min, &e| min.min(e)),
+            max: elements
+                .iter()
+                .fold(F::neg_infinity(), |max, &e| max.max(e)),
+            mean,
+            std: variance.sqrt(),
+        });
+    }
+}
+
+/// Per column and overall [Statistics] of a [Matrix], see [Matrix::summary]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Summary<F> {
+    columns: Vec<Statistics<F>>,
+    overall: Statistics<F>,
+}
+impl<F> Summary<F> {
+    /// The statistics of each column
+    pub fn columns(&self) -> &[Statistics<F>] {
+        return &self.columns;
+    }
+    /// The statistics of every element together
+    pub fn overall(&self) -> &Statistics<F> {
+        return &self.overall;
+    }
+}
+impl<F: Display> Display for Summary<F> {
+    /// A table with one row per statistic and one column per matrix column, then `all`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:>6}", "")?;
+        for column in 0..self.columns.len() {
+            write!(f, " {:>12}", column)?;
+        }
+        writeln!(f, " {:>12}", "all")?;
+
+        for (row, name) in ["min", "max", "mean", "std"].into_iter().enumerate() {
+            write!(f, "{:>6}", name)?;
+            for column in self.columns.iter().chain([&self.overall]) {
+                let statistics = [&column.min, &column.max, &column.mean, &column.std];
+                write!(f, " {:>12.4}", statistics[row])?;
+            }
+            writeln!(f)?;
+        }
+        return Ok(());
+    }
+}
+
+impl<F: Float> Matrix<F> {
+    /// The min, max, mean, and standard deviation of each column and of all the elements,
+    /// like a dataframe `describe()` <br>
+    /// `NaN` elements are ignored by min and max but propagate into the mean and std.
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if there are fewer than 2 rows
+    pub fn summary(&self) -> Result<Summary<F>, MatrixError> {
+        let columns = self
+            .columns()
+            .map(|column| Statistics::of(&column.copied().collect::<Vec<_>>()))
+            .collect::<Result<_, _>>()?;
+        let overall = Statistics::of(&self.elements().copied().collect::<Vec<_>>())?;
+        return Ok(Summary { columns, overall });
+    }
+
+    /// The mean of each column
+    pub fn column_means(&self) -> Vec<F> {
+        let count = F::from(self.height()).expect("height fits in a float");
+        return self
+            .columns()
+            .map(|column| column.fold(F::zero(), |sum, &element| sum + element) / count)
+            .collect();
+    }
+
+    /// The sample variance (divided by `height - 1`) of each column
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if there are fewer than 2 rows
+    pub fn column_variances(&self) -> Result<Vec<F>, MatrixError> {
+        if self.height() < 2 {
+            return Err(DimensionError::TooSmall.into());
+        }
+
+        let degrees_of_freedom = F::from(self.height() - 1).expect("height fits in a float");
+        let means = self.column_means();
+
+        let variances = self
+            .columns()
+            .zip(means)
+            .map(|(column, mean)| {
+                column.fold(F::zero(), |sum, &element| {
+                    sum + (element - mean) * (element - mean)
+                }) / degrees_of_freedom
+            })
+            .collect();
+
+        return Ok(variances);
+    }
+
+    /// The sample covariance matrix of the columns <br>
+    /// each row is an observation and each column is a variable
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if there are fewer than 2 rows
+    pub fn covariance(&self) -> Result<Self, MatrixError> {
+        if self.height() < 2 {
+            return Err(DimensionError::TooSmall.into());
+        }
+
+        let degrees_of_freedom = F::from(self.height() - 1).expect("height fits in a float");
+        let centered = self.center_columns();
+
+        let covariance = centered
+            .gram()
+            .scalar_multiply(F::one() / degrees_of_freedom);
+
+        return Ok(covariance);
+    }
+
+    /// The variance inflation factor of each column, `1 / (1 - R²ⱼ)` where `R²ⱼ` is from
+    /// regressing column `j` on the other columns and an intercept <br>
+    /// `1` means column `j` is uncorrelated with the rest, above about `10` its coefficient in a fit
+    /// is poorly determined, and infinite means it is an exact linear combination of the others.
+    /// Computed as the diagonal of the inverse of the correlation matrix of the non-constant columns.
+    /// Constant columns such as an intercept have no variance to inflate and get `NaN`.
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if there are fewer than 2 rows
+    /// - [MatrixError::Decomposition]
+    ///   - if the eigendecomposition of the correlation matrix does not converge
+    pub fn variance_inflation_factors(&self) -> Result<Vec<F>, MatrixError> {
+        let variances = self.column_variances()?;
+        let varying = (0..self.width())
+            .filter(|&column| variances[column] > F::zero())
+            .collect::<Vec<_>>();
+        let mut factors = vec![F::nan(); self.width()];
+        let Some(width) = NonZeroUsize::new(varying.len()) else {
+            return Ok(factors);
+        };
+
+        // standardized so `ZᵀZ / (n - 1)` is the correlation matrix
+        let means = self.column_means();
+        let mut standardized = Matrix::zeros(Shape {
+            rows: self.height_nonzero(),
+            cols: width,
+        });
+        for (standardized_row, row) in standardized.rows_mut().zip(self.rows()) {
+            for (element, &column) in standardized_row.iter_mut().zip(varying.iter()) {
+                *element = (row[column] - means[column]) / variances[column].sqrt();
+            }
+        }
+        let degrees_of_freedom = F::from(self.height() - 1).expect("height fits in a float");
+        let correlation = standardized
+            .gram()
+            .scalar_multiply(F::one() / degrees_of_freedom);
+
+        // diag(V * diag(1 / λ) * Vᵀ), where a zero eigenvalue makes every column in its direction infinite
+        let eigen = correlation.symmetric_eigen()?;
+        let tolerance = F::epsilon() * F::from(width.get()).expect("width fits in a float");
+        for (row, &column) in eigen.eigenvectors().rows().zip(varying.iter()) {
+            factors[column] = row.iter().zip(eigen.eigenvalues()).fold(
+                F::zero(),
+                |sum, (&vector, &eigenvalue)| {
+                    let weight = vector * vector;
+                    if eigenvalue > tolerance {
+                        sum + weight / eigenvalue
+                    } else if weight > tolerance.sqrt() {
+                        F::infinity()
+                    } else {
+                        sum
+                    }
+                },
+            );
+        }
+
+        return Ok(factors);
+    }
+
+    /// Subtract the mean of each column from every element in that column
+    pub fn center_columns(&self) -> Self {
+        return self
+            .broadcast_op(Axis::Row, &self.column_means(), |e, mean| e - mean)
+            .expect("there is one mean per column");
+    }
+
+    /// Center each column then divide it by its sample standard deviation <br>
+    /// Columns with zero variance are only centered.
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if there are fewer than 2 rows
+    pub fn scale_columns_to_unit_variance(&self) -> Result<Self, MatrixError> {
+        let standard_deviations = self
+            .column_variances()?
+            .into_iter()
+            .map(|variance| {
+                if variance.is_zero() {
+                    F::one()
+                } else {
+                    variance.sqrt()
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let mut scaled = self.center_columns();
+        for row in scaled.rows_mut() {
+            for (element, &deviation) in row.iter_mut().zip(standard_deviations.iter()) {
+                *element = *element / deviation;
+            }
+        }
+
+        return Ok(scaled);
+    }
+
+    /// Decorrelate the columns and scale them to unit variance
+    /// ## Parameters
+    /// - `kind`: build a [WhiteningKind::Pca] or [WhiteningKind::Zca] transform.
+    /// - `epsilon`: added to each eigenvalue of the covariance before inverting its square root
+    ///   so that (nearly) constant directions do not blow up.
+    /// ## Returns
+    /// - The whitened matrix and the fitted [Whitening] so the same transform can be applied to new data.
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if there are fewer than 2 rows
+    /// - [MatrixError::Decomposition]
+    ///   - if the eigendecomposition of the covariance does not converge
+    pub fn whiten(
+        &self,
+        kind: WhiteningKind,
+        epsilon: F,
+    ) -> Result<(Self, Whitening<F>), MatrixError> {
+        let eigen = self.covariance()?.symmetric_eigen()?;
+
+        let mut scaled_vectors = eigen.eigenvectors().clone();
+        for row in scaled_vectors.rows_mut() {
+            for (element, &eigenvalue) in row.iter_mut().zip(eigen.eigenvalues()) {
+                *element = *element / (eigenvalue.max(F::zero()) + epsilon).sqrt();
+            }
+        }
+
+        let transform = match kind {
+            WhiteningKind::Pca => scaled_vectors,
+            WhiteningKind::Zca => {
+                scaled_vectors.matrix_multiply(&eigen.eigenvectors().transpose())?
+            }
+        };
+
+        let whitening = Whitening {
+            mean: self.column_means(),
+            transform,
+        };
+        let whitened = whitening.apply(self)?;
+
+        return Ok((whitened, whitening));
+    }
+}
@@ -2,7 +2,21 @@ use std::num::NonZeroUsize;
 
 use num::Float;
 
-use crate::{DataSet, Matrix, MatrixError};
+use crate::{DataSet, DimensionError, Matrix, MatrixError, Shape};
+
+pub mod autoregressive;
+pub mod design;
+pub mod fit;
+pub mod fourier;
+pub mod isotonic;
+pub mod pipeline;
+pub mod segmented;
+pub mod stepwise;
+
+pub use self::{
+    autoregressive::*, design::*, fit::*, fourier::*, isotonic::*, pipeline::*, segmented::*,
+    stepwise::*,
+};
 
 pub trait Regression<T> {
     type Error;
@@ -11,60 +25,189 @@ pub trait Regression<T> {
 
 impl<F: Float> DataSet<F> {
     pub fn polynomial_input_matrix(&self, degree: usize) -> Matrix<F> {
-        // SAFETY: any usize value + 1 is always > 0
-        let width = unsafe { NonZeroUsize::new_unchecked(degree + 1) };
+        // saturating so `usize::MAX` cannot overflow, allocating that many columns fails anyway
+        let width = NonZeroUsize::MIN.saturating_add(degree);
         let height = self.len_nonzero();
 
-        let mut input_matrix = Matrix::zeros(height, width);
+        let mut input_matrix = Matrix::zeros(Shape {
+            rows: height,
+            cols: width,
+        });
 
-        for column_index in 0..width.get() {
-            let exponent = column_index as i32;
+        for (row, data_point) in input_matrix.rows_mut().zip(self.data()) {
+            for (column_index, input_matrix_value) in row.iter_mut().enumerate() {
+                let exponent = column_index as i32;
 
-            for row_index in 0..height.get() {
-                // SAFETY: row_index is inbounds because the input_matrix was declared with height
-                let input_value = unsafe { self.data().get_unchecked(row_index).input() };
-
-                let input_matrix_value = input_value.powi(exponent);
-
-                input_matrix[(row_index, column_index)] = input_matrix_value;
+                *input_matrix_value = data_point.input().powi(exponent);
             }
         }
         return input_matrix;
     }
 
     pub fn polynomial_output_matrix(&self) -> Matrix<F> {
-        /// This is safe because `1` is a valid value of [NonZeroUsize]
-        const ONE: NonZeroUsize = unsafe { NonZeroUsize::new_unchecked(1) };
+        const ONE: NonZeroUsize = NonZeroUsize::new(1).unwrap();
 
         let height = self.len_nonzero();
-        let mut output_matrix = Matrix::zeros(height, ONE);
-
-        for row_index in 0..height.get() {
-            // SAFETY: row_index is inbounds because the output_matrix was declared with height
-            let output_value = unsafe { self.data().get_unchecked(row_index).output() };
+        let mut output_matrix = Matrix::zeros(Shape {
+            rows: height,
+            cols: ONE,
+        });
 
-            output_matrix[(row_index, 0)] = *output_value;
+        for (row, data_point) in output_matrix.rows_mut().zip(self.data()) {
+            row[0] = *data_point.output();
         }
 
         return output_matrix;
     }
 }
 
+/// The pieces of the normal equations `XᵀX * β` == `Xᵀy` behind a least squares fit <br>
+/// Kept so diagnostics such as leverage can be computed from the same matrices as the coefficients.
+#[derive(Debug, Clone)]
+pub struct NormalEquations<F> {
+    /// `X`, one row per data point
+    design: Matrix<F>,
+    /// `y`, a single column
+    response: Matrix<F>,
+    /// `XᵀX`
+    xtx: Matrix<F>,
+    /// `Xᵀy`
+    xty: Matrix<F>,
+}
+impl<F: Float> NormalEquations<F> {
+    /// Form `XᵀX` and `Xᵀy`
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if `response.height()` != `design.height()`
+    pub fn new(design: Matrix<F>, response: Matrix<F>) -> Result<Self, MatrixError> {
+        if response.height() != design.height() {
+            return Err(DimensionError::UnexpectedHeight {
+                expected: design.height(),
+                actual: response.shape(),
+            }
+            .into());
+        }
+
+        let xtx = design.gram();
+        let xty = Matrix::product(&design.transposed_view(), &response)?;
+
+        return Ok(NormalEquations {
+            design,
+            response,
+            xtx,
+            xty,
+        });
+    }
+
+    /// The design matrix `X`
+    pub fn design(&self) -> &Matrix<F> {
+        return &self.design;
+    }
+    /// The response `y`
+    pub fn response(&self) -> &Matrix<F> {
+        return &self.response;
+    }
+    /// `XᵀX`
+    pub fn xtx(&self) -> &Matrix<F> {
+        return &self.xtx;
+    }
+    /// `Xᵀy`
+    pub fn xty(&self) -> &Matrix<F> {
+        return &self.xty;
+    }
+
+    /// The least squares coefficients `β` == `(XᵀX)⁻¹Xᵀy`, found by solving `XᵀX * β` == `Xᵀy`
+    /// ## Errors
+    /// - [MatrixError::Decomposition]
+    ///   - if `XᵀX` is singular
+    pub fn coefficients(&self) -> Result<Matrix<F>, MatrixError> {
+        return self.xtx.solve(&self.xty);
+    }
+
+    /// The hat matrix `H` == `X(XᵀX)⁻¹Xᵀ` that maps `y` onto the fitted values `ŷ` == `H * y`
+    /// ## Errors
+    /// - [MatrixError::Decomposition]
+    ///   - if `XᵀX` is singular
+    pub fn hat_matrix(&self) -> Result<Matrix<F>, MatrixError> {
+        // (XᵀX)⁻¹Xᵀ without forming the inverse
+        let solved = self.xtx.solve(&self.design.transposed_view())?;
+        return self.design.matrix_multiply(&solved);
+    }
+}
+
+impl<F: Float> DataSet<F> {
+    /// The [NormalEquations] of a polynomial fit of `degree`
+    /// ## Errors
+    /// - Same as [NormalEquations::new]
+    pub fn polynomial_normal_equations(
+        &self,
+        degree: usize,
+    ) -> Result<NormalEquations<F>, MatrixError> {
+        return NormalEquations::new(
+            self.polynomial_input_matrix(degree),
+            self.polynomial_output_matrix(),
+        );
+    }
+
+    /// The sum of squared residuals of the polynomial `c₀ + c₁x + ... + cₙxⁿ` over the data <br>
+    /// Generic over the element type so it can be evaluated with [crate::Dual] numbers
+    /// to get its gradient with respect to the coefficients.
+    pub fn polynomial_loss<D: Float + From<F>>(&self, coefficients: &[D]) -> D {
+        return self.data().iter().fold(D::zero(), |loss, data_point| {
+            let input: D = (*data_point.input()).into();
+            let prediction = coefficients
+                .iter()
+                .rev()
+                .fold(D::zero(), |sum, &coefficient| sum * input + coefficient);
+            let residual = prediction - (*data_point.output()).into();
+            loss + residual * residual
+        });
+    }
+
+    /// Fit a polynomial of `degree` keeping the residuals and leverage for diagnostics
+    /// ## Errors
+    /// - Same as [NormalEquations::fit]
+    pub fn polynomial_fit(&self, degree: usize) -> Result<RegressionFit<F>, MatrixError> {
+        return self.polynomial_normal_equations(degree)?.fit();
+    }
+}
+
 impl<F: Float> Regression<F> for DataSet<F> {
     type Error = MatrixError;
     fn polynomial_regression(&self, degree: usize) -> Result<Matrix<F>, Self::Error> {
-        let input_matrix = self.polynomial_input_matrix(degree);
-        let output_matrix = self.polynomial_output_matrix();
-
-        let input_transpose = input_matrix.transpose();
-
-        let pseudo_inverse = input_transpose
-            .matrix_multiply(&input_matrix)?
-            .inverse()?
-            .matrix_multiply(&input_transpose)?;
+        return self.polynomial_normal_equations(degree)?.coefficients();
+    }
+}
 
-        let coefficient_matrix = pseudo_inverse.matrix_multiply(&output_matrix)?;
+/// `inputs` with a column of ones in front
+pub(crate) fn with_intercept<F: Float>(inputs: &Matrix<F>) -> Matrix<F> {
+    let mut design = Matrix::zeros(Shape {
+        rows: inputs.height_nonzero(),
+        cols: NonZeroUsize::MIN.saturating_add(inputs.width()),
+    });
+    for (design_row, input_row) in design.rows_mut().zip(inputs.rows()) {
+        design_row[0] = F::one();
+        design_row[1..].copy_from_slice(input_row);
+    }
+    return design;
+}
 
-        return Ok(coefficient_matrix);
+/// Add `coefficient * (scale * x + offset)ᵖᵒʷᵉʳ` to the coefficients of `polynomial` in ascending powers of `x` <br>
+/// Expanded with the binomial theorem, `Σ C(power, k) scaleᵏ offsetᵖᵒʷᵉʳ⁻ᵏ xᵏ`.
+/// ## Panics
+/// - if `polynomial` has fewer than `power + 1` coefficients
+pub(crate) fn add_affine_power<F: Float>(
+    polynomial: &mut [F],
+    coefficient: F,
+    scale: F,
+    offset: F,
+    power: usize,
+) {
+    let mut binomial = F::one();
+    for (k, element) in polynomial[..=power].iter_mut().enumerate() {
+        let term = binomial * scale.powi(k as i32) * offset.powi((power - k) as i32);
+        *element = *element + coefficient * term;
+        binomial = binomial * F::from(power - k).expect("usize converts to a float")
+            / F::from(k + 1).expect("usize converts to a float");
     }
 }
@@ -0,0 +1,176 @@
+use std::num::NonZeroUsize;
+
+use num::Float;
+
+use crate::{
+    regression::with_intercept, DataSet, DimensionError, Matrix, MatrixError, NormalEquations,
+    RegressionFit, Shape,
+};
+
+/// How [RollingWindow] summarizes the values in a window
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aggregate {
+    Mean,
+    Min,
+    Max,
+    /// The population standard deviation, so a window of one value is `0`
+    StandardDeviation,
+}
+impl Aggregate {
+    fn name(&self) -> &'static str {
+        return match self {
+            Aggregate::Mean => "mean",
+            Aggregate::Min => "min",
+            Aggregate::Max => "max",
+            Aggregate::StandardDeviation => "std",
+        };
+    }
+    fn apply<F: Float>(&self, window: &[F]) -> F {
+        let length = F::from(window.len()).expect("usize converts to a float");
+        let mean = || window.iter().fold(F::zero(), |sum, &value| sum + value) / length;
+        return match self {
+            Aggregate::Mean => mean(),
+            Aggregate::Min => window
+                .iter()
+                .fold(F::infinity(), |min, &value| min.min(value)),
+            Aggregate::Max => window
+                .iter()
+                .fold(F::neg_infinity(), |max, &value| max.max(value)),
+            Aggregate::StandardDeviation => {
+                let mean = mean();
+                let variance = window.iter().fold(F::zero(), |sum, &value| {
+                    sum + (value - mean) * (value - mean)
+                }) / length;
+                variance.sqrt()
+            }
+        };
+    }
+}
+
+/// A feature that summarizes the `length` outputs before each point
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RollingWindow {
+    pub length: NonZeroUsize,
+    pub aggregate: Aggregate,
+}
+
+/// Rows of feature values and the output each row predicts, built by [DataSet::with_lags]
+/// and [DataSet::autoregressive_features]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Features<F> {
+    names: Vec<String>,
+    inputs: Matrix<F>,
+    outputs: Matrix<F>,
+}
+impl<F: Float> Features<F> {
+    /// A name for each column of [Features::inputs] like `lag_7` or `rolling_mean_3`
+    pub fn names(&self) -> &[String] {
+        return &self.names;
+    }
+    /// One row per predicted point and one column per feature
+    pub fn inputs(&self) -> &Matrix<F> {
+        return &self.inputs;
+    }
+    /// A single column with the output each row of [Features::inputs] predicts
+    pub fn outputs(&self) -> &Matrix<F> {
+        return &self.outputs;
+    }
+
+    /// The [NormalEquations] of a linear model of the features with an intercept <br>
+    /// The first column of the design matrix is all ones, the rest are [Features::inputs].
+    pub fn normal_equations(&self) -> NormalEquations<F> {
+        return NormalEquations::new(with_intercept(&self.inputs), self.outputs.clone())
+            .expect("the design and outputs have one row per predicted point");
+    }
+
+    /// Fit an autoregressive model, the intercept then one coefficient per feature
+    /// ## Errors
+    /// - Same as [NormalEquations::fit]
+    pub fn fit(&self) -> Result<RegressionFit<F>, MatrixError> {
+        return self.normal_equations().fit();
+    }
+}
+
+impl<I, F: Float> DataSet<I, F> {
+    /// [DataSet::autoregressive_features] with only lagged outputs
+    pub fn with_lags(&self, lags: &[usize]) -> Result<Features<F>, MatrixError> {
+        return self.autoregressive_features(lags, &[]);
+    }
+
+    /// Predict each output from earlier outputs <br>
+    /// The outputs are used in the order the points are stored,
+    /// so sort a [crate::TimeSeries] by time first.
+    /// Points without enough history for the longest lag or window are dropped.
+    /// ## Parameters
+    /// - `lags`: a feature of the output `lag` points back for each `lag`.
+    /// - `windows`: a feature summarizing the `length` outputs before the point for each window.
+    /// ## Returns
+    /// - [Features] with the lags then the windows as columns,
+    ///   and one row for each point from the longest lag or window on.
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if there are no lags or windows, or a lag is `0`
+    ///   - if no point has enough history for the longest lag or window
+    pub fn autoregressive_features(
+        &self,
+        lags: &[usize],
+        windows: &[RollingWindow],
+    ) -> Result<Features<F>, MatrixError> {
+        if lags.contains(&0) {
+            Err(DimensionError::Zero)?
+        }
+        let Some(width) = NonZeroUsize::new(lags.len() + windows.len()) else {
+            return Err(DimensionError::Zero.into());
+        };
+
+        let outputs = self
+            .data()
+            .iter()
+            .map(|data_point| *data_point.output())
+            .collect::<Vec<_>>();
+        let history = lags
+            .iter()
+            .copied()
+            .chain(windows.iter().map(|window| window.length.get()))
+            .max()
+            .unwrap_or_default();
+        let Some(height) = NonZeroUsize::new(outputs.len().saturating_sub(history)) else {
+            return Err(DimensionError::TooSmall.into());
+        };
+
+        let mut inputs = Matrix::zeros(Shape {
+            rows: height,
+            cols: width,
+        });
+        let mut targets = Matrix::zeros(Shape {
+            rows: height,
+            cols: NonZeroUsize::MIN,
+        });
+        for (row, point) in (history..outputs.len()).enumerate() {
+            targets[row][0] = outputs[point];
+            for (column, lag) in lags.iter().enumerate() {
+                inputs[row][column] = outputs[point - lag];
+            }
+            for (column, window) in windows.iter().enumerate() {
+                let values = &outputs[point - window.length.get()..point];
+                inputs[row][lags.len() + column] = window.aggregate.apply(values);
+            }
+        }
+
+        let names = lags
+            .iter()
+            .map(|lag| format!("lag_{lag}"))
+            .chain(
+                windows
+                    .iter()
+                    .map(|window| format!("rolling_{}_{}", window.aggregate.name(), window.length)),
+            )
+            .collect();
+
+        return Ok(Features {
+            names,
+            inputs,
+            outputs: targets,
+        });
+    }
+}
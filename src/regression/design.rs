@@ -0,0 +1,209 @@
+use std::num::NonZeroUsize;
+
+use num::Float;
+
+use crate::{DimensionError, Matrix, MatrixError, NormalEquations, Shape};
+
+/// A product of input columns raised to powers, one column of a [DesignMatrix]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Term {
+    /// The power of each input column, all `0` for the intercept
+    exponents: Vec<usize>,
+}
+impl Term {
+    /// The power of each input column
+    pub fn exponents(&self) -> &[usize] {
+        return &self.exponents;
+    }
+    /// The sum of the exponents
+    pub fn degree(&self) -> usize {
+        return self.exponents.iter().sum();
+    }
+    /// `true` if more than one input column appears in the term
+    pub fn is_interaction(&self) -> bool {
+        return self.exponents.iter().filter(|&&power| power > 0).count() > 1;
+    }
+
+    /// A name like `x1*x2^2`, or `1` for the intercept
+    fn name(&self, input_names: &[String]) -> String {
+        let factors = self
+            .exponents
+            .iter()
+            .zip(input_names)
+            .filter(|(&power, _)| power > 0)
+            .map(|(&power, name)| match power {
+                1 => name.clone(),
+                power => format!("{name}^{power}"),
+            })
+            .collect::<Vec<_>>();
+        return if factors.is_empty() {
+            "1".to_string()
+        } else {
+            factors.join("*")
+        };
+    }
+
+    fn evaluate<F: Float>(&self, inputs: &[F]) -> F {
+        return self
+            .exponents
+            .iter()
+            .zip(inputs)
+            .fold(F::one(), |product, (&power, &input)| {
+                product * input.powi(power as i32)
+            });
+    }
+}
+
+/// Builds the columns of a [DesignMatrix] from named input columns <br>
+/// By default the terms are an intercept then every product of the inputs up to degree `1`.
+/// For two inputs `x1, x2` and degree `2` the columns are `1, x1, x2, x1^2, x1*x2, x2^2`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DesignMatrixBuilder {
+    input_names: Vec<String>,
+    degree: usize,
+    intercept: bool,
+    interactions: bool,
+}
+impl DesignMatrixBuilder {
+    /// A builder for inputs with one column per name
+    pub fn new(input_names: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        return DesignMatrixBuilder {
+            input_names: input_names.into_iter().map(Into::into).collect(),
+            degree: 1,
+            intercept: true,
+            interactions: true,
+        };
+    }
+    /// Include every term with a total degree of at most `degree`
+    pub fn degree(mut self, degree: usize) -> Self {
+        self.degree = degree;
+        return self;
+    }
+    /// Whether the first column is the all ones intercept
+    pub fn intercept(mut self, intercept: bool) -> Self {
+        self.intercept = intercept;
+        return self;
+    }
+    /// Whether products of different inputs like `x1*x2` are included,
+    /// without them only powers of a single input are
+    pub fn interactions(mut self, interactions: bool) -> Self {
+        self.interactions = interactions;
+        return self;
+    }
+
+    pub fn input_names(&self) -> &[String] {
+        return &self.input_names;
+    }
+
+    /// The terms in column order, by degree then by the order of the inputs
+    pub fn terms(&self) -> Vec<Term> {
+        let inputs = self.input_names.len();
+        let mut terms = Vec::new();
+        if self.intercept {
+            terms.push(Term {
+                exponents: vec![0; inputs],
+            });
+        }
+
+        // each term of degree d is a non-decreasing sequence of d input indices
+        for degree in 1..=self.degree {
+            if inputs == 0 {
+                break;
+            }
+            let mut indices = vec![0; degree];
+            loop {
+                let mut exponents = vec![0; inputs];
+                for &index in indices.iter() {
+                    exponents[index] += 1;
+                }
+                let term = Term { exponents };
+                if self.interactions || !term.is_interaction() {
+                    terms.push(term);
+                }
+
+                // advance to the next non-decreasing sequence
+                let Some(position) = indices.iter().rposition(|&index| index + 1 < inputs) else {
+                    break;
+                };
+                let next = indices[position] + 1;
+                for index in indices[position..].iter_mut() {
+                    *index = next;
+                }
+            }
+        }
+
+        return terms;
+    }
+
+    /// Evaluate every term for each row of `inputs`
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if `inputs` does not have one column per input name
+    ///   - if there are no terms, which happens without an intercept and a degree of `0`
+    pub fn build<F: Float>(&self, inputs: &Matrix<F>) -> Result<DesignMatrix<F>, MatrixError> {
+        if inputs.width() != self.input_names.len() {
+            return Err(DimensionError::UnexpectedWidth {
+                expected: self.input_names.len(),
+                actual: inputs.shape(),
+            }
+            .into());
+        }
+
+        let terms = self.terms();
+        let width = NonZeroUsize::new(terms.len()).ok_or(DimensionError::Zero)?;
+        let mut matrix = Matrix::zeros(Shape {
+            rows: inputs.height_nonzero(),
+            cols: width,
+        });
+        for (design_row, input_row) in matrix.rows_mut().zip(inputs.rows()) {
+            for (element, term) in design_row.iter_mut().zip(terms.iter()) {
+                *element = term.evaluate(input_row);
+            }
+        }
+
+        let names = terms
+            .iter()
+            .map(|term| term.name(&self.input_names))
+            .collect();
+        return Ok(DesignMatrix {
+            names,
+            terms,
+            matrix,
+        });
+    }
+}
+
+/// The columns made by [DesignMatrixBuilder::build] with the name and [Term] of each
+#[derive(Debug, Clone, PartialEq)]
+pub struct DesignMatrix<F> {
+    names: Vec<String>,
+    terms: Vec<Term>,
+    matrix: Matrix<F>,
+}
+impl<F: Float> DesignMatrix<F> {
+    /// A name for each column like `1`, `x1`, or `x1*x2`
+    pub fn names(&self) -> &[String] {
+        return &self.names;
+    }
+    pub fn terms(&self) -> &[Term] {
+        return &self.terms;
+    }
+    /// One row per input row and one column per term
+    pub fn matrix(&self) -> &Matrix<F> {
+        return &self.matrix;
+    }
+    pub fn into_matrix(self) -> Matrix<F> {
+        return self.matrix;
+    }
+    /// The index of the column called `name`
+    pub fn column_index(&self, name: &str) -> Option<usize> {
+        return self.names.iter().position(|column| column == name);
+    }
+
+    /// The [NormalEquations] of a least squares fit of `outputs`, one coefficient per column
+    /// ## Errors
+    /// - Same as [NormalEquations::new]
+    pub fn normal_equations(&self, outputs: Matrix<F>) -> Result<NormalEquations<F>, MatrixError> {
+        return NormalEquations::new(self.matrix.clone(), outputs);
+    }
+}
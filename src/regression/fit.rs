@@ -0,0 +1,421 @@
+use num::Float;
+
+use crate::{DecompositionError, DimensionError, Matrix, MatrixError, NormalEquations};
+
+/// How [RegressionFit::coefficient_covariance] estimates the covariance of the coefficients
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CovarianceEstimator {
+    /// `s² (XᵀX)⁻¹`, assumes every data point has the same noise variance
+    Classical,
+    /// White's estimator `(XᵀX)⁻¹ Xᵀ diag(eᵢ²) X (XᵀX)⁻¹`, robust to noise variance that changes between data points
+    Hc0,
+    /// [CovarianceEstimator::Hc0] scaled by `n / (n - p)` to reduce its bias in small samples
+    Hc1,
+}
+
+/// The variance inflation factor above which [FitReport::is_collinear] warns, the usual rule of thumb
+pub const VARIANCE_INFLATION_WARNING: f64 = 10.0;
+
+/// How numerically trustworthy a [RegressionFit] is and which fallbacks it needed
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FitReport<F> {
+    condition_number: F,
+    rank: usize,
+    parameters: usize,
+    pseudo_inverse: bool,
+    ridge_jitter: Option<F>,
+    max_variance_inflation: F,
+}
+impl<F: Float> FitReport<F> {
+    /// `λmax / λmin` of `XᵀX`, infinite if it is singular <br>
+    /// Roughly `log₁₀` of this many digits of the coefficients are lost to rounding.
+    pub fn condition_number(&self) -> F {
+        return self.condition_number;
+    }
+    /// The numerical rank of `XᵀX`
+    pub fn rank(&self) -> usize {
+        return self.rank;
+    }
+    /// `true` if `XᵀX` was rank deficient so the minimum norm solution from its pseudo-inverse was used
+    pub fn used_pseudo_inverse(&self) -> bool {
+        return self.pseudo_inverse;
+    }
+    /// The amount added to the diagonal of `XᵀX` when it had full rank but rounding made it
+    /// fail to factor as positive definite
+    pub fn ridge_jitter(&self) -> Option<F> {
+        return self.ridge_jitter;
+    }
+    /// `true` if a fallback was used or the condition number is beyond `1 / √ε`,
+    /// at which point at least half the digits of the coefficients may be wrong
+    pub fn is_degraded(&self) -> bool {
+        return self.rank < self.parameters
+            || self.pseudo_inverse
+            || self.ridge_jitter.is_some()
+            || self.condition_number > F::one() / F::epsilon().sqrt();
+    }
+    /// The largest [Matrix::variance_inflation_factors] of the design matrix <br>
+    /// `NaN` if no column varies or there are too few data points to tell.
+    pub fn max_variance_inflation(&self) -> F {
+        return self.max_variance_inflation;
+    }
+    /// `true` if [FitReport::max_variance_inflation] is above [VARIANCE_INFLATION_WARNING] <br>
+    /// The columns are so nearly linear combinations of each other that their individual
+    /// coefficients and standard errors can not be interpreted, even if the predictions are fine.
+    pub fn is_collinear(&self) -> bool {
+        let threshold = F::from(VARIANCE_INFLATION_WARNING).expect("f64 converts to a float");
+        return self.max_variance_inflation > threshold;
+    }
+}
+
+/// A least squares fit `y` ≈ `X * β` with the pieces needed for diagnostics
+#[derive(Debug, Clone)]
+pub struct RegressionFit<F> {
+    normal: NormalEquations<F>,
+    report: FitReport<F>,
+    /// `(XᵀX)⁻¹`, or its pseudo-inverse if [FitReport::used_pseudo_inverse]
+    xtx_inverse: Matrix<F>,
+    /// `β`, a single column
+    coefficients: Matrix<F>,
+    /// `y - X * β`
+    residuals: Vec<F>,
+}
+impl<F: Float> NormalEquations<F> {
+    /// Solve for the coefficients and keep everything needed for diagnostics <br>
+    /// Rather than failing on a singular `XᵀX` this falls back to its pseudo-inverse,
+    /// and [RegressionFit::report] records when that happened.
+    /// ## Errors
+    /// - [MatrixError::Decomposition]
+    ///   - if the eigendecomposition of `XᵀX` does not converge
+    pub fn fit(self) -> Result<RegressionFit<F>, MatrixError> {
+        let eigen = self.xtx().symmetric_eigen()?;
+        let eigenvalues = eigen.eigenvalues();
+        let parameters = eigenvalues.len();
+        let largest = eigenvalues[parameters - 1];
+        let smallest = eigenvalues[0];
+        let tolerance =
+            largest * F::epsilon() * F::from(parameters).expect("parameters fit in a float");
+
+        let rank = eigenvalues
+            .iter()
+            .filter(|&&eigenvalue| eigenvalue > tolerance)
+            .count();
+        let condition_number = if smallest > F::zero() {
+            largest / smallest
+        } else {
+            F::infinity()
+        };
+
+        let mut ridge_jitter = None;
+        let xtx_inverse = if rank < parameters {
+            // V * diag(1 / λ) * Vᵀ keeping only the eigenvalues above the tolerance
+            let mut scaled_vectors = eigen.eigenvectors().clone();
+            for row in scaled_vectors.rows_mut() {
+                for (element, &eigenvalue) in row.iter_mut().zip(eigenvalues) {
+                    *element = if eigenvalue > tolerance {
+                        *element / eigenvalue
+                    } else {
+                        F::zero()
+                    };
+                }
+            }
+            Matrix::product(&scaled_vectors, &eigen.eigenvectors().transposed_view())?
+        } else {
+            let mut xtx = self.xtx().clone();
+            if let Err(MatrixError::Decomposition(DecompositionError::NotPositiveDefinite)) =
+                xtx.cholesky()
+            {
+                for element in xtx.diagonal_iter_mut() {
+                    *element = *element + tolerance;
+                }
+                ridge_jitter = Some(tolerance);
+            }
+            xtx.solve(&Matrix::identity(xtx.height_nonzero()))?
+        };
+
+        let report = FitReport {
+            condition_number,
+            rank,
+            parameters,
+            pseudo_inverse: rank < parameters,
+            ridge_jitter,
+            max_variance_inflation: max_variance_inflation(self.design()),
+        };
+
+        let coefficients = xtx_inverse.matrix_multiply(self.xty())?;
+        let fitted = self.design().matrix_multiply(&coefficients)?;
+        let residuals = self
+            .response()
+            .column(0)
+            .zip(fitted.column(0))
+            .map(|(&observed, &fitted)| observed - fitted)
+            .collect();
+
+        return Ok(RegressionFit {
+            normal: self,
+            report,
+            xtx_inverse,
+            coefficients,
+            residuals,
+        });
+    }
+}
+impl<F: Float> RegressionFit<F> {
+    pub fn normal_equations(&self) -> &NormalEquations<F> {
+        return &self.normal;
+    }
+    /// The condition number, rank, and fallbacks used while fitting
+    pub fn report(&self) -> &FitReport<F> {
+        return &self.report;
+    }
+    /// `β`, one row per column of the design matrix
+    pub fn coefficients(&self) -> &Matrix<F> {
+        return &self.coefficients;
+    }
+    /// `(XᵀX)⁻¹`
+    pub fn xtx_inverse(&self) -> &Matrix<F> {
+        return &self.xtx_inverse;
+    }
+    /// `y - X * β` in data point order
+    pub fn residuals(&self) -> &[F] {
+        return &self.residuals;
+    }
+    /// The number of data points `n`
+    pub fn observations(&self) -> usize {
+        return self.normal.design().height();
+    }
+    /// The number of coefficients `p`
+    pub fn parameters(&self) -> usize {
+        return self.normal.design().width();
+    }
+    /// The residual sum of squares
+    pub fn residual_sum_of_squares(&self) -> F {
+        return self
+            .residuals
+            .iter()
+            .fold(F::zero(), |sum, &residual| sum + residual * residual);
+    }
+
+    /// The unbiased residual variance `s²` == `RSS / (n - p)`
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if there are not more data points than coefficients
+    pub fn residual_variance(&self) -> Result<F, MatrixError> {
+        let degrees_of_freedom = self.degrees_of_freedom()?;
+        return Ok(self.residual_sum_of_squares() / degrees_of_freedom);
+    }
+
+    /// The fraction of the variance of the response the fit explains, `1 - RSS / TSS` <br>
+    /// `TSS` is the sum of squares about the mean of the response, so this assumes the design has an intercept.
+    /// `NaN` if every response is the same.
+    pub fn r_squared(&self) -> F {
+        return F::one() - self.residual_sum_of_squares() / self.total_sum_of_squares();
+    }
+
+    /// [RegressionFit::r_squared] penalized for the number of coefficients,
+    /// `1 - (RSS / (n - p)) / (TSS / (n - 1))` <br>
+    /// Unlike `R²` it can decrease when a column that explains little is added.
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if there are not more data points than coefficients
+    pub fn adjusted_r_squared(&self) -> Result<F, MatrixError> {
+        let degrees_of_freedom = self.degrees_of_freedom()?;
+        let total_degrees_of_freedom =
+            F::from(self.observations() - 1).expect("observations fit in a float");
+        return Ok(F::one()
+            - (self.residual_sum_of_squares() / degrees_of_freedom)
+                / (self.total_sum_of_squares() / total_degrees_of_freedom));
+    }
+
+    /// The Akaike information criterion of gaussian errors, `n * ln(RSS / n) + 2p` up to a constant <br>
+    /// Only differences between fits of the same response mean anything, lower is better.
+    /// `-∞` for a perfect fit.
+    pub fn aic(&self) -> F {
+        let observations = F::from(self.observations()).expect("observations fit in a float");
+        let parameters = F::from(self.parameters()).expect("parameters fit in a float");
+        return observations * (self.residual_sum_of_squares() / observations).ln()
+            + (parameters + parameters);
+    }
+
+    /// `Σ(yᵢ - ȳ)²`
+    fn total_sum_of_squares(&self) -> F {
+        let response = self.normal.response();
+        let count = F::from(response.height()).expect("height fits in a float");
+        let mean = response.column(0).fold(F::zero(), |sum, &y| sum + y) / count;
+        return response
+            .column(0)
+            .fold(F::zero(), |sum, &y| sum + (y - mean) * (y - mean));
+    }
+
+    /// The diagonal of the hat matrix, `hᵢ` == `xᵢ(XᵀX)⁻¹xᵢᵀ` <br>
+    /// Each is in `[0, 1]` and measures how much data point `i` pulls the fit towards itself.
+    pub fn leverage(&self) -> Vec<F> {
+        let design = self.normal.design();
+        return design
+            .rows()
+            .map(|row| {
+                let mut leverage = F::zero();
+                for (j, &x_j) in row.iter().enumerate() {
+                    for (k, &x_k) in row.iter().enumerate() {
+                        leverage = leverage + x_j * self.xtx_inverse[j][k] * x_k;
+                    }
+                }
+                leverage
+            })
+            .collect();
+    }
+
+    /// Internally studentized residuals `eᵢ / (s * √(1 - hᵢ))` <br>
+    /// Points with a leverage of `1` are fit exactly and get `NaN`.
+    /// ## Errors
+    /// - Same as [RegressionFit::residual_variance]
+    pub fn studentized_residuals(&self) -> Result<Vec<F>, MatrixError> {
+        let deviation = self.residual_variance()?.sqrt();
+        return Ok(self
+            .residuals
+            .iter()
+            .zip(self.leverage())
+            .map(|(&residual, leverage)| residual / (deviation * (F::one() - leverage).sqrt()))
+            .collect());
+    }
+
+    /// Cook's distance `Dᵢ` == `eᵢ² / (p * s²) * hᵢ / (1 - hᵢ)²` <br>
+    /// How far the fitted values move when data point `i` is left out.
+    /// A common rule of thumb flags points with `Dᵢ > 4 / n`.
+    /// ## Errors
+    /// - Same as [RegressionFit::residual_variance]
+    pub fn cooks_distance(&self) -> Result<Vec<F>, MatrixError> {
+        let variance = self.residual_variance()?;
+        let parameters = F::from(self.parameters()).expect("parameters fit in a float");
+        return Ok(self
+            .residuals
+            .iter()
+            .zip(self.leverage())
+            .map(|(&residual, leverage)| {
+                let complement = F::one() - leverage;
+                residual * residual / (parameters * variance) * leverage / (complement * complement)
+            })
+            .collect());
+    }
+
+    /// The Durbin–Watson statistic `Σ(eᵢ - eᵢ₋₁)² / Σeᵢ²` of the residuals in data point order <br>
+    /// Near `2` when neighbouring residuals are uncorrelated,
+    /// towards `0` for positive and towards `4` for negative autocorrelation.
+    /// `NaN` if every residual is `0`.
+    pub fn durbin_watson(&self) -> F {
+        let differences = self.residuals.windows(2).fold(F::zero(), |sum, pair| {
+            sum + (pair[1] - pair[0]) * (pair[1] - pair[0])
+        });
+        return differences / self.residual_sum_of_squares();
+    }
+
+    /// The sample autocorrelation of the residuals at lags `1..=max_lag` <br>
+    /// `rₖ` == `Σeᵢeᵢ₋ₖ / Σeᵢ²`, the residuals of a fit with an intercept already have a mean of `0`.
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if `max_lag` is not less than the number of data points
+    pub fn residual_autocorrelation(&self, max_lag: usize) -> Result<Vec<F>, MatrixError> {
+        if max_lag >= self.observations() {
+            return Err(DimensionError::TooSmall.into());
+        }
+
+        let sum_of_squares = self.residual_sum_of_squares();
+        return Ok((1..=max_lag)
+            .map(|lag| {
+                self.residuals
+                    .iter()
+                    .zip(&self.residuals[lag..])
+                    .fold(F::zero(), |sum, (&earlier, &later)| sum + earlier * later)
+                    / sum_of_squares
+            })
+            .collect());
+    }
+
+    /// The Ljung–Box statistic `Q` == `n(n + 2) Σ rₖ² / (n - k)` over lags `1..=max_lag` <br>
+    /// Under uncorrelated residuals `Q` is approximately chi-squared with `max_lag` degrees of freedom,
+    /// so large values indicate autocorrelation.
+    /// ## Errors
+    /// - Same as [RegressionFit::residual_autocorrelation]
+    pub fn ljung_box(&self, max_lag: usize) -> Result<F, MatrixError> {
+        let n = self.observations();
+        let to_float = |value: usize| F::from(value).expect("observations fit in a float");
+
+        let weighted = self
+            .residual_autocorrelation(max_lag)?
+            .into_iter()
+            .zip(1..)
+            .fold(F::zero(), |sum, (r, lag)| sum + r * r / to_float(n - lag));
+
+        return Ok(to_float(n) * to_float(n + 2) * weighted);
+    }
+
+    /// The estimated covariance matrix of the coefficients, `p x p`
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if `estimator` is [CovarianceEstimator::Classical] or [CovarianceEstimator::Hc1]
+    ///     and there are not more data points than coefficients
+    pub fn coefficient_covariance(
+        &self,
+        estimator: CovarianceEstimator,
+    ) -> Result<Matrix<F>, MatrixError> {
+        if estimator == CovarianceEstimator::Classical {
+            return Ok(self.xtx_inverse.scalar_multiply(self.residual_variance()?));
+        }
+
+        // Xᵀ diag(eᵢ²) X
+        let mut meat = Matrix::zeros(self.xtx_inverse.shape());
+        for (row, &residual) in self.normal.design().rows().zip(&self.residuals) {
+            let weight = residual * residual;
+            for (j, &x_j) in row.iter().enumerate() {
+                for (k, &x_k) in row.iter().enumerate() {
+                    meat[j][k] = meat[j][k] + weight * x_j * x_k;
+                }
+            }
+        }
+        let sandwich = self
+            .xtx_inverse
+            .matrix_multiply(&meat)?
+            .matrix_multiply(&self.xtx_inverse)?;
+
+        return Ok(match estimator {
+            CovarianceEstimator::Hc1 => {
+                let observations =
+                    F::from(self.observations()).expect("observations fit in a float");
+                sandwich.scalar_multiply(observations / self.degrees_of_freedom()?)
+            }
+            _ => sandwich,
+        });
+    }
+
+    /// The standard error of each coefficient, the square root of the diagonal of
+    /// [RegressionFit::coefficient_covariance]
+    /// ## Errors
+    /// - Same as [RegressionFit::coefficient_covariance]
+    pub fn standard_errors(&self, estimator: CovarianceEstimator) -> Result<Vec<F>, MatrixError> {
+        return Ok(self
+            .coefficient_covariance(estimator)?
+            .diagonal_iter()
+            .map(|variance| variance.sqrt())
+            .collect());
+    }
+
+    /// `n - p` as a float
+    fn degrees_of_freedom(&self) -> Result<F, MatrixError> {
+        if self.observations() <= self.parameters() {
+            return Err(DimensionError::TooSmall.into());
+        }
+        return Ok(
+            F::from(self.observations() - self.parameters()).expect("observations fit in a float")
+        );
+    }
+}
+
+/// The largest variance inflation factor of the varying columns of `design`, `NaN` if there is none
+fn max_variance_inflation<F: Float>(design: &Matrix<F>) -> F {
+    return match design.variance_inflation_factors() {
+        Ok(factors) => factors
+            .into_iter()
+            .filter(|factor| !factor.is_nan())
+            .fold(F::nan(), F::max),
+        Err(_) => F::nan(),
+    };
+}
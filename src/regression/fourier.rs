@@ -0,0 +1,113 @@
+use std::num::NonZeroUsize;
+
+use num::Float;
+
+use crate::{DataSet, DimensionError, Matrix, MatrixError, NormalEquations, RegressionFit, Shape};
+
+/// A fit of `a + Σ(bₖ sin(2πkx / period) + cₖ cos(2πkx / period))` for `k` in `1..=harmonics`
+#[derive(Debug, Clone)]
+pub struct FourierFit<F> {
+    period: F,
+    harmonics: usize,
+    fit: RegressionFit<F>,
+}
+impl<F: Float> FourierFit<F> {
+    pub fn period(&self) -> F {
+        return self.period;
+    }
+    pub fn harmonics(&self) -> usize {
+        return self.harmonics;
+    }
+    /// The underlying least squares fit, its coefficients are `a, b₁, c₁, b₂, c₂, ...`
+    pub fn fit(&self) -> &RegressionFit<F> {
+        return &self.fit;
+    }
+    /// The constant term `a`
+    pub fn intercept(&self) -> F {
+        return self.fit.coefficients()[0][0];
+    }
+    /// `bₖ`, the coefficient of `sin(2πkx / period)` at index `k - 1`
+    pub fn sine_coefficients(&self) -> Vec<F> {
+        return (0..self.harmonics)
+            .map(|k| self.fit.coefficients()[1 + 2 * k][0])
+            .collect();
+    }
+    /// `cₖ`, the coefficient of `cos(2πkx / period)` at index `k - 1`
+    pub fn cosine_coefficients(&self) -> Vec<F> {
+        return (0..self.harmonics)
+            .map(|k| self.fit.coefficients()[2 + 2 * k][0])
+            .collect();
+    }
+
+    /// Evaluate the fitted series at `input`
+    pub fn predict(&self, input: F) -> F {
+        return fourier_terms(input, self.period, self.harmonics)
+            .zip(self.fit.coefficients().column(0))
+            .fold(F::zero(), |sum, (term, &coefficient)| {
+                sum + term * coefficient
+            });
+    }
+}
+
+/// `1, sin(ωx), cos(ωx), sin(2ωx), cos(2ωx), ...` where `ω` == `2π / period`
+pub(crate) fn fourier_terms<F: Float>(
+    input: F,
+    period: F,
+    harmonics: usize,
+) -> impl Iterator<Item = F> {
+    let tau = F::from(std::f64::consts::TAU).expect("f64 converts to a float");
+    let angle = tau * input / period;
+    let waves = (1..=harmonics).flat_map(move |k| {
+        let (sin, cos) = (angle * F::from(k).expect("usize converts to a float")).sin_cos();
+        [sin, cos]
+    });
+    return std::iter::once(F::one()).chain(waves);
+}
+
+impl<F: Float> DataSet<F> {
+    /// Fit a Fourier series with `harmonics` sine and cosine pairs by least squares <br>
+    /// Suited to seasonal data where the period is known, such as `365.25` days or `24` hours.
+    /// ## Parameters
+    /// - `harmonics`: the number of `sin`/`cos` pairs, `0` fits only the mean.
+    /// - `period`: the length of one cycle in units of the inputs.
+    /// ## Errors
+    /// - [MatrixError::NotFinite]
+    ///   - if `period` is `NaN` or infinite
+    /// - [MatrixError::DimensionError]
+    ///   - if `period` is `0` or negative
+    /// - Same as [NormalEquations::fit]
+    pub fn fourier_regression(
+        &self,
+        harmonics: usize,
+        period: F,
+    ) -> Result<FourierFit<F>, MatrixError> {
+        if !period.is_finite() {
+            Err(MatrixError::NotFinite("the period"))?
+        }
+        if period <= F::zero() {
+            Err(DimensionError::TooSmall)?
+        }
+
+        let width = NonZeroUsize::MIN.saturating_add(2 * harmonics);
+        let mut design = Matrix::zeros(Shape {
+            rows: self.len_nonzero(),
+            cols: width,
+        });
+        for (row, data_point) in design.rows_mut().zip(self.data()) {
+            for (element, term) in
+                row.iter_mut()
+                    .zip(fourier_terms(*data_point.input(), period, harmonics))
+            {
+                *element = term;
+            }
+        }
+
+        let fit = NormalEquations::new(design, self.polynomial_output_matrix())?.fit()?;
+
+        return Ok(FourierFit {
+            period,
+            harmonics,
+            fit,
+        });
+    }
+}
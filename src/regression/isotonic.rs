@@ -0,0 +1,133 @@
+use num::Float;
+
+use crate::{matrix::sort::compare_nan_last, DataSet, MatrixError, SortDirection};
+
+/// A non-decreasing step fit made by [DataSet::isotonic_regression]
+#[derive(Debug, Clone, PartialEq)]
+pub struct IsotonicFit<F> {
+    inputs: Vec<F>,
+    fitted: Vec<F>,
+    residual_sum_of_squares: F,
+}
+impl<F: Float> IsotonicFit<F> {
+    /// The distinct inputs in ascending order
+    pub fn inputs(&self) -> &[F] {
+        return &self.inputs;
+    }
+    /// The fitted value at each of [IsotonicFit::inputs], never decreasing
+    pub fn fitted(&self) -> &[F] {
+        return &self.fitted;
+    }
+    pub fn residual_sum_of_squares(&self) -> F {
+        return self.residual_sum_of_squares;
+    }
+
+    /// Evaluate the fit at `input` <br>
+    /// Interpolates linearly between the fitted inputs and is constant past either end,
+    /// so the prediction is still non-decreasing.
+    pub fn predict(&self, input: F) -> F {
+        return interpolate(&self.inputs, &self.fitted, input);
+    }
+}
+
+/// Linear interpolation through `(inputs[i], values[i])`, constant past either end
+/// ## Panics
+/// - if `inputs` is empty, it must be ascending and the same length as `values`
+pub(crate) fn interpolate<F: Float>(inputs: &[F], values: &[F], input: F) -> F {
+    let after = inputs.partition_point(|&known| known <= input);
+    if after == 0 {
+        return values[0];
+    }
+    if after == inputs.len() {
+        return values[after - 1];
+    }
+
+    let (x_0, x_1) = (inputs[after - 1], inputs[after]);
+    let (y_0, y_1) = (values[after - 1], values[after]);
+    return y_0 + (y_1 - y_0) * (input - x_0) / (x_1 - x_0);
+}
+
+/// Adjacent points pooled into one fitted value, their mean
+struct Block<F> {
+    sum: F,
+    count: F,
+    /// The number of distinct inputs in the block
+    length: usize,
+}
+impl<F: Float> Block<F> {
+    fn mean(&self) -> F {
+        return self.sum / self.count;
+    }
+}
+
+impl<F: Float> DataSet<F> {
+    /// Fit the non-decreasing function closest to the outputs in least squares <br>
+    /// Uses the pool adjacent violators algorithm: walk the points by input and,
+    /// whenever a block's mean is below the one before it, merge them into their combined mean.
+    /// Points with the same input share one fitted value. `O(n log n)` for the sort.
+    /// For a non-increasing fit negate the outputs, fit, and negate the result.
+    /// ## Errors
+    /// - [MatrixError::NotFinite]
+    ///   - if an input or an output is `NaN` or infinite, which could never be pooled or ordered
+    pub fn isotonic_regression(&self) -> Result<IsotonicFit<F>, MatrixError> {
+        let mut points = self
+            .data()
+            .iter()
+            .map(
+                |data_point| match (*data_point.input(), *data_point.output()) {
+                    (input, _) if !input.is_finite() => Err(MatrixError::NotFinite("an input")),
+                    (_, output) if !output.is_finite() => Err(MatrixError::NotFinite("an output")),
+                    point => Ok(point),
+                },
+            )
+            .collect::<Result<Vec<_>, _>>()?;
+        points.sort_by(|a, b| compare_nan_last(&a.0, &b.0, SortDirection::Ascending));
+
+        let mut inputs: Vec<F> = Vec::new();
+        let mut blocks: Vec<Block<F>> = Vec::new();
+        for &(input, output) in points.iter() {
+            if inputs.last() == Some(&input) {
+                let block = blocks.last_mut().expect("a block exists for every input");
+                block.sum = block.sum + output;
+                block.count = block.count + F::one();
+            } else {
+                inputs.push(input);
+                blocks.push(Block {
+                    sum: output,
+                    count: F::one(),
+                    length: 1,
+                });
+            }
+
+            while blocks.len() > 1
+                && blocks[blocks.len() - 2].mean() > blocks[blocks.len() - 1].mean()
+            {
+                let last = blocks.pop().expect("there are at least two blocks");
+                let previous = blocks.last_mut().expect("there are at least two blocks");
+                previous.sum = previous.sum + last.sum;
+                previous.count = previous.count + last.count;
+                previous.length += last.length;
+            }
+        }
+
+        let fitted = blocks
+            .iter()
+            .flat_map(|block| std::iter::repeat_n(block.mean(), block.length))
+            .collect::<Vec<_>>();
+
+        let fit = IsotonicFit {
+            inputs,
+            fitted,
+            residual_sum_of_squares: F::zero(),
+        };
+        let residual_sum_of_squares = points.iter().fold(F::zero(), |sum, &(input, output)| {
+            let residual = output - fit.predict(input);
+            sum + residual * residual
+        });
+
+        return Ok(IsotonicFit {
+            residual_sum_of_squares,
+            ..fit
+        });
+    }
+}
@@ -0,0 +1,250 @@
+use std::num::NonZeroUsize;
+
+use num::Float;
+
+use crate::{
+    regression::with_intercept, DimensionError, Matrix, MatrixError, NormalEquations, Shape,
+};
+
+/// A transform of the input columns that [Pipeline::fit] learns from the training inputs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    /// Center each column and divide it by its sample standard deviation,
+    /// columns with zero variance are only centered
+    Standardize,
+    /// Replace each column `x` with the columns `x, x², ..., xᵈ`
+    PolynomialFeatures { degree: usize },
+}
+
+/// How [Pipeline::fit] fits the transformed inputs, always with an unpenalized intercept
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Estimator<F> {
+    /// Ordinary least squares, see [NormalEquations::fit]
+    LeastSquares,
+    /// Least squares plus `penalty * |β|²`, shrinking the coefficients towards zero
+    Ridge { penalty: F },
+}
+
+/// An unfitted sequence of [Step]s followed by an [Estimator], for example
+/// ```text
+/// Pipeline::new(Estimator::Ridge { penalty: 0.1 })
+///     .standardize()
+///     .polynomial_features(3)
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pipeline<F> {
+    steps: Vec<Step>,
+    estimator: Estimator<F>,
+}
+impl<F: Float> Pipeline<F> {
+    /// A pipeline with no steps, so `estimator` sees the inputs unchanged
+    pub fn new(estimator: Estimator<F>) -> Self {
+        return Pipeline {
+            steps: Vec::new(),
+            estimator,
+        };
+    }
+    /// Add `step` after the existing steps
+    pub fn then(mut self, step: Step) -> Self {
+        self.steps.push(step);
+        return self;
+    }
+    /// Add a [Step::Standardize]
+    pub fn standardize(self) -> Self {
+        return self.then(Step::Standardize);
+    }
+    /// Add a [Step::PolynomialFeatures]
+    pub fn polynomial_features(self, degree: usize) -> Self {
+        return self.then(Step::PolynomialFeatures { degree });
+    }
+
+    pub fn steps(&self) -> &[Step] {
+        return &self.steps;
+    }
+    pub fn estimator(&self) -> &Estimator<F> {
+        return &self.estimator;
+    }
+
+    /// Learn each step from the output of the step before it, then fit the estimator
+    /// ## Parameters
+    /// - `inputs`: one row per observation and one column per feature.
+    /// - `outputs`: a single column with the output of each row of `inputs`.
+    /// ## Returns
+    /// - The [FittedPipeline] that applies the same learned transforms to new inputs.
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if `outputs.height()` != `inputs.height()`
+    ///   - if there is a [Step::Standardize] and fewer than 2 rows
+    ///   - if there is a [Step::PolynomialFeatures] with a `degree` of `0`
+    /// - [MatrixError::Decomposition]
+    ///   - if the [Estimator::Ridge] system is singular, only possible with a `penalty` of `0`
+    /// - Same as [NormalEquations::fit] for [Estimator::LeastSquares]
+    pub fn fit(
+        &self,
+        inputs: &Matrix<F>,
+        outputs: &Matrix<F>,
+    ) -> Result<FittedPipeline<F>, MatrixError> {
+        if outputs.height() != inputs.height() {
+            return Err(DimensionError::UnexpectedHeight {
+                expected: inputs.height(),
+                actual: outputs.shape(),
+            }
+            .into());
+        }
+
+        let mut transformed = inputs.clone();
+        let mut steps = Vec::with_capacity(self.steps.len());
+        for step in self.steps.iter() {
+            let fitted = FittedStep::fit(step, &transformed)?;
+            transformed = fitted.apply(&transformed);
+            steps.push(fitted);
+        }
+
+        let design = with_intercept(&transformed);
+        let coefficients = match self.estimator {
+            Estimator::LeastSquares => NormalEquations::new(design, outputs.clone())?
+                .fit()?
+                .coefficients()
+                .clone(),
+            Estimator::Ridge { penalty } => {
+                let mut xtx = design.gram();
+                for index in 1..xtx.height() {
+                    xtx[index][index] = xtx[index][index] + penalty;
+                }
+                let xty = Matrix::product(&design.transposed_view(), outputs)?;
+                xtx.solve(&xty)?
+            }
+        };
+
+        return Ok(FittedPipeline {
+            steps,
+            input_width: inputs.width(),
+            coefficients: coefficients.elements().copied().collect(),
+        });
+    }
+}
+
+/// A [Step] with the parameters it learned from the training inputs
+#[derive(Debug, Clone, PartialEq)]
+pub enum FittedStep<F> {
+    Standardize { means: Vec<F>, scales: Vec<F> },
+    PolynomialFeatures { degree: usize },
+}
+impl<F: Float> FittedStep<F> {
+    fn fit(step: &Step, inputs: &Matrix<F>) -> Result<Self, MatrixError> {
+        return match *step {
+            Step::Standardize => {
+                let scales = inputs
+                    .column_variances()?
+                    .into_iter()
+                    .map(|variance| {
+                        if variance.is_zero() {
+                            F::one()
+                        } else {
+                            variance.sqrt()
+                        }
+                    })
+                    .collect();
+                Ok(FittedStep::Standardize {
+                    means: inputs.column_means(),
+                    scales,
+                })
+            }
+            Step::PolynomialFeatures { degree: 0 } => Err(DimensionError::Zero.into()),
+            Step::PolynomialFeatures { degree } => Ok(FittedStep::PolynomialFeatures { degree }),
+        };
+    }
+
+    /// Transform `inputs` which have the width this step was fitted on
+    fn apply(&self, inputs: &Matrix<F>) -> Matrix<F> {
+        return match self {
+            FittedStep::Standardize { means, scales } => {
+                let mut standardized = inputs.clone();
+                for row in standardized.rows_mut() {
+                    for ((element, &mean), &scale) in row.iter_mut().zip(means).zip(scales) {
+                        *element = (*element - mean) / scale;
+                    }
+                }
+                standardized
+            }
+            FittedStep::PolynomialFeatures { degree } => {
+                let width = inputs
+                    .width_nonzero()
+                    .saturating_mul(NonZeroUsize::new(*degree).expect("degree is not zero"));
+                let mut expanded = Matrix::zeros(Shape {
+                    rows: inputs.height_nonzero(),
+                    cols: width,
+                });
+                for (expanded_row, input_row) in expanded.rows_mut().zip(inputs.rows()) {
+                    for (powers, &input) in expanded_row.chunks_mut(*degree).zip(input_row) {
+                        for (power, element) in powers.iter_mut().enumerate() {
+                            *element = input.powi(power as i32 + 1);
+                        }
+                    }
+                }
+                expanded
+            }
+        };
+    }
+}
+
+/// A [Pipeline] fitted by [Pipeline::fit] <br>
+/// New inputs go through exactly the transforms learned from the training inputs,
+/// so the means and scales can not drift out of sync between fitting and predicting.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FittedPipeline<F> {
+    steps: Vec<FittedStep<F>>,
+    input_width: usize,
+    coefficients: Vec<F>,
+}
+impl<F: Float> FittedPipeline<F> {
+    pub fn steps(&self) -> &[FittedStep<F>] {
+        return &self.steps;
+    }
+    /// The intercept then one coefficient per column of [FittedPipeline::transform]
+    pub fn coefficients(&self) -> &[F] {
+        return &self.coefficients;
+    }
+
+    /// Apply the fitted steps to `inputs`
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if `inputs` does not have the same number of columns as the training inputs
+    pub fn transform(&self, inputs: &Matrix<F>) -> Result<Matrix<F>, MatrixError> {
+        if inputs.width() != self.input_width {
+            return Err(DimensionError::UnexpectedWidth {
+                expected: self.input_width,
+                actual: inputs.shape(),
+            }
+            .into());
+        }
+
+        let transformed = self
+            .steps
+            .iter()
+            .fold(inputs.clone(), |transformed, step| step.apply(&transformed));
+        return Ok(transformed);
+    }
+
+    /// Predict the output of each row of `inputs`
+    /// ## Returns
+    /// - A single column with one prediction per row.
+    /// ## Errors
+    /// - Same as [FittedPipeline::transform]
+    pub fn predict(&self, inputs: &Matrix<F>) -> Result<Matrix<F>, MatrixError> {
+        let transformed = self.transform(inputs)?;
+        let mut predictions = Matrix::zeros(Shape {
+            rows: transformed.height_nonzero(),
+            cols: NonZeroUsize::MIN,
+        });
+        for (prediction, row) in predictions.rows_mut().zip(transformed.rows()) {
+            prediction[0] = row
+                .iter()
+                .zip(&self.coefficients[1..])
+                .fold(self.coefficients[0], |sum, (&input, &coefficient)| {
+                    sum + input * coefficient
+                });
+        }
+        return Ok(predictions);
+    }
+}
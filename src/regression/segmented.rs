@@ -0,0 +1,191 @@
+use std::num::NonZeroUsize;
+
+use num::Float;
+
+use crate::{
+    matrix::sort::compare_nan_last, regression::add_affine_power, DataSet, DimensionError, Matrix,
+    MatrixError, NormalEquations, RegressionFit, Shape, SortDirection,
+};
+
+/// A continuous piecewise polynomial fit, one polynomial per segment between breakpoints <br>
+/// Fitted with the basis `1, x, ..., xᵈ` plus `(x - b)₊, ..., (x - b)₊ᵈ` for each breakpoint `b`,
+/// where `(x - b)₊` is `0` left of `b`.
+/// Every added term is `0` at its breakpoint so neighbouring segments meet there,
+/// but the slope and curvature are free to change.
+#[derive(Debug, Clone)]
+pub struct SegmentedFit<F> {
+    breakpoints: Vec<F>,
+    degree: usize,
+    fit: RegressionFit<F>,
+}
+impl<F: Float> SegmentedFit<F> {
+    /// The breakpoints in ascending order
+    pub fn breakpoints(&self) -> &[F] {
+        return &self.breakpoints;
+    }
+    pub fn degree(&self) -> usize {
+        return self.degree;
+    }
+    /// The underlying least squares fit for residuals and diagnostics
+    pub fn fit(&self) -> &RegressionFit<F> {
+        return &self.fit;
+    }
+    /// The number of segments, one more than the number of breakpoints
+    pub fn segments(&self) -> usize {
+        return self.breakpoints.len() + 1;
+    }
+
+    /// The coefficients of the polynomial that applies in `segment`, in ascending powers of `x` <br>
+    /// Segment `0` is left of the first breakpoint and segment `k` starts at breakpoint `k - 1`.
+    /// ## Panics
+    /// - if `segment` is not less than [SegmentedFit::segments]
+    pub fn segment_polynomial(&self, segment: usize) -> Vec<F> {
+        assert!(segment < self.segments(), "segment out of bounds");
+
+        let coefficients = self.fit.coefficients();
+        let mut polynomial = (0..=self.degree)
+            .map(|power| coefficients[power][0])
+            .collect::<Vec<_>>();
+
+        // expand c * (x - b)ʲ
+        for (index, &breakpoint) in self.breakpoints[..segment].iter().enumerate() {
+            for power in 1..=self.degree {
+                let coefficient =
+                    coefficients[(self.degree + 1) + index * self.degree + power - 1][0];
+                add_affine_power(&mut polynomial, coefficient, F::one(), -breakpoint, power);
+            }
+        }
+
+        return polynomial;
+    }
+
+    /// Evaluate the fitted curve at `input`
+    pub fn predict(&self, input: F) -> F {
+        let coefficients = self.fit.coefficients();
+        return segmented_terms(input, &self.breakpoints, self.degree)
+            .zip(coefficients.column(0))
+            .fold(F::zero(), |sum, (term, &coefficient)| {
+                sum + term * coefficient
+            });
+    }
+}
+
+/// `1, x, ..., xᵈ` then `(x - b)₊, ..., (x - b)₊ᵈ` for each breakpoint `b`
+pub(crate) fn segmented_terms<F: Float>(
+    input: F,
+    breakpoints: &[F],
+    degree: usize,
+) -> impl Iterator<Item = F> + '_ {
+    let powers = (0..=degree).map(move |power| input.powi(power as i32));
+    let hinges = breakpoints.iter().flat_map(move |&breakpoint| {
+        let hinge = (input - breakpoint).max(F::zero());
+        (1..=degree).map(move |power| hinge.powi(power as i32))
+    });
+    return powers.chain(hinges);
+}
+
+impl<F: Float> DataSet<F> {
+    /// Fit a continuous piecewise polynomial of `degree` that may bend at each breakpoint
+    /// ## Parameters
+    /// - `breakpoints`: where one segment ends and the next begins, in any order.
+    /// - `degree`: the degree of the polynomial in each segment.
+    /// ## Returns
+    /// - The [SegmentedFit].
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if `degree` is `0`, since a continuous piecewise constant is just a constant
+    /// - [MatrixError::NotFinite]
+    ///   - if a breakpoint or an input is `NaN` or infinite
+    /// - Same as [NormalEquations::fit]
+    pub fn segmented_regression(
+        &self,
+        breakpoints: &[F],
+        degree: usize,
+    ) -> Result<SegmentedFit<F>, MatrixError> {
+        if degree == 0 {
+            Err(DimensionError::Zero)?
+        }
+
+        if breakpoints.iter().any(|breakpoint| !breakpoint.is_finite()) {
+            Err(MatrixError::NotFinite("a breakpoint"))?
+        }
+        self.finite_inputs()?;
+
+        let mut breakpoints = breakpoints.to_vec();
+        breakpoints.sort_by(|a, b| compare_nan_last(a, b, SortDirection::Ascending));
+        breakpoints.dedup();
+
+        let width = NonZeroUsize::MIN.saturating_add(degree * (breakpoints.len() + 1));
+        let mut design = Matrix::zeros(Shape {
+            rows: self.len_nonzero(),
+            cols: width,
+        });
+        for (row, data_point) in design.rows_mut().zip(self.data()) {
+            for (element, term) in
+                row.iter_mut()
+                    .zip(segmented_terms(*data_point.input(), &breakpoints, degree))
+            {
+                *element = term;
+            }
+        }
+
+        let fit = NormalEquations::new(design, self.polynomial_output_matrix())?.fit()?;
+
+        return Ok(SegmentedFit {
+            breakpoints,
+            degree,
+            fit,
+        });
+    }
+
+    /// Find the single breakpoint with the smallest residual sum of squares <br>
+    /// The candidates are the midpoints between consecutive distinct inputs
+    /// that leave at least `degree + 1` points on each side.
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if there are too few distinct inputs for any candidate
+    /// - [MatrixError::NotFinite]
+    ///   - if an input is `NaN` or infinite
+    /// - Same as [DataSet::segmented_regression]
+    pub fn segmented_regression_search(
+        &self,
+        degree: usize,
+    ) -> Result<SegmentedFit<F>, MatrixError> {
+        let mut inputs = self.finite_inputs()?;
+        inputs.sort_by(|a, b| compare_nan_last(a, b, SortDirection::Ascending));
+
+        let two = F::one() + F::one();
+        let mut best: Option<SegmentedFit<F>> = None;
+        for split in (degree + 1)..=inputs.len().saturating_sub(degree + 1) {
+            if inputs[split - 1] == inputs[split] {
+                continue;
+            }
+            let breakpoint = (inputs[split - 1] + inputs[split]) / two;
+            let candidate = self.segmented_regression(&[breakpoint], degree)?;
+
+            let better = match &best {
+                Some(best) => {
+                    candidate.fit.residual_sum_of_squares() < best.fit.residual_sum_of_squares()
+                }
+                None => true,
+            };
+            if better {
+                best = Some(candidate);
+            }
+        }
+
+        return best.ok_or(DimensionError::TooSmall.into());
+    }
+
+    /// Every input, checking that none is `NaN` or infinite
+    fn finite_inputs(&self) -> Result<Vec<F>, MatrixError> {
+        return self
+            .data()
+            .iter()
+            .map(|data_point| match *data_point.input() {
+                input if input.is_finite() => Ok(input),
+                _ => Err(MatrixError::NotFinite("an input")),
+            })
+            .collect();
+    }
+}
@@ -0,0 +1,172 @@
+use num::Float;
+
+use crate::{DesignMatrix, DimensionError, Matrix, MatrixError, NormalEquations, RegressionFit};
+
+/// Which direction [DesignMatrix::stepwise_selection] searches in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectionDirection {
+    /// Start from the intercept alone and add the column that improves the score most
+    Forward,
+    /// Start from every column and remove the column whose removal improves the score most
+    Backward,
+}
+
+/// How [DesignMatrix::stepwise_selection] compares the fits of different column sets
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectionCriterion {
+    /// [RegressionFit::adjusted_r_squared], higher is better
+    AdjustedRSquared,
+    /// [RegressionFit::aic], lower is better
+    Aic,
+}
+impl SelectionCriterion {
+    /// The score of `fit` where higher is always better,
+    /// [None] if the criterion is not defined for it
+    fn score<F: Float>(&self, fit: &RegressionFit<F>) -> Option<F> {
+        let score = match self {
+            SelectionCriterion::AdjustedRSquared => fit.adjusted_r_squared().ok()?,
+            SelectionCriterion::Aic => -fit.aic(),
+        };
+        return (!score.is_nan()).then_some(score);
+    }
+}
+
+/// The columns kept by [DesignMatrix::stepwise_selection] and the model fitted on them
+#[derive(Debug, Clone)]
+pub struct StepwiseSelection<F> {
+    columns: Vec<usize>,
+    names: Vec<String>,
+    score: F,
+    fit: RegressionFit<F>,
+}
+impl<F: Float> StepwiseSelection<F> {
+    /// The indices of the selected columns of the [DesignMatrix] in ascending order
+    pub fn columns(&self) -> &[usize] {
+        return &self.columns;
+    }
+    /// The names of the selected columns, in the same order as [StepwiseSelection::columns]
+    pub fn names(&self) -> &[String] {
+        return &self.names;
+    }
+    /// The adjusted `R²` or AIC of [StepwiseSelection::fit]
+    pub fn score(&self) -> F {
+        return self.score;
+    }
+    /// The fit on the selected columns, one coefficient per column
+    pub fn fit(&self) -> &RegressionFit<F> {
+        return &self.fit;
+    }
+}
+
+impl<F: Float> DesignMatrix<F> {
+    /// Greedily choose the columns that give the best score, one column at a time <br>
+    /// Each step tries every single column change and takes the best one,
+    /// stopping once no change improves the score.
+    /// The intercept column `1` is always kept.
+    /// ## Parameters
+    /// - `outputs`: a single column with the output of each row.
+    /// - `direction`: add columns to the intercept or remove them from the full design.
+    /// - `criterion`: the score to improve.
+    /// ## Returns
+    /// - The selected columns and the fit on them.
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if `outputs.height()` != the height of the design
+    ///   - if the score is not defined for any column set the search starts from,
+    ///     such as adjusted `R²` with no more rows than columns
+    /// - Same as [NormalEquations::fit]
+    pub fn stepwise_selection(
+        &self,
+        outputs: &Matrix<F>,
+        direction: SelectionDirection,
+        criterion: SelectionCriterion,
+    ) -> Result<StepwiseSelection<F>, MatrixError> {
+        let intercept = self.column_index("1");
+        let width = self.matrix().width();
+
+        let mut columns = match direction {
+            SelectionDirection::Forward => intercept.into_iter().collect::<Vec<_>>(),
+            SelectionDirection::Backward => (0..width).collect(),
+        };
+        let mut best = if columns.is_empty() {
+            None
+        } else {
+            let fit = self.fit_columns(&columns, outputs)?;
+            criterion.score(&fit).map(|score| (score, fit))
+        };
+
+        loop {
+            let candidates = match direction {
+                SelectionDirection::Forward => (0..width)
+                    .filter(|column| !columns.contains(column))
+                    .map(|added| {
+                        let mut candidate = columns.clone();
+                        candidate.push(added);
+                        candidate.sort_unstable();
+                        candidate
+                    })
+                    .collect::<Vec<_>>(),
+                SelectionDirection::Backward => columns
+                    .iter()
+                    .filter(|&&column| Some(column) != intercept && columns.len() > 1)
+                    .map(|&removed| {
+                        columns
+                            .iter()
+                            .copied()
+                            .filter(|&column| column != removed)
+                            .collect::<Vec<_>>()
+                    })
+                    .collect(),
+            };
+
+            let mut improvement: Option<(Vec<usize>, F, RegressionFit<F>)> = None;
+            for candidate in candidates {
+                let fit = self.fit_columns(&candidate, outputs)?;
+                let Some(score) = criterion.score(&fit) else {
+                    continue;
+                };
+                let beats_current = best.as_ref().is_none_or(|(best, _)| score > *best);
+                let beats_others = improvement
+                    .as_ref()
+                    .is_none_or(|(_, other, _)| score > *other);
+                if beats_current && beats_others {
+                    improvement = Some((candidate, score, fit));
+                }
+            }
+
+            match improvement {
+                Some((candidate, score, fit)) => {
+                    columns = candidate;
+                    best = Some((score, fit));
+                }
+                None => break,
+            }
+        }
+
+        let (score, fit) = best.ok_or(DimensionError::TooSmall)?;
+        let score = match criterion {
+            SelectionCriterion::AdjustedRSquared => score,
+            SelectionCriterion::Aic => -score,
+        };
+        let names = columns
+            .iter()
+            .map(|&column| self.names()[column].clone())
+            .collect();
+        return Ok(StepwiseSelection {
+            columns,
+            names,
+            score,
+            fit,
+        });
+    }
+
+    /// Least squares on only `columns` of the design
+    fn fit_columns(
+        &self,
+        columns: &[usize],
+        outputs: &Matrix<F>,
+    ) -> Result<RegressionFit<F>, MatrixError> {
+        let design = self.matrix().select_columns(columns.iter().copied())?;
+        return NormalEquations::new(design, outputs.clone())?.fit();
+    }
+}
@@ -0,0 +1,207 @@
+use std::{
+    collections::HashMap,
+    fs,
+    io::{self, BufRead, Write},
+};
+
+use matrix::{
+    parse_matrix_expression, DataSet, ExpressionBindings, ExpressionValue, Matrix, Workspace,
+};
+
+const HELP: &str = "\
+  load <name> <path.csv>   read a matrix into a variable
+  save <name> <path.csv>   write a variable as CSV
+  load-data <name> <path>  read `input, output` rows into a data set
+  save-workspace <dir>     write every matrix and data set under <dir>
+  load-workspace <dir>     replace the matrices and data sets with the ones under <dir>
+  <name> = <expression>    evaluate and store the result
+  <expression>             evaluate and print the result
+  vars                     list the variables and data sets
+  help                     show this message
+  quit                     leave
+
+expressions use numbers, variables, + - *, parentheses, a postfix ' to transpose,
+the identity I, and the functions inv(m), det(m), t(m)";
+
+fn format_value(value: &ExpressionValue<f64>) -> String {
+    return match value {
+        ExpressionValue::Scalar(scalar) => format!("{scalar}\n"),
+        ExpressionValue::Matrix(matrix) => matrix.to_csv_string(),
+    };
+}
+
+/// Matrices live in a [Workspace] so they can be saved together, scalars are kept beside it
+#[derive(Default)]
+struct Variables {
+    workspace: Workspace<f64>,
+    scalars: HashMap<String, f64>,
+}
+impl Variables {
+    fn get(&self, name: &str) -> Option<ExpressionValue<f64>> {
+        return match self.scalars.get(name) {
+            Some(scalar) => Some(ExpressionValue::Scalar(*scalar)),
+            None => self.workspace.binding(name),
+        };
+    }
+    fn insert(&mut self, name: &str, value: ExpressionValue<f64>) -> Result<(), String> {
+        match value {
+            ExpressionValue::Scalar(scalar) => {
+                self.workspace.remove_matrix(name);
+                self.scalars.insert(name.to_string(), scalar);
+            }
+            ExpressionValue::Matrix(matrix) => {
+                self.workspace
+                    .insert_matrix(name, matrix)
+                    .map_err(|error| error.to_string())?;
+                self.scalars.remove(name);
+            }
+        }
+        return Ok(());
+    }
+}
+impl ExpressionBindings<f64> for Variables {
+    fn binding(&self, name: &str) -> Option<ExpressionValue<f64>> {
+        return self.get(name);
+    }
+}
+
+fn evaluate(expression: &str, variables: &Variables) -> Result<ExpressionValue<f64>, String> {
+    return parse_matrix_expression(expression, variables).map_err(|error| error.to_string());
+}
+
+/// What one line of input asks for
+enum Line<'a> {
+    Empty,
+    Quit,
+    Help,
+    Variables,
+    Load { name: &'a str, path: &'a str },
+    Save { name: &'a str, path: &'a str },
+    LoadData { name: &'a str, path: &'a str },
+    SaveWorkspace { directory: &'a str },
+    LoadWorkspace { directory: &'a str },
+    Assign { name: &'a str, expression: &'a str },
+    Print { expression: &'a str },
+}
+
+fn parse_line(line: &str) -> Result<Line<'_>, String> {
+    let line = line.trim();
+    let words = line.split_whitespace().collect::<Vec<_>>();
+    return match words.as_slice() {
+        [] => Ok(Line::Empty),
+        ["quit" | "exit"] => Ok(Line::Quit),
+        ["help"] => Ok(Line::Help),
+        ["vars"] => Ok(Line::Variables),
+        ["load", name, path] => Ok(Line::Load { name, path }),
+        ["save", name, path] => Ok(Line::Save { name, path }),
+        ["load-data", name, path] => Ok(Line::LoadData { name, path }),
+        ["save-workspace", directory] => Ok(Line::SaveWorkspace { directory }),
+        ["load-workspace", directory] => Ok(Line::LoadWorkspace { directory }),
+        ["load" | "save" | "load-data", ..] => {
+            Err(format!("usage: {} <name> <path.csv>", words[0]))
+        }
+        ["save-workspace" | "load-workspace", ..] => {
+            Err(format!("usage: {} <directory>", words[0]))
+        }
+        _ => match line.split_once('=') {
+            Some((name, expression)) => {
+                let name = name.trim();
+                if name.is_empty() || !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+                    return Err(format!("{name:?} is not a valid variable name"));
+                }
+                Ok(Line::Assign { name, expression })
+            }
+            None => Ok(Line::Print { expression: line }),
+        },
+    };
+}
+
+/// Carry out one line other than [Line::Quit]
+/// ## Returns
+/// - The text to print, if any
+fn execute(line: Line, variables: &mut Variables) -> Result<Option<String>, String> {
+    match line {
+        Line::Empty | Line::Quit => {}
+        Line::Help => return Ok(Some(format!("{HELP}\n"))),
+        Line::Variables => {
+            let mut names = variables
+                .scalars
+                .keys()
+                .map(String::as_str)
+                .chain(variables.workspace.matrices().map(|(name, _)| name))
+                .collect::<Vec<_>>();
+            names.sort();
+            let data_sets = variables
+                .workspace
+                .data_sets()
+                .map(|(name, _)| name)
+                .collect::<Vec<_>>();
+            let mut text = format!("{}\n", names.join(" "));
+            if !data_sets.is_empty() {
+                text.push_str(&format!("data sets: {}\n", data_sets.join(" ")));
+            }
+            return Ok(Some(text));
+        }
+        Line::Load { name, path } => {
+            let csv = fs::read_to_string(path).map_err(|error| format!("{path}: {error}"))?;
+            let matrix = Matrix::from_csv_str(&csv).map_err(|error| error.to_string())?;
+            variables.insert(name, ExpressionValue::Matrix(matrix))?;
+        }
+        Line::Save { name, path } => {
+            let value = variables
+                .get(name)
+                .ok_or(format!("{name} is not defined"))?;
+            fs::write(path, format_value(&value)).map_err(|error| format!("{path}: {error}"))?;
+        }
+        Line::LoadData { name, path } => {
+            let data_set = DataSet::from_csv(path).map_err(|error| error.to_string())?;
+            variables
+                .workspace
+                .insert_data_set(name, data_set)
+                .map_err(|error| error.to_string())?;
+        }
+        Line::SaveWorkspace { directory } => {
+            variables
+                .workspace
+                .save(directory)
+                .map_err(|error| error.to_string())?;
+        }
+        Line::LoadWorkspace { directory } => {
+            variables.workspace = Workspace::load(directory).map_err(|error| error.to_string())?;
+        }
+        Line::Assign { name, expression } => {
+            let value = evaluate(expression, variables)?;
+            variables.insert(name, value)?;
+        }
+        Line::Print { expression } => {
+            return Ok(Some(format_value(&evaluate(expression, variables)?)));
+        }
+    }
+    return Ok(None);
+}
+
+/// Read lines from `input` until it ends or `quit`, writing results and errors to `output`
+pub fn run_repl(input: impl BufRead, mut output: impl Write) -> io::Result<()> {
+    let mut variables = Variables::default();
+
+    write!(output, "> ")?;
+    output.flush()?;
+    for line in input.lines() {
+        let line = line?;
+        let result = match parse_line(&line) {
+            Ok(Line::Quit) => return Ok(()),
+            Ok(line) => execute(line, &mut variables),
+            Err(message) => Err(message),
+        };
+
+        match result {
+            Ok(Some(text)) => write!(output, "{text}")?,
+            Ok(None) => {}
+            Err(message) => writeln!(output, "error: {message}")?,
+        }
+        write!(output, "> ")?;
+        output.flush()?;
+    }
+
+    return Ok(());
+}
@@ -0,0 +1,68 @@
+use std::num::NonZeroUsize;
+
+use num::Float;
+use rand::Rng;
+
+use crate::{DimensionError, Matrix, MatrixError, Shape};
+
+/// Draw one sample from the standard normal distribution using the Box-Muller transform
+pub fn sample_standard_normal<F: Float, R: Rng + ?Sized>(rng: &mut R) -> F {
+    // 1 - [0, 1) is in (0, 1] so the logarithm is finite
+    let uniform_1 = 1.0 - rng.gen::<f64>();
+    let uniform_2 = rng.gen::<f64>();
+
+    let sample = (-2.0 * uniform_1.ln()).sqrt() * (std::f64::consts::TAU * uniform_2).cos();
+
+    return F::from(sample).expect("f64 converts to a float");
+}
+
+/// Draw `count` samples from the multivariate normal distribution `N(mean, covariance)` <br>
+/// Each sample is `mean + L * z` where `covariance` == `L * Lᵀ` and `z` is standard normal.
+/// ## Parameters
+/// - `mean`: the mean of each of the `d` variables.
+/// - `covariance`: the `d x d` symmetric positive definite covariance matrix.
+/// - `count`: the number of samples.
+/// - `rng`: source of randomness.
+/// ## Returns
+/// - A `count x d` [Matrix] where each row is a sample.
+/// ## Errors
+/// - [MatrixError::DimensionError]
+///   - if `covariance` is not `mean.len() x mean.len()`
+/// - [MatrixError::Decomposition]
+///   - if `covariance` is not symmetric positive definite
+pub fn sample_multivariate_normal<F: Float, R: Rng + ?Sized>(
+    mean: &[F],
+    covariance: &Matrix<F>,
+    count: NonZeroUsize,
+    rng: &mut R,
+) -> Result<Matrix<F>, MatrixError> {
+    let expected = Shape::try_new(mean.len(), mean.len())?;
+    if covariance.shape() != expected {
+        return Err(DimensionError::DifferentDimensions {
+            lhs: expected,
+            rhs: covariance.shape(),
+        }
+        .into());
+    }
+
+    let lower = covariance.cholesky()?;
+    let dimension = mean.len();
+
+    let mut samples = Matrix::zeros(Shape {
+        rows: count,
+        cols: covariance.width_nonzero(),
+    });
+    let mut standard = vec![F::zero(); dimension];
+    for sample in samples.rows_mut() {
+        for z in standard.iter_mut() {
+            *z = sample_standard_normal(rng);
+        }
+
+        for (row, element) in sample.iter_mut().enumerate() {
+            // lower is lower triangular so only the first row + 1 terms contribute
+            *element = (0..=row).fold(mean[row], |sum, k| sum + lower[row][k] * standard[k]);
+        }
+    }
+
+    return Ok(samples);
+}
@@ -1,3 +1,6 @@
+// index-like literals such as `01` are used to label elements
+#![allow(clippy::zero_prefixed_literal)]
+
 use super::*;
 
 #[test]
@@ -206,7 +209,7 @@ fn inverse3x3_1() {
     .unwrap();
 
     assert_eq!(inverse, expected_inverse);
-    
+
     let identity = matrix.matrix_multiply(&inverse).unwrap();
     let expected_identity = Matrix::<f64>::identity(matrix.width_nonzero());
     assert_eq!(identity, expected_identity);
@@ -221,26 +224,19 @@ fn inverse3x3_2() {
     ])
     .unwrap();
 
-    let mut inverse = matrix.inverse().unwrap();
-    // round each element
-    for element in inverse.elements_mut() {
-        *element = format!("{:.4}", element).parse().unwrap();
-    }
+    let inverse = matrix.inverse().unwrap();
 
-    let mut expected_inverse = Matrix::try_from([
+    let expected_inverse = Matrix::try_from([
         [11.0 / 27.0, -2.0 / 27.0, 19.0 / 27.0], //
         [-23.0 / 54.0, 7.0 / 27.0, -26.0 / 27.0],
         [-17.0 / 54.0, 4.0 / 27.0, -11.0 / 27.0],
     ])
-    .unwrap();
-    // round each element
-    for element in expected_inverse.elements_mut() {
-        *element = format!("{:.4}", element).parse().unwrap();
-    }
+    .unwrap()
+    .round_to(4);
 
-    assert_eq!(inverse, expected_inverse);
+    assert_eq!(inverse.round_to(4), expected_inverse);
 
-    let identity = matrix.matrix_multiply(&inverse).unwrap();
+    let identity = matrix.matrix_multiply(&inverse).unwrap().round_to(4);
     let expected_identity = Matrix::<f64>::identity(matrix.width_nonzero());
     assert_eq!(identity, expected_identity);
 }
@@ -253,25 +249,18 @@ fn inverse2x2() {
     ])
     .unwrap();
 
-    let mut inverse = matrix.inverse().unwrap();
-    // round each element
-    for element in inverse.elements_mut() {
-        *element = format!("{:.4}", element).parse().unwrap();
-    }
+    let inverse = matrix.inverse().unwrap();
 
-    let mut expected_inverse = Matrix::try_from([
+    let expected_inverse = Matrix::try_from([
         [4.4643, -0.78571], //
         [-0.78571, 0.14286],
     ])
-    .unwrap();
-    // round each element
-    for element in expected_inverse.elements_mut() {
-        *element = format!("{:.4}", element).parse().unwrap();
-    }
+    .unwrap()
+    .round_to(4);
 
-    assert_eq!(inverse, expected_inverse);
+    assert_eq!(inverse.round_to(4), expected_inverse);
 
-    let identity = matrix.matrix_multiply(&inverse).unwrap();
+    let identity = matrix.matrix_multiply(&inverse).unwrap().round_to(4);
     let expected_identity = Matrix::<f64>::identity(matrix.width_nonzero());
     assert_eq!(identity, expected_identity);
 }
@@ -311,11 +300,7 @@ fn read_data_set() {
 #[test]
 fn linear_regression() {
     let data = DataSet::<f64>::from_csv("./tests/dataset.csv").unwrap();
-    let mut coefficient_matrix = data.polynomial_regression(1).unwrap();
-    // round each element
-    for element in coefficient_matrix.elements_mut() {
-        *element = format!("{:.4}", element).parse().unwrap();
-    }
+    let coefficient_matrix = data.polynomial_regression(1).unwrap().round_to(4);
 
     let expected_coefficient_matrix = Matrix::try_from([
         [-2.6786], // x^0 coefficient
@@ -329,11 +314,7 @@ fn linear_regression() {
 #[test]
 fn quadratic_regression() {
     let data = DataSet::<f64>::from_csv("./tests/dataset.csv").unwrap();
-    let mut coefficient_matrix = data.polynomial_regression(2).unwrap();
-    // round each element
-    for element in coefficient_matrix.elements_mut() {
-        *element = format!("{:.4}", element).parse().unwrap();
-    }
+    let coefficient_matrix = data.polynomial_regression(2).unwrap().round_to(4);
 
     let expected_coefficient_matrix = Matrix::try_from([
         [-34.7143], // x^0 coefficient
@@ -344,3 +325,4329 @@ fn quadratic_regression() {
 
     assert_eq!(coefficient_matrix, expected_coefficient_matrix);
 }
+
+#[test]
+fn symmetric_eigen() {
+    let matrix = Matrix::<f64>::try_from([
+        [2.0, 1.0], //
+        [1.0, 2.0],
+    ])
+    .unwrap();
+
+    let eigen = matrix.symmetric_eigen().unwrap();
+
+    assert!((eigen.eigenvalues()[0] - 1.0).abs() < 1e-12);
+    assert!((eigen.eigenvalues()[1] - 3.0).abs() < 1e-12);
+
+    let vectors = eigen.eigenvectors();
+    let reconstructed = vectors
+        .matrix_multiply(&Matrix::try_from([[1.0, 0.0], [0.0, 3.0]]).unwrap())
+        .unwrap()
+        .matrix_multiply(&vectors.transpose())
+        .unwrap();
+    for (index, &element) in reconstructed.elements_enumerated() {
+        assert!((element - matrix[index]).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn fit_line_and_plane() {
+    let points = Matrix::<f64>::try_from([
+        [0.0, 1.0], //
+        [1.0, 3.0],
+        [2.0, 5.0],
+        [3.0, 7.0],
+    ])
+    .unwrap();
+    let line = fit_line_2d(&points).unwrap();
+    let direction = line.direction();
+    assert!((direction[1] / direction[0] - 2.0).abs() < 1e-12);
+    assert!(line.distance([4.0, 9.0]) < 1e-12);
+
+    let points = Matrix::<f64>::try_from([
+        [0.0, 0.0, 1.0], //
+        [1.0, 0.0, 1.0],
+        [0.0, 1.0, 1.0],
+        [1.0, 1.0, 1.0],
+    ])
+    .unwrap();
+    let plane = fit_plane_3d(&points).unwrap();
+    assert!((plane.normal()[2].abs() - 1.0).abs() < 1e-12);
+    assert!((plane.distance([5.0, 5.0, 3.0]) - 2.0).abs() < 1e-12);
+
+    assert!(fit_plane_3d(&Matrix::try_from([[1.0, 2.0]]).unwrap()).is_err());
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn kmeans_two_clusters() {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    let data = Matrix::<f64>::try_from([
+        [0.0, 0.1], //
+        [0.1, 0.0],
+        [0.0, 0.0],
+        [10.0, 10.1],
+        [10.1, 10.0],
+        [10.0, 10.0],
+    ])
+    .unwrap();
+
+    let mut rng = StdRng::seed_from_u64(7);
+    let k = std::num::NonZeroUsize::new(2).unwrap();
+    let result = kmeans(&data, k, 100, &mut rng).unwrap();
+
+    let assignments = result.assignments();
+    assert_eq!(assignments[0], assignments[1]);
+    assert_eq!(assignments[0], assignments[2]);
+    assert_eq!(assignments[3], assignments[4]);
+    assert_eq!(assignments[3], assignments[5]);
+    assert_ne!(assignments[0], assignments[3]);
+
+    let centroid = &result.centroids()[assignments[3]];
+    assert!((centroid[0] - 10.0333).abs() < 1e-3);
+
+    // without any rounds the points are assigned to the seeds, which are rows of the data
+    let mut rng = StdRng::seed_from_u64(7);
+    let seeded = kmeans(&data, k, 0, &mut rng).unwrap();
+    assert_eq!(seeded.iterations(), 0);
+    assert!(seeded
+        .assignments()
+        .iter()
+        .all(|&cluster| cluster < k.get()));
+    for (row, &cluster) in data.rows().zip(seeded.assignments()) {
+        let distance = |centroid: &[f64]| {
+            centroid
+                .iter()
+                .zip(row)
+                .map(|(c, p)| (c - p) * (c - p))
+                .sum::<f64>()
+        };
+        assert!(seeded
+            .centroids()
+            .rows()
+            .all(|centroid| distance(&seeded.centroids()[cluster]) <= distance(centroid)));
+    }
+}
+
+#[test]
+fn center_and_scale_columns() {
+    let matrix = Matrix::<f64>::try_from([
+        [1.0, 10.0], //
+        [2.0, 20.0],
+        [3.0, 30.0],
+    ])
+    .unwrap();
+
+    let expected_centered = Matrix::try_from([
+        [-1.0, -10.0], //
+        [0.0, 0.0],
+        [1.0, 10.0],
+    ])
+    .unwrap();
+    assert_eq!(matrix.center_columns(), expected_centered);
+
+    let expected_scaled = Matrix::try_from([
+        [-1.0, -1.0], //
+        [0.0, 0.0],
+        [1.0, 1.0],
+    ])
+    .unwrap();
+    assert_eq!(
+        matrix.scale_columns_to_unit_variance().unwrap(),
+        expected_scaled
+    );
+}
+
+#[test]
+fn whitening_has_identity_covariance() {
+    let matrix = Matrix::<f64>::try_from([
+        [2.0, 1.0], //
+        [4.0, 3.5],
+        [6.0, 4.0],
+        [8.0, 7.5],
+        [1.0, 2.0],
+    ])
+    .unwrap();
+
+    for kind in [WhiteningKind::Pca, WhiteningKind::Zca] {
+        let (whitened, whitening) = matrix.whiten(kind, 0.0).unwrap();
+        let covariance = whitened.covariance().unwrap();
+        let identity = Matrix::<f64>::identity(covariance.width_nonzero());
+        for (index, &element) in covariance.elements_enumerated() {
+            assert!((element - identity[index]).abs() < 1e-9);
+        }
+
+        let reapplied = whitening.apply(&matrix).unwrap();
+        assert_eq!(reapplied, whitened);
+    }
+}
+
+#[test]
+fn matrix_sqrt() {
+    let matrix = Matrix::<f64>::try_from([
+        [5.0, 4.0], //
+        [4.0, 5.0],
+    ])
+    .unwrap();
+
+    let root = matrix.sqrt().unwrap();
+    let expected_root = Matrix::try_from([
+        [2.0, 1.0], //
+        [1.0, 2.0],
+    ])
+    .unwrap();
+    for (index, &element) in root.elements_enumerated() {
+        assert!((element - expected_root[index]).abs() < 1e-12);
+    }
+
+    let indefinite = Matrix::<f64>::try_from([
+        [1.0, 2.0], //
+        [2.0, 1.0],
+    ])
+    .unwrap();
+    assert!(indefinite.sqrt().is_err());
+}
+
+#[test]
+fn cholesky() {
+    let matrix = Matrix::<f64>::try_from([
+        [4.0, 12.0, -16.0], //
+        [12.0, 37.0, -43.0],
+        [-16.0, -43.0, 98.0],
+    ])
+    .unwrap();
+
+    let expected_lower = Matrix::try_from([
+        [2.0, 0.0, 0.0], //
+        [6.0, 1.0, 0.0],
+        [-8.0, 5.0, 3.0],
+    ])
+    .unwrap();
+
+    assert_eq!(matrix.cholesky().unwrap(), expected_lower);
+
+    let indefinite = Matrix::<f64>::try_from([
+        [1.0, 2.0], //
+        [2.0, 1.0],
+    ])
+    .unwrap();
+    assert!(indefinite.cholesky().is_err());
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn multivariate_normal_sample_statistics() {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    let mean = [1.0, -2.0];
+    let covariance = Matrix::<f64>::try_from([
+        [2.0, 0.6], //
+        [0.6, 1.0],
+    ])
+    .unwrap();
+
+    let mut rng = StdRng::seed_from_u64(42);
+    let count = std::num::NonZeroUsize::new(20_000).unwrap();
+    let samples = sample_multivariate_normal(&mean, &covariance, count, &mut rng).unwrap();
+
+    for (sample_mean, expected_mean) in samples.column_means().into_iter().zip(mean) {
+        assert!((sample_mean - expected_mean).abs() < 0.05);
+    }
+    let sample_covariance = samples.covariance().unwrap();
+    for (index, &element) in sample_covariance.elements_enumerated() {
+        assert!((element - covariance[index]).abs() < 0.1);
+    }
+}
+
+#[test]
+fn lu_and_log_determinant() {
+    let matrix = Matrix::<f64>::try_from([
+        [1.0, 2.0, 3.0], //
+        [0.0, 1.0, 4.0],
+        [5.0, 6.0, 0.0],
+    ])
+    .unwrap();
+
+    let lu = matrix.lu().unwrap();
+    let mut permuted = matrix.clone();
+    for (row, &original_row) in lu.permutation().iter().enumerate() {
+        permuted[row].copy_from_slice(&matrix[original_row]);
+    }
+    let product = lu.lower().matrix_multiply(&lu.upper()).unwrap();
+    for (index, &element) in product.elements_enumerated() {
+        assert!((element - permuted[index]).abs() < 1e-12);
+    }
+    assert!((lu.determinant() - 1.0).abs() < 1e-12);
+
+    // the plain determinant of 1e-200 * I overflows to 0
+    let mut tiny = Matrix::<f64>::identity(std::num::NonZeroUsize::new(4).unwrap());
+    tiny[0][0] = -1e-200;
+    for index in 1..4 {
+        tiny[index][index] = 1e-200;
+    }
+    let (sign, log_absolute) = tiny.log_determinant().unwrap();
+    assert_eq!(sign, -1.0);
+    assert!((log_absolute - 4.0 * (1e-200f64).ln()).abs() < 1e-9);
+
+    let singular = Matrix::<f64>::try_from([
+        [1.0, 2.0], //
+        [2.0, 4.0],
+    ])
+    .unwrap();
+    assert_eq!(
+        singular.log_determinant().unwrap(),
+        (0.0, f64::NEG_INFINITY)
+    );
+}
+
+#[test]
+fn solve_refined_hilbert() {
+    const SIZE: usize = 8;
+    let size = std::num::NonZeroUsize::new(SIZE).unwrap();
+
+    let mut hilbert = Matrix::<f64>::zeros(Shape::square(size));
+    for (index, element) in hilbert.elements_mut_enumerated() {
+        *element = 1.0 / (index.row() + index.column() + 1) as f64;
+    }
+
+    let expected_solution = Matrix::<f64>::try_from([[1.0]; SIZE]).unwrap();
+    let b = hilbert.matrix_multiply(&expected_solution).unwrap();
+
+    let error = |solution: &Matrix<f64>| {
+        solution
+            .elements()
+            .map(|element| (element - 1.0).abs())
+            .fold(0.0, f64::max)
+    };
+
+    let unrefined = hilbert.solve_refined(&b, 0).unwrap();
+    let refined = hilbert.solve_refined(&b, 3).unwrap();
+
+    assert!(error(&refined) <= error(&unrefined));
+    assert!(error(&refined) < 1e-4);
+}
+
+#[test]
+fn nan_policy() {
+    let mut matrix = Matrix::<f64>::try_from([
+        [1.0, f64::NAN], //
+        [f64::NAN, 4.0],
+    ])
+    .unwrap();
+
+    assert!(matrix.contains_nan());
+    assert_ne!(matrix, matrix.clone());
+    assert!(matrix.eq_ignoring_nan(&matrix.clone()));
+
+    let nan_indices = matrix
+        .nan_indices()
+        .map(|index| (index.row(), index.column()))
+        .collect::<Vec<_>>();
+    assert_eq!(nan_indices, [(0, 1), (1, 0)]);
+
+    assert_eq!(matrix.replace_nan(0.0), 2);
+    assert!(!matrix.contains_nan());
+    assert_eq!(matrix, Matrix::try_from([[1.0, 0.0], [0.0, 4.0]]).unwrap());
+}
+
+#[test]
+fn cast_elements() {
+    let integers = Matrix::<i32>::try_from([
+        [1, 2], //
+        [3, 4],
+    ])
+    .unwrap();
+    let floats = integers.cast::<f64>();
+    assert_eq!(floats, Matrix::try_from([[1.0, 2.0], [3.0, 4.0]]).unwrap());
+
+    let narrowed = Matrix::<f64>::try_from([[0.1, 1e300]])
+        .unwrap()
+        .cast_lossy::<f32>();
+    assert_eq!(narrowed[0][0], 0.1f32);
+    assert_eq!(narrowed[0][1], f32::INFINITY);
+
+    let truncated = Matrix::<f64>::try_from([[1.9, -2.5]])
+        .unwrap()
+        .try_cast::<i32>()
+        .unwrap();
+    assert_eq!(truncated, Matrix::try_from([[1, -2]]).unwrap());
+
+    let unrepresentable = Matrix::<f64>::try_from([[1.0, f64::NAN]]).unwrap();
+    assert!(matches!(
+        unrepresentable.try_cast::<i32>(),
+        Err(MatrixError::Cast { row: 0, column: 1 })
+    ));
+}
+
+#[test]
+fn column_and_row_vectors() {
+    let x = ColumnVector::<f64>::try_from([1.0, 0.0, 0.0]).unwrap();
+    let y = ColumnVector::<f64>::try_from([0.0, 1.0, 0.0]).unwrap();
+
+    assert_eq!(x.dot(&y).unwrap(), 0.0);
+    assert_eq!(x.cross(&y).unwrap().as_slice(), [0.0, 0.0, 1.0]);
+    assert!(x.dot(&ColumnVector::try_from([1.0]).unwrap()).is_err());
+
+    let v = ColumnVector::<f64>::try_from([3.0, 4.0]).unwrap();
+    assert_eq!(v.norm(), 5.0);
+    assert_eq!(v.normalize().unwrap().as_slice(), [0.6, 0.8]);
+    assert!(ColumnVector::<f64>::try_from([0.0, 0.0])
+        .unwrap()
+        .normalize()
+        .is_none());
+
+    let matrix = Matrix::try_from([
+        [1.0, 2.0], //
+        [3.0, 4.0],
+    ])
+    .unwrap();
+    assert_eq!(
+        matrix.mul_column_vector(&v).unwrap().as_slice(),
+        [11.0, 25.0]
+    );
+
+    let row: RowVector<f64> = v.clone().transpose();
+    assert_eq!(row.mul_matrix(&matrix).unwrap().as_slice(), [15.0, 22.0]);
+    // the operators are infallible, see column_vector_operator_length_mismatch
+    assert_eq!(&matrix * &v, matrix.mul_column_vector(&v).unwrap());
+    assert_eq!(&row * &matrix, row.mul_matrix(&matrix).unwrap());
+    assert_eq!(
+        v.outer(&row),
+        Matrix::try_from([[9.0, 12.0], [12.0, 16.0]]).unwrap()
+    );
+    assert_eq!(ColumnVector::try_from(&v.to_matrix()).unwrap(), v);
+}
+
+#[test]
+#[should_panic]
+fn column_vector_operator_length_mismatch() {
+    let v = ColumnVector::try_from(vec![1.0, 2.0]).unwrap();
+    let wide = Matrix::try_from([[1.0, 2.0, 3.0]]).unwrap();
+    let _ = &wide * &v;
+}
+
+#[test]
+fn vector_angle_and_projection() {
+    let a = ColumnVector::<f64>::try_from([2.0, 2.0]).unwrap();
+    let b = ColumnVector::<f64>::try_from([3.0, 0.0]).unwrap();
+
+    let angle = a.angle_between(&b).unwrap();
+    assert!((angle - std::f64::consts::FRAC_PI_4).abs() < 1e-12);
+    assert_eq!(a.angle_between(&a).unwrap(), 0.0);
+
+    assert_eq!(a.project_onto(&b).unwrap().as_slice(), [2.0, 0.0]);
+    assert_eq!(a.reject_from(&b).unwrap().as_slice(), [0.0, 2.0]);
+
+    let zero = ColumnVector::<f64>::try_from([0.0, 0.0]).unwrap();
+    assert!(matches!(
+        a.project_onto(&zero),
+        Err(MatrixError::ZeroVector)
+    ));
+    assert!(matches!(
+        zero.angle_between(&a),
+        Err(MatrixError::ZeroVector)
+    ));
+}
+
+#[test]
+fn givens_and_householder() {
+    let size = std::num::NonZeroUsize::new(3).unwrap();
+    let matrix = Matrix::<f64>::try_from([
+        [4.0, 1.0, 2.0], //
+        [3.0, 5.0, 1.0],
+        [0.0, 2.0, 6.0],
+    ])
+    .unwrap();
+
+    let givens = Givens::zeroing(0, 1, matrix[0][0], matrix[1][0]);
+    let mut rotated = matrix.clone();
+    givens.apply_left(&mut rotated).unwrap();
+    assert!((rotated[0][0] - 5.0).abs() < 1e-12);
+    assert!(rotated[1][0].abs() < 1e-12);
+    let expected = givens
+        .to_matrix(size)
+        .unwrap()
+        .matrix_multiply(&matrix)
+        .unwrap();
+    assert_eq!(rotated, expected);
+
+    let mut right = matrix.clone();
+    givens.apply_right(&mut right).unwrap();
+    let expected = matrix
+        .matrix_multiply(&givens.to_matrix(size).unwrap())
+        .unwrap();
+    assert_eq!(right, expected);
+
+    let column = matrix.column(0).copied().collect::<Vec<_>>();
+    let (householder, beta) = Householder::zeroing(&column, 0).unwrap();
+    let mut reflected = matrix.clone();
+    householder.apply_left(&mut reflected).unwrap();
+    assert!((reflected[0][0] - beta).abs() < 1e-12);
+    assert!((beta.abs() - 5.0).abs() < 1e-12);
+    assert!(reflected[1][0].abs() < 1e-12);
+    assert!(reflected[2][0].abs() < 1e-12);
+
+    let reflector = Matrix::householder(householder.vector()).unwrap();
+    let identity = reflector.matrix_multiply(&reflector).unwrap();
+    for (index, &element) in identity.elements_enumerated() {
+        let expected = if index.row() == index.column() {
+            1.0
+        } else {
+            0.0
+        };
+        assert!((element - expected).abs() < 1e-12);
+    }
+
+    let mut too_small = Matrix::<f64>::try_from([[1.0, 2.0]]).unwrap();
+    assert!(givens.apply_left(&mut too_small).is_err());
+}
+
+#[test]
+fn hessenberg_and_tridiagonal() {
+    let matrix = Matrix::<f64>::try_from([
+        [4.0, 1.0, -2.0, 2.0], //
+        [1.0, 2.0, 0.0, 1.0],
+        [-2.0, 0.0, 3.0, -2.0],
+        [2.0, 1.0, -2.0, -1.0],
+    ])
+    .unwrap();
+
+    let check = |original: &Matrix<f64>, reduction: &OrthogonalReduction<f64>, max_band: usize| {
+        let (reduced, q) = (reduction.reduced(), reduction.transform());
+        for (index, &element) in reduced.elements_enumerated() {
+            if index.row() > index.column() + 1 || index.column() > index.row() + max_band {
+                assert_eq!(element, 0.0);
+            }
+        }
+        let product = q
+            .matrix_multiply(reduced)
+            .unwrap()
+            .matrix_multiply(&q.transpose())
+            .unwrap();
+        for (index, &element) in product.elements_enumerated() {
+            assert!((element - original[index]).abs() < 1e-12);
+        }
+    };
+
+    check(&matrix, &matrix.hessenberg().unwrap(), usize::MAX / 2);
+    check(&matrix, &matrix.tridiagonal().unwrap(), 1);
+
+    let mut nonsymmetric = matrix.clone();
+    nonsymmetric[0][3] = 10.0;
+    check(
+        &nonsymmetric,
+        &nonsymmetric.hessenberg().unwrap(),
+        usize::MAX / 2,
+    );
+    assert!(nonsymmetric.tridiagonal().is_err());
+}
+
+#[test]
+fn schur_decomposition() {
+    let matrix = Matrix::<f64>::try_from([
+        [1.0, 2.0, 3.0, 4.0], //
+        [-2.0, 1.0, 0.5, 1.0],
+        [0.0, 3.0, 2.0, -1.0],
+        [1.0, 0.0, 1.0, 3.0],
+    ])
+    .unwrap();
+
+    let schur = matrix.schur().unwrap();
+    let (q, t) = (schur.q(), schur.t());
+
+    let product = q
+        .matrix_multiply(t)
+        .unwrap()
+        .matrix_multiply(&q.transpose())
+        .unwrap();
+    for (index, &element) in product.elements_enumerated() {
+        assert!((element - matrix[index]).abs() < 1e-10);
+    }
+    for (index, &element) in t.elements_enumerated() {
+        if index.row() > index.column() + 1 {
+            assert_eq!(element, 0.0);
+        }
+    }
+
+    let trace = (0..4).map(|index| matrix[index][index]).sum::<f64>();
+    let eigenvalues = schur.eigenvalues();
+    let eigenvalue_sum = eigenvalues
+        .iter()
+        .map(|eigenvalue| eigenvalue.re)
+        .sum::<f64>();
+    assert!((eigenvalue_sum - trace).abs() < 1e-10);
+
+    let rotation = Matrix::<f64>::try_from([
+        [0.0, -1.0], //
+        [1.0, 0.0],
+    ])
+    .unwrap();
+    let eigenvalues = rotation.schur().unwrap().eigenvalues();
+    assert!((eigenvalues[0].im.abs() - 1.0).abs() < 1e-12);
+
+    let triangular_eigenvalues = Matrix::<f64>::try_from([
+        [2.0, 1.0], //
+        [1.0, 2.0],
+    ])
+    .unwrap()
+    .schur()
+    .unwrap();
+    assert_eq!(triangular_eigenvalues.t()[1][0], 0.0);
+    let mut real_parts = triangular_eigenvalues
+        .eigenvalues()
+        .iter()
+        .map(|eigenvalue| eigenvalue.re)
+        .collect::<Vec<_>>();
+    real_parts.sort_by(f64::total_cmp);
+    assert!((real_parts[0] - 1.0).abs() < 1e-12);
+    assert!((real_parts[1] - 3.0).abs() < 1e-12);
+}
+
+#[test]
+fn block_diagonal_and_direct_sum() {
+    let a = Matrix::try_from([
+        [1, 2], //
+        [3, 4],
+    ])
+    .unwrap();
+    let b = Matrix::try_from([[5]]).unwrap();
+    let c = Matrix::try_from([[6, 7, 8]]).unwrap();
+
+    let expected = Matrix::try_from([
+        [1, 2, 0, 0, 0, 0], //
+        [3, 4, 0, 0, 0, 0],
+        [0, 0, 5, 0, 0, 0],
+        [0, 0, 0, 6, 7, 8],
+    ])
+    .unwrap();
+    assert_eq!(Matrix::block_diagonal(&[&a, &b, &c]).unwrap(), expected);
+
+    let expected = Matrix::try_from([
+        [1, 2, 0], //
+        [3, 4, 0],
+        [0, 0, 5],
+    ])
+    .unwrap();
+    assert_eq!(a.direct_sum(&b), expected);
+
+    assert!(Matrix::<i32>::block_diagonal(&[]).is_err());
+}
+
+#[test]
+fn iterative_solvers_over_linear_operators() {
+    let matrix = Matrix::<f64>::try_from([
+        [4.0, 1.0, 0.0], //
+        [1.0, 3.0, 1.0],
+        [0.0, 1.0, 2.0],
+    ])
+    .unwrap();
+    let b = ColumnVector::try_from([1.0, 2.0, 3.0]).unwrap();
+
+    let result = conjugate_gradient(&matrix, &b, 1e-12, 10).unwrap();
+    assert!(result.converged());
+    let residual = matrix.mul_column_vector(result.solution()).unwrap();
+    for (&actual, &expected) in residual.iter().zip(b.iter()) {
+        assert!((actual - expected).abs() < 1e-10);
+    }
+
+    // the same tridiagonal operator without storing a matrix
+    let operator = FnOperator::new(3, 3, |x: &ColumnVector<f64>| {
+        ColumnVector::try_from([
+            4.0 * x[0] + x[1],
+            x[0] + 3.0 * x[1] + x[2],
+            x[1] + 2.0 * x[2],
+        ])
+        .unwrap()
+    });
+    let matrix_free = conjugate_gradient(&operator, &b, 1e-12, 10).unwrap();
+    assert_eq!(matrix_free.solution(), result.solution());
+
+    let start = ColumnVector::try_from([1.0, 1.0, 1.0]).unwrap();
+    let dominant = power_iteration(&operator, &start, 1e-14, 1000).unwrap();
+    assert!(dominant.converged());
+    let expected_eigenvalue = 3.0 + 3.0f64.sqrt();
+    assert!((dominant.eigenvalue() - expected_eigenvalue).abs() < 1e-10);
+
+    let wrong_length = ColumnVector::try_from([1.0, 2.0]).unwrap();
+    assert!(conjugate_gradient(&operator, &wrong_length, 1e-12, 10).is_err());
+}
+
+#[test]
+fn gmres_nonsymmetric() {
+    let matrix = Matrix::<f64>::try_from([
+        [4.0, -1.0, 0.0, 2.0], //
+        [2.0, 5.0, -1.0, 0.0],
+        [0.0, 3.0, 6.0, -2.0],
+        [1.0, 0.0, -3.0, 7.0],
+    ])
+    .unwrap();
+    let b = ColumnVector::try_from([1.0, -2.0, 3.0, 4.0]).unwrap();
+
+    let check = |solution: &ColumnVector<f64>| {
+        let product = matrix.mul_column_vector(solution).unwrap();
+        for (&actual, &expected) in product.iter().zip(b.iter()) {
+            assert!((actual - expected).abs() < 1e-9);
+        }
+    };
+
+    let full = gmres(
+        &matrix,
+        &b,
+        std::num::NonZeroUsize::new(4).unwrap(),
+        1e-12,
+        100,
+    )
+    .unwrap();
+    assert!(full.converged());
+    assert!(full.iterations() <= 4);
+    check(full.solution());
+
+    let restarted = gmres(
+        &matrix,
+        &b,
+        std::num::NonZeroUsize::new(2).unwrap(),
+        1e-12,
+        200,
+    )
+    .unwrap();
+    assert!(restarted.converged());
+    check(restarted.solution());
+    let history = restarted.residual_history();
+    assert!(history.last().unwrap() < history.first().unwrap());
+}
+
+#[test]
+fn batch_multiply() {
+    let translation = Matrix::try_from([
+        [1, 0, 5], //
+        [0, 1, 7],
+        [0, 0, 1],
+    ])
+    .unwrap();
+    let scale = Matrix::try_from([
+        [2, 0, 0], //
+        [0, 3, 0],
+        [0, 0, 1],
+    ])
+    .unwrap();
+    let point = Matrix::try_from([[1], [1], [1]]).unwrap();
+
+    let lhs = [translation.clone(), scale.clone()];
+    let rhs = [scale.clone(), point.clone()];
+    let products = Matrix::batch_multiply(&lhs, &rhs).unwrap();
+    assert_eq!(products[0], translation.matrix_multiply(&scale).unwrap());
+    assert_eq!(products[1], scale.matrix_multiply(&point).unwrap());
+
+    let mut reused = products.clone();
+    reused.iter_mut().for_each(Matrix::set_zero);
+    Matrix::batch_multiply_into(&lhs, &rhs, &mut reused).unwrap();
+    assert_eq!(reused, products);
+
+    #[cfg(feature = "rayon")]
+    {
+        reused.iter_mut().for_each(Matrix::set_zero);
+        Matrix::par_batch_multiply_into(&lhs, &rhs, &mut reused).unwrap();
+        assert_eq!(reused, products);
+    }
+
+    assert!(Matrix::batch_multiply(&lhs, &rhs[..1]).is_err());
+    assert!(Matrix::batch_multiply(&rhs, &lhs).is_err());
+}
+
+#[test]
+fn matrix_pool_reuses_buffers() {
+    let mut pool = MatrixPool::new();
+    let lhs = Matrix::try_from([
+        [1, 2], //
+        [3, 4],
+    ])
+    .unwrap();
+    let rhs = Matrix::try_from([
+        [5, 6], //
+        [7, 8],
+    ])
+    .unwrap();
+
+    for _ in 0..3 {
+        let product = lhs.matrix_multiply_pooled(&rhs, &mut pool).unwrap();
+        assert_eq!(product, lhs.matrix_multiply(&rhs).unwrap());
+
+        let sum = product.add_pooled(&rhs, &mut pool).unwrap();
+        assert_eq!(sum, product.add(&rhs).unwrap());
+
+        pool.recycle(product);
+        pool.recycle(sum);
+        // only the two recycled buffers are ever in the pool
+        assert_eq!(pool.len(), 2);
+    }
+
+    let transpose = lhs.transpose_pooled(&mut pool);
+    assert_eq!(transpose, lhs.transpose());
+    assert_eq!(pool.len(), 1);
+    assert_eq!(
+        lhs.scalar_multiply_pooled(2, &mut pool),
+        lhs.scalar_multiply(2)
+    );
+    assert!(pool.is_empty());
+}
+
+#[test]
+fn matrix_pool_reuses_decomposition_buffers() {
+    let mut pool = MatrixPool::new();
+    let a = Matrix::try_from([
+        [4.0, 3.0, 2.0, 1.0, 0.5],
+        [3.0, 4.0, 3.0, 2.0, 1.0],
+        [2.0, 3.0, 4.0, 3.0, 2.0],
+        [1.0, 2.0, 3.0, 4.0, 3.0],
+        [0.5, 1.0, 2.0, 3.0, 4.0],
+    ])
+    .unwrap();
+    let b = Matrix::try_from([[1.0], [2.0], [3.0], [4.0], [5.0]]).unwrap();
+
+    for _ in 0..3 {
+        let inverse = a.inverse_pooled(&mut pool).unwrap();
+        assert_eq!(inverse.round_to(10), a.inverse().unwrap().round_to(10));
+        pool.recycle(inverse);
+
+        let solution = a.solve_pooled(&b, &mut pool).unwrap();
+        assert_eq!(solution.round_to(10), a.solve(&b).unwrap().round_to(10));
+        pool.recycle(solution);
+
+        let lu = a.lu_pooled(&mut pool).unwrap();
+        assert_eq!(lu.determinant(), a.lu().unwrap().determinant());
+        lu.recycle(&mut pool);
+
+        // the inverse and its reduced copy and the solution are all that is ever allocated
+        assert_eq!(pool.len(), 3);
+    }
+
+    let singular = Matrix::try_from([[1.0, 2.0], [2.0, 4.0]]).unwrap();
+    assert!(singular.inverse_pooled(&mut pool).is_err());
+    assert!(singular
+        .solve_pooled(&Matrix::try_from([[1.0], [2.0]]).unwrap(), &mut pool)
+        .is_err());
+    assert!(Matrix::try_from([[1.0, 2.0]])
+        .unwrap()
+        .inverse_pooled(&mut pool)
+        .is_err());
+    // failures still give their buffers back
+    assert_eq!(pool.len(), 5);
+}
+
+#[test]
+fn small_specializations() {
+    // cofactor expansion of the 4 x 4 along the first row, using the 3 x 3 fast path for each minor
+    let matrix = Matrix::try_from([
+        [3, 2, -1, 4], //
+        [2, 1, 5, 7],
+        [0, 5, 2, -6],
+        [-1, 2, 1, 0],
+    ])
+    .unwrap();
+    let expansion = (0..4).fold(0, |sum, column| {
+        sum + matrix[0][column] * matrix.cofactor((0, column)).unwrap()
+    });
+    assert_eq!(matrix.determinant().unwrap(), expansion);
+    assert_eq!(matrix.determinant().unwrap(), -418);
+
+    // the 4 x 4 product matches the generic batch kernel
+    let square = matrix.matrix_multiply(&matrix).unwrap();
+    let batch =
+        Matrix::batch_multiply(std::slice::from_ref(&matrix), std::slice::from_ref(&matrix))
+            .unwrap();
+    assert_eq!(square, batch[0]);
+
+    let matrix = Matrix::<f64>::try_from([
+        [4.0, 7.0, 2.0, 3.0], //
+        [0.0, 5.0, 0.0, 1.0],
+        [1.0, 0.0, 3.0, 2.0],
+        [2.0, 1.0, 0.0, 6.0],
+    ])
+    .unwrap();
+    for size in 2..=4 {
+        let size = std::num::NonZeroUsize::new(size).unwrap();
+        let mut block = Matrix::zeros(Shape::square(size));
+        for (index, element) in block.elements_mut_enumerated() {
+            *element = matrix[index];
+        }
+
+        let identity = block.matrix_multiply(&block.inverse().unwrap()).unwrap();
+        for (index, &element) in identity.elements_enumerated() {
+            let expected = if index.row() == index.column() {
+                1.0
+            } else {
+                0.0
+            };
+            assert!((element - expected).abs() < 1e-12);
+        }
+    }
+}
+
+#[test]
+fn determinant_by_elimination() {
+    let matrix = Matrix::try_from([
+        [2, -1, 0, 3, 1, 4], //
+        [1, 3, -2, 0, 5, 1],
+        [0, 2, 4, -1, 2, 0],
+        [3, 0, 1, 2, -3, 2],
+        [1, 1, 0, 4, 2, -1],
+        [-2, 3, 1, 0, 1, 3],
+    ])
+    .unwrap();
+    assert_eq!(
+        matrix.determinant().unwrap(),
+        matrix.determinant_by_cofactor().unwrap()
+    );
+
+    // a zero leading pivot forces a row swap
+    let mut swapped = matrix.clone();
+    swapped[0][0] = 0;
+    assert_eq!(
+        swapped.determinant().unwrap(),
+        swapped.determinant_by_cofactor().unwrap()
+    );
+
+    // a 15 x 15 would never finish by cofactor expansion
+    let size = std::num::NonZeroUsize::new(15).unwrap();
+    let mut large = Matrix::<i64>::identity(size).scalar_multiply(2);
+    large[0][14] = 7;
+    large[14][0] = 1;
+    // expanding along the corners: 2^13 * (2 * 2 - 7 * 1)
+    assert_eq!(large.determinant().unwrap(), 8192 * -3);
+
+    let singular = Matrix::<f64>::zeros(Shape::square(size));
+    assert_eq!(singular.determinant().unwrap(), 0.0);
+}
+
+#[test]
+fn concurrent_row_shards() {
+    let size = std::num::NonZeroUsize::new(10).unwrap();
+    let mut matrix = Matrix::<i64>::zeros(Shape::square(size));
+    for (index, element) in matrix.elements_mut_enumerated() {
+        *element = (index.row() * 10 + index.column()) as i64;
+    }
+    let matrix = std::sync::Arc::new(matrix);
+
+    let shards = matrix.row_shards(std::num::NonZeroUsize::new(3).unwrap());
+    assert_eq!(shards, vec![0..4, 4..7, 7..10]);
+
+    let sums = std::thread::scope(|scope| {
+        let handles = shards
+            .into_iter()
+            .map(|rows| {
+                let matrix = std::sync::Arc::clone(&matrix);
+                scope.spawn(move || {
+                    let shard = matrix.row_range(rows).unwrap();
+                    // read only operations are safe on the shared matrix at the same time
+                    let determinant = matrix.determinant().unwrap();
+                    shard.flatten().sum::<i64>() + determinant
+                })
+            })
+            .collect::<Vec<_>>();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect::<Vec<_>>()
+    });
+    assert_eq!(sums.iter().sum::<i64>(), matrix.elements().sum::<i64>());
+
+    assert_eq!(matrix.row_range(0..10).unwrap().count(), 10);
+    assert!(matrix.row_range(5..11).is_err());
+    // more shards than rows
+    assert_eq!(
+        matrix
+            .row_shards(std::num::NonZeroUsize::new(20).unwrap())
+            .len(),
+        10
+    );
+}
+
+#[test]
+fn deterministic_reductions() {
+    let height = std::num::NonZeroUsize::new(300).unwrap();
+    let width = std::num::NonZeroUsize::new(7).unwrap();
+    let mut matrix = Matrix::<f64>::zeros(Shape {
+        rows: height,
+        cols: width,
+    });
+    for (index, element) in matrix.elements_mut_enumerated() {
+        *element = ((index.row() * 7 + index.column()) as f64 * 0.37).sin() / 3.0;
+    }
+
+    let mut integers = Matrix::<i64>::zeros(Shape {
+        rows: height,
+        cols: width,
+    });
+    for (index, element) in integers.elements_mut_enumerated() {
+        *element = (index.row() * 7 + index.column()) as i64;
+    }
+    assert_eq!(integers.sum(), (0..2100).sum::<i64>());
+
+    let squares = matrix
+        .elements()
+        .map(|element| element * element)
+        .sum::<f64>();
+    assert!((matrix.frobenius_norm() - squares.sqrt()).abs() < 1e-12);
+
+    #[cfg(feature = "rayon")]
+    {
+        let run = |threads: usize| {
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .unwrap();
+            return pool.install(|| {
+                let column =
+                    ColumnVector::try_from(matrix.elements().copied().collect::<Vec<_>>()).unwrap();
+                (
+                    matrix.par_sum(),
+                    matrix.par_frobenius_norm(),
+                    column.par_dot(&column).unwrap(),
+                )
+            });
+        };
+
+        let (sum, norm, dot) = run(1);
+        for threads in [2, 3, 8] {
+            assert_eq!(run(threads), (sum, norm, dot));
+        }
+        assert_eq!(sum, matrix.sum());
+        assert_eq!(norm, matrix.frobenius_norm());
+    }
+}
+
+#[test]
+fn compensated_summation() {
+    let values = [1.0, 1e100, 1.0, -1e100];
+    assert_eq!(Summation::Naive.sum(values), 0.0);
+    assert_eq!(Summation::Kahan.sum(values), 2.0);
+
+    // 0.1 is not representable so naive accumulation drifts
+    let count = 100_000;
+    let tenths = ColumnVector::try_from(vec![0.1_f64; count]).unwrap();
+    let ones = ColumnVector::try_from(vec![1.0_f64; count]).unwrap();
+    let exact = 10_000.0;
+    let naive_error = (tenths.dot(&ones).unwrap() - exact).abs();
+    let kahan_error = (tenths.dot_with(&ones, Summation::Kahan).unwrap() - exact).abs();
+    let pairwise_error = (tenths.dot_with(&ones, Summation::Pairwise).unwrap() - exact).abs();
+    assert!(kahan_error <= 1e-12);
+    assert!(pairwise_error < naive_error);
+    assert!(kahan_error < naive_error);
+
+    let row = tenths.to_matrix().transpose();
+    let product = row
+        .matrix_multiply_with(&ones.to_matrix(), Summation::Kahan)
+        .unwrap();
+    assert!((product[0][0] - exact).abs() <= 1e-12);
+    assert!((row.sum_with(Summation::Kahan) - exact).abs() <= 1e-12);
+    assert!((ones.norm_with(Summation::Pairwise) - (count as f64).sqrt()).abs() < 1e-12);
+}
+
+#[test]
+fn errors_carry_shapes() {
+    let mut matrix = Matrix::try_from([
+        [1, 2, 3], //
+        [4, 5, 6],
+    ])
+    .unwrap();
+
+    let error = matrix.matrix_multiply(&matrix).unwrap_err();
+    let shape = Shape::try_new(2, 3).unwrap();
+    assert_eq!(error.shape(), Some(shape));
+    assert_eq!(error.rhs_shape(), Some(shape));
+    assert!(!shape.can_multiply(&shape));
+    assert_eq!(
+        shape.product(&shape.transpose()),
+        Some(Shape::try_new(2, 2).unwrap())
+    );
+
+    let error = matrix.set_row(2, &[0, 0, 0]).unwrap_err();
+    assert!(matches!(
+        error,
+        MatrixError::ElementOutOfBounds {
+            row: 2,
+            column: 0,
+            ..
+        }
+    ));
+    assert_eq!(error.shape(), Some(matrix.shape()));
+    assert_eq!(
+        error.to_string(),
+        "Index (row 2, column 0) is out of bounds for a 2x3 matrix"
+    );
+    assert!(matches!(
+        matrix.set_column(1, &[0, 0, 0]),
+        Err(MatrixError::LengthMismatch {
+            expected: 2,
+            actual: 3,
+            ..
+        })
+    ));
+
+    let response = Matrix::<f64>::zeros(Shape::try_new(3, 1).unwrap());
+    assert!(matches!(
+        NormalEquations::new(matrix.cast::<f64>(), response),
+        Err(MatrixError::DimensionError(DimensionError::UnexpectedHeight {
+            expected: 2,
+            actual
+        })) if actual == Shape::try_new(3, 1).unwrap()
+    ));
+    assert_eq!(Matrix::<i32>::zeros(shape).shape(), shape);
+
+    matrix.set_row(1, &[7, 8, 9]).unwrap();
+    matrix.set_column(0, &[0, 0]).unwrap();
+    let one = std::num::NonZeroUsize::MIN;
+    let two = std::num::NonZeroUsize::new(2).unwrap();
+    let block = matrix.submatrix((0, 1), two, two).unwrap();
+    assert_eq!(
+        block,
+        Matrix::try_from([
+            [2, 3], //
+            [8, 9],
+        ])
+        .unwrap()
+    );
+    assert!(matches!(
+        matrix.submatrix((1, 2), two, one),
+        Err(MatrixError::ElementOutOfBounds {
+            row: 2,
+            column: 2,
+            ..
+        })
+    ));
+    // a block whose last index does not fit in a usize is out of bounds rather than a panic
+    let huge = std::num::NonZeroUsize::MAX;
+    assert!(matches!(
+        matrix.submatrix((1, 0), huge, one),
+        Err(MatrixError::ElementOutOfBounds {
+            row: usize::MAX,
+            column: 0,
+            ..
+        })
+    ));
+    assert!(matrix.submatrix((0, 1), one, huge).is_err());
+}
+
+#[test]
+fn try_constructors_and_accessors() {
+    assert!(Matrix::<i32>::try_zeros(0, 3).is_err());
+    assert!(Matrix::<i32>::try_identity(0).is_err());
+    assert_eq!(
+        Matrix::<i32>::try_identity(2).unwrap(),
+        Matrix::try_from([[1, 0], [0, 1]]).unwrap()
+    );
+
+    assert!(Matrix::<i32>::try_from_rows(vec![]).is_err());
+    assert!(matches!(
+        Matrix::try_from_rows(vec![vec![1, 2], vec![3]]),
+        Err(MatrixError::InconsistentRow {
+            row: 1,
+            expected: 2,
+            actual: 1
+        })
+    ));
+    let mut matrix = Matrix::try_from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    assert_eq!(
+        matrix,
+        Matrix::<i32>::try_zeros(2, 2)
+            .unwrap()
+            .add(&matrix)
+            .unwrap()
+    );
+
+    assert_eq!(*matrix.try_get_element((1, 0)).unwrap(), 3);
+    assert!(matrix.try_get_element((0, 2)).is_err());
+    matrix.try_set_element((0, 1), 5).unwrap();
+    assert_eq!(matrix.try_get_row(0).unwrap(), &[1, 5]);
+    assert!(matrix.try_get_row(2).is_err());
+
+    // an empty data set cannot be constructed, so len_nonzero cannot panic
+    assert!("".parse::<DataSet<f64>>().is_err());
+}
+
+#[test]
+fn index_offsets_neighbors_and_ranges() {
+    let shape = Shape::try_new(3, 4).unwrap();
+    let corner = MatrixIndex::from((0, 0));
+    assert_eq!(corner.offset(-1, 0), None);
+    assert_eq!(corner.offset(2, 3), Some(MatrixIndex::from((2, 3))));
+
+    let neighbors = corner.neighbors(shape).collect::<Vec<_>>();
+    assert_eq!(neighbors, vec![(0, 1).into(), (1, 0).into()]);
+    assert_eq!(MatrixIndex::from((1, 1)).neighbors(shape).count(), 4);
+    assert_eq!(corner.neighbors_with_diagonals(shape).count(), 3);
+    assert_eq!(
+        MatrixIndex::from((1, 1))
+            .neighbors_with_diagonals(shape)
+            .count(),
+        8
+    );
+    assert_eq!(MatrixIndex::from((2, 3)).neighbors(shape).count(), 2);
+
+    let range = IndexRange::new((1, 1), Shape::try_new(2, 2).unwrap());
+    assert_eq!(range.bottom_right(), Some(MatrixIndex::from((2, 2))));
+    assert!(range.contains((2, 1)));
+    assert!(!range.contains((0, 1)));
+    // a block that runs past usize::MAX has no last index but still knows what it contains
+    let edge = IndexRange::new((usize::MAX, 0), Shape::try_new(2, 1).unwrap());
+    assert_eq!(edge.bottom_right(), None);
+    assert!(edge.contains((usize::MAX, 0)));
+    assert!(!edge.contains((usize::MAX - 1, 0)));
+    assert_eq!(
+        range.iter(Order::RowMajor).collect::<Vec<_>>(),
+        vec![(1, 1).into(), (1, 2).into(), (2, 1).into(), (2, 2).into()]
+    );
+    assert_eq!(
+        range.iter(Order::ColumnMajor).collect::<Vec<_>>(),
+        vec![(1, 1).into(), (2, 1).into(), (1, 2).into(), (2, 2).into()]
+    );
+
+    let matrix = Matrix::try_from([
+        [1, 2], //
+        [3, 4],
+    ])
+    .unwrap();
+    let column_major = matrix
+        .indexes(Order::ColumnMajor)
+        .map(|index| matrix[index])
+        .collect::<Vec<_>>();
+    assert_eq!(column_major, vec![1, 3, 2, 4]);
+}
+
+#[test]
+fn windows_and_chunks() {
+    let matrix = Matrix::try_from([
+        [1, 2, 3], //
+        [4, 5, 6],
+        [7, 8, 9],
+    ])
+    .unwrap();
+    let one = std::num::NonZeroUsize::MIN;
+    let two = std::num::NonZeroUsize::new(2).unwrap();
+    let four = std::num::NonZeroUsize::new(4).unwrap();
+
+    // 2 x 2 sum pooling
+    let sums = matrix
+        .windows(two, two)
+        .map(|window| window.elements().sum::<i32>())
+        .collect::<Vec<_>>();
+    assert_eq!(sums, vec![12, 16, 24, 28]);
+    let last = matrix.windows(two, two).last().unwrap();
+    assert_eq!(last[(0, 0)], 5);
+    assert_eq!(
+        last.to_matrix(),
+        Matrix::try_from([
+            [5, 6], //
+            [8, 9],
+        ])
+        .unwrap()
+    );
+    assert_eq!(matrix.windows(four, one).count(), 0);
+    assert_eq!(matrix.windows(one, one).count(), 9);
+
+    let tiles = matrix
+        .chunks(two, two)
+        .map(|tile| (tile.height(), tile.width(), tile.elements().sum::<i32>()))
+        .collect::<Vec<_>>();
+    assert_eq!(tiles, vec![(2, 2, 12), (2, 1, 9), (1, 2, 15), (1, 1, 9)]);
+    assert_eq!(matrix.chunks(four, four).count(), 1);
+
+    // views past the edge, including ones whose last index would overflow, are None
+    assert!(matrix
+        .view(IndexRange::new((2, 2), Shape::try_new(2, 1).unwrap()))
+        .is_none());
+    assert!(matrix
+        .view(IndexRange::new(
+            (usize::MAX, 0),
+            Shape::try_new(2, 1).unwrap()
+        ))
+        .is_none());
+    assert!(matrix
+        .view(IndexRange::new((0, usize::MAX), Shape::square(two)))
+        .is_none());
+}
+
+#[test]
+fn diagonal_iterators() {
+    let mut matrix = Matrix::try_from([
+        [1, 2, 3], //
+        [4, 5, 6],
+    ])
+    .unwrap();
+
+    assert_eq!(
+        matrix.diagonal_iter().copied().collect::<Vec<_>>(),
+        vec![1, 5]
+    );
+    assert_eq!(
+        matrix.k_diagonal_iter(1).copied().collect::<Vec<_>>(),
+        vec![2, 6]
+    );
+    assert_eq!(
+        matrix.k_diagonal_iter(2).copied().collect::<Vec<_>>(),
+        vec![3]
+    );
+    assert_eq!(
+        matrix.k_diagonal_iter(-1).copied().collect::<Vec<_>>(),
+        vec![4]
+    );
+    assert_eq!(matrix.k_diagonal_iter(3).count(), 0);
+    assert_eq!(matrix.k_diagonal_iter(-2).count(), 0);
+    assert_eq!(
+        matrix.anti_diagonal_iter().copied().collect::<Vec<_>>(),
+        vec![3, 5]
+    );
+    assert_eq!(matrix.trace(), 6);
+
+    matrix.diagonal_iter_mut().for_each(|element| *element = 0);
+    matrix
+        .anti_diagonal_iter_mut()
+        .for_each(|element| *element += 10);
+    matrix
+        .k_diagonal_iter_mut(-1)
+        .for_each(|element| *element = -1);
+    assert_eq!(
+        matrix,
+        Matrix::try_from([
+            [0, 2, 13], //
+            [-1, 10, 6],
+        ])
+        .unwrap()
+    );
+}
+
+#[test]
+fn permutation_equality() {
+    let matrix = Matrix::try_from([
+        [1, 2, 3], //
+        [4, 5, 6],
+        [7, 8, 9],
+    ])
+    .unwrap();
+    let rows_swapped = Matrix::try_from([
+        [7, 8, 9], //
+        [1, 2, 3],
+        [4, 5, 6],
+    ])
+    .unwrap();
+    assert_eq!(
+        matrix.is_row_permutation_of(&rows_swapped),
+        Some(vec![1, 2, 0])
+    );
+    assert_eq!(matrix.is_column_permutation_of(&rows_swapped), None);
+
+    let both = Matrix::try_from([
+        [9, 7, 8], //
+        [3, 1, 2],
+        [6, 4, 5],
+    ])
+    .unwrap();
+    assert_eq!(matrix.is_row_permutation_of(&both), None);
+    let permutation = matrix.is_permutation_of(&both).unwrap();
+    for (index, element) in matrix.elements_enumerated() {
+        let row = permutation.rows()[index.row()];
+        let column = permutation.columns()[index.column()];
+        assert_eq!(*element, both[row][column]);
+    }
+
+    // the same multiset of elements is not enough
+    let scrambled = Matrix::try_from([
+        [1, 2, 3], //
+        [4, 5, 6],
+        [7, 9, 8],
+    ])
+    .unwrap();
+    assert_eq!(matrix.is_permutation_of(&scrambled), None);
+    assert_eq!(matrix.is_permutation_of(&matrix.transpose()), None);
+}
+
+#[test]
+fn matrix_builder() {
+    let mut builder = MatrixBuilder::new();
+    assert!(builder.push_row(Vec::<i32>::new()).is_err());
+    builder
+        .push_row(vec![1, 2])
+        .unwrap()
+        .push_row(vec![3, 4])
+        .unwrap();
+    assert_eq!(builder.width(), Some(2));
+
+    let error = builder.push_row(vec![5, 6, 7]).unwrap_err();
+    assert!(matches!(
+        error,
+        MatrixError::InconsistentRow {
+            row: 2,
+            expected: 2,
+            actual: 3
+        }
+    ));
+
+    // a failed push leaves the builder unchanged
+    assert_eq!(builder.height(), 2);
+    assert_eq!(
+        builder.build().unwrap(),
+        Matrix::try_from([
+            [1, 2], //
+            [3, 4],
+        ])
+        .unwrap()
+    );
+    assert!(MatrixBuilder::<i32>::new().build().is_err());
+}
+
+#[test]
+fn solve_augmented_systems() {
+    // x + y = 3, x - y = 1
+    let unique = Matrix::<f64>::try_from([
+        [1.0, 1.0, 3.0], //
+        [1.0, -1.0, 1.0],
+    ])
+    .unwrap();
+    let solved = unique.solve_augmented(1).unwrap();
+    assert_eq!(solved.consistency(), Consistency::Unique);
+    assert_eq!(solved.rank(), 2);
+    let solution = solved.solution().unwrap();
+    assert!((solution[0][0] - 2.0).abs() < 1e-12);
+    assert!((solution[1][0] - 1.0).abs() < 1e-12);
+
+    // the second equation is twice the first
+    let infinite = Matrix::<f64>::try_from([
+        [1.0, 2.0, 4.0], //
+        [2.0, 4.0, 8.0],
+    ])
+    .unwrap();
+    let solved = infinite.solve_augmented(1).unwrap();
+    assert_eq!(solved.consistency(), Consistency::Infinite);
+    assert_eq!(solved.rank(), 1);
+    let solution = solved.solution().unwrap();
+    assert!((solution[0][0] + 2.0 * solution[1][0] - 4.0).abs() < 1e-12);
+
+    let inconsistent = Matrix::<f64>::try_from([
+        [1.0, 2.0, 4.0], //
+        [2.0, 4.0, 9.0],
+    ])
+    .unwrap();
+    let solved = inconsistent.solve_augmented(1).unwrap();
+    assert_eq!(solved.consistency(), Consistency::Inconsistent);
+    assert!(solved.solution().is_none());
+
+    // [A | I] solves for the inverse
+    let augmented = Matrix::<f64>::try_from([
+        [2.0, 1.0, 1.0, 0.0], //
+        [1.0, 1.0, 0.0, 1.0],
+    ])
+    .unwrap();
+    let inverse = augmented
+        .solve_augmented(2)
+        .unwrap()
+        .into_solution()
+        .unwrap();
+    let expected = Matrix::try_from([
+        [1.0, -1.0], //
+        [-1.0, 2.0],
+    ])
+    .unwrap();
+    for (index, &element) in inverse.elements_enumerated() {
+        assert!((element - expected[index]).abs() < 1e-12);
+    }
+
+    assert!(augmented.solve_augmented(0).is_err());
+    assert!(augmented.solve_augmented(4).is_err());
+}
+
+#[test]
+fn smith_normal_form() {
+    let matrix = Matrix::<i64>::try_from([
+        [2, 4, 4], //
+        [-6, 6, 12],
+        [10, -4, -16],
+    ])
+    .unwrap();
+    let smith = matrix.smith_normal_form();
+    assert_eq!(smith.invariant_factors(), vec![2, 6, 12]);
+    assert_eq!(
+        smith
+            .u()
+            .matrix_multiply(&matrix)
+            .unwrap()
+            .matrix_multiply(smith.v())
+            .unwrap(),
+        *smith.s()
+    );
+    assert_eq!(smith.u().determinant().unwrap().abs(), 1);
+    assert_eq!(smith.v().determinant().unwrap().abs(), 1);
+
+    // rectangular and rank deficient
+    let matrix = Matrix::<i64>::try_from([
+        [2, 4, 6, 8], //
+        [1, 2, 3, 4],
+    ])
+    .unwrap();
+    let (u, s, v) = matrix.smith_normal_form().into_parts();
+    assert_eq!(
+        u.matrix_multiply(&matrix)
+            .unwrap()
+            .matrix_multiply(&v)
+            .unwrap(),
+        s
+    );
+    assert_eq!(
+        s,
+        Matrix::try_from([
+            [1, 0, 0, 0], //
+            [0, 0, 0, 0],
+        ])
+        .unwrap()
+    );
+}
+
+#[test]
+fn hermite_normal_form() {
+    let matrix = Matrix::<i64>::try_from([
+        [3, 3, 1, 4], //
+        [0, 1, 0, 0],
+        [0, 0, 19, 16],
+        [0, 0, 0, 3],
+    ])
+    .unwrap();
+    let hermite = matrix.hermite_normal_form();
+    assert_eq!(
+        *hermite.h(),
+        Matrix::try_from([
+            [3, 0, 1, 1], //
+            [0, 1, 0, 0],
+            [0, 0, 19, 1],
+            [0, 0, 0, 3],
+        ])
+        .unwrap()
+    );
+    assert_eq!(hermite.u().matrix_multiply(&matrix).unwrap(), *hermite.h());
+    assert_eq!(hermite.u().determinant().unwrap().abs(), 1);
+    assert_eq!(hermite.rank(), 4);
+
+    let matrix = Matrix::<i64>::try_from([
+        [2, 4, 6], //
+        [-3, -6, 1],
+        [4, 8, 12],
+    ])
+    .unwrap();
+    let (h, u) = matrix.hermite_normal_form().into_parts();
+    assert_eq!(u.matrix_multiply(&matrix).unwrap(), h);
+    assert_eq!(matrix.hermite_normal_form().pivot_columns(), &[0, 2]);
+    // echelon form with positive pivots and reduced entries above them
+    assert_eq!(h[2], [0, 0, 0]);
+    assert!(h[0][0] > 0 && h[1][2] > 0);
+    assert!((0..h[1][2]).contains(&h[0][2]));
+}
+
+#[test]
+fn bareiss_determinant() {
+    // diagonally dominant so the determinant is around 1000⁶, far outside i32
+    let size = std::num::NonZeroUsize::new(6).unwrap();
+    let mut matrix = Matrix::<i32>::zeros(Shape::square(size));
+    for (index, element) in matrix.elements_mut_enumerated() {
+        *element = if index.row() == index.column() {
+            1000
+        } else {
+            (index.row() as i32 - index.column() as i32).abs()
+        };
+    }
+    let wide = matrix.determinant_widened::<i128>().unwrap();
+    assert!(wide > i128::from(i32::MAX));
+    assert!(matches!(
+        matrix.checked_determinant(),
+        Err(MatrixError::Overflow)
+    ));
+    assert_eq!(matrix.cast::<i128>().checked_determinant().unwrap(), wide);
+
+    let small = Matrix::<i32>::try_from([
+        [2, -1, 0], //
+        [1, 3, -2],
+        [0, 2, 4],
+    ])
+    .unwrap();
+    assert_eq!(
+        small.checked_determinant().unwrap(),
+        small.determinant().unwrap()
+    );
+    assert_eq!(small.determinant_bareiss().unwrap(), 36);
+}
+
+#[test]
+fn exact_big_arithmetic() {
+    use num::{BigInt, BigRational};
+
+    // the 5 x 5 Hilbert matrix is famously ill-conditioned but its inverse has integer entries
+    let size = std::num::NonZeroUsize::new(5).unwrap();
+    let mut hilbert = Matrix::<i64>::zeros(Shape::square(size)).to_bigrational();
+    for (index, element) in hilbert.elements_mut_enumerated() {
+        *element = BigRational::new(
+            BigInt::from(1),
+            BigInt::from(index.row() + index.column() + 1),
+        );
+    }
+
+    let determinant = hilbert.determinant_exact().unwrap();
+    assert_eq!(
+        determinant,
+        BigRational::new(BigInt::from(1), BigInt::from(266_716_800_000_i64))
+    );
+
+    let inverse = hilbert.inverse_exact().unwrap();
+    assert!(inverse.elements().all(|element| element.is_integer()));
+    assert_eq!(inverse[0][0], BigRational::from_integer(BigInt::from(25)));
+    assert_eq!(
+        inverse[4][4],
+        BigRational::from_integer(BigInt::from(44_100))
+    );
+
+    // sums, vectors, batches, and pools only need Clone elements
+    assert_eq!(
+        hilbert.matrix_multiply(&inverse).unwrap().sum(),
+        BigRational::from_integer(5.into())
+    );
+    let column = ColumnVector::try_from(hilbert.column(0).cloned().collect::<Vec<_>>()).unwrap();
+    let row: RowVector<BigRational> = column.clone().transpose();
+    assert_eq!(
+        (&row * &inverse).as_slice()[0],
+        BigRational::from_integer(1.into())
+    );
+    assert_eq!((&inverse * &column)[0], BigRational::from_integer(1.into()));
+    assert_eq!(column.dot(&column).unwrap(), column.norm_squared());
+    let products = Matrix::batch_multiply(
+        std::slice::from_ref(&hilbert),
+        std::slice::from_ref(&inverse),
+    )
+    .unwrap();
+    let mut pool = MatrixPool::new();
+    let pooled = hilbert.matrix_multiply_pooled(&inverse, &mut pool).unwrap();
+    assert_eq!(pooled, products[0]);
+    assert_eq!(pooled, Matrix::<i64>::identity(size).to_bigrational());
+
+    let integers = Matrix::<i64>::try_from([
+        [i64::MAX, 1], //
+        [1, i64::MAX],
+    ])
+    .unwrap()
+    .to_bigint();
+    let expected = BigInt::from(i64::MAX) * BigInt::from(i64::MAX) - BigInt::from(1);
+    assert_eq!(integers.determinant_exact().unwrap(), expected);
+
+    let singular = Matrix::<i64>::try_from([
+        [1, 2], //
+        [2, 4],
+    ])
+    .unwrap()
+    .to_bigrational();
+    assert!(singular.inverse_exact().is_err());
+    let (reduced, pivots) = singular.rref_exact();
+    assert_eq!(pivots, vec![0]);
+    assert_eq!(reduced[0][1], BigRational::from_integer(BigInt::from(2)));
+}
+
+#[test]
+fn solve_general_solution_sets() {
+    // x + 2y + z = 4 and y + z = 1: one free variable
+    let a = Matrix::<f64>::try_from([
+        [1.0, 2.0, 1.0], //
+        [0.0, 1.0, 1.0],
+    ])
+    .unwrap();
+    let b = Matrix::<f64>::try_from([[4.0], [1.0]]).unwrap();
+    let solutions = a.solve_general(&b).unwrap();
+    assert_eq!(solutions.consistency(), Consistency::Infinite);
+    assert_eq!(solutions.null_space().len(), 1);
+
+    let particular = solutions.particular().unwrap();
+    let null = solutions.null_space()[0].to_matrix();
+    for t in [-2.0, 0.0, 3.5] {
+        let x = particular.add(&null.scalar_multiply(t)).unwrap();
+        let ax = a.matrix_multiply(&x).unwrap();
+        for (index, &element) in ax.elements_enumerated() {
+            assert!((element - b[index]).abs() < 1e-12);
+        }
+    }
+
+    let square = Matrix::<f64>::try_from([
+        [2.0, 0.0], //
+        [0.0, 4.0],
+    ])
+    .unwrap();
+    let b = Matrix::<f64>::try_from([[2.0], [2.0]]).unwrap();
+    let unique = square.solve_general(&b).unwrap();
+    assert_eq!(unique.consistency(), Consistency::Unique);
+    assert!(unique.null_space().is_empty());
+    assert!((unique.particular().unwrap()[1][0] - 0.5).abs() < 1e-12);
+
+    let singular = Matrix::<f64>::try_from([
+        [1.0, 1.0], //
+        [1.0, 1.0],
+    ])
+    .unwrap();
+    let b = Matrix::<f64>::try_from([[1.0], [2.0]]).unwrap();
+    assert!(matches!(
+        singular.solve_general(&b).unwrap(),
+        SolutionSet::Inconsistent
+    ));
+    assert!(singular
+        .solve_general(&Matrix::<f64>::try_from([[1.0]]).unwrap())
+        .is_err());
+}
+
+#[test]
+fn least_squares_line_fit() {
+    // y = 1 + 2x with symmetric noise that cancels out
+    let a = Matrix::<f64>::try_from([
+        [1.0, 0.0], //
+        [1.0, 1.0],
+        [1.0, 2.0],
+        [1.0, 3.0],
+    ])
+    .unwrap();
+    let b = Matrix::<f64>::try_from([[1.1], [2.9], [5.1], [6.9]]).unwrap();
+    let x = a.least_squares(&b).unwrap();
+    assert!((x[0][0] - 1.06).abs() < 1e-12);
+    assert!((x[1][0] - 1.96).abs() < 1e-12);
+
+    // the normal equations give the same answer on a well conditioned problem
+    let transpose = a.transpose();
+    let normal = transpose
+        .matrix_multiply(&a)
+        .unwrap()
+        .inverse()
+        .unwrap()
+        .matrix_multiply(&transpose.matrix_multiply(&b).unwrap())
+        .unwrap();
+    for (index, &element) in x.elements_enumerated() {
+        assert!((element - normal[index]).abs() < 1e-12);
+    }
+
+    let dependent = Matrix::<f64>::try_from([
+        [1.0, 2.0], //
+        [2.0, 4.0],
+        [3.0, 6.0],
+    ])
+    .unwrap();
+    let b = Matrix::<f64>::try_from([[1.0], [2.0], [3.0]]).unwrap();
+    assert!(matches!(
+        dependent.least_squares(&b),
+        Err(MatrixError::Decomposition(DecompositionError::Singular))
+    ));
+    assert!(a.transpose().least_squares(&b).is_err());
+}
+
+#[test]
+fn normal_equation_components() {
+    let data = DataSet::<f64>::try_from([(0.0, 1.0), (1.0, 3.0), (2.0, 4.0), (3.0, 8.0)]).unwrap();
+    let normal = data.polynomial_normal_equations(1).unwrap();
+
+    assert_eq!(
+        *normal.xtx(),
+        Matrix::try_from([
+            [4.0, 6.0], //
+            [6.0, 14.0],
+        ])
+        .unwrap()
+    );
+    assert_eq!(*normal.xty(), Matrix::try_from([[16.0], [35.0]]).unwrap());
+
+    // the hat matrix is a symmetric projection whose trace is the number of coefficients
+    let hat = normal.hat_matrix().unwrap();
+    let squared = hat.matrix_multiply(&hat).unwrap();
+    for (index, &element) in hat.elements_enumerated() {
+        assert!((element - hat[index.transpose()]).abs() < 1e-12);
+        assert!((element - squared[index]).abs() < 1e-12);
+    }
+    assert!((hat.trace() - 2.0).abs() < 1e-12);
+
+    // H * y are the fitted values X * β
+    let fitted = hat.matrix_multiply(normal.response()).unwrap();
+    let predicted = normal
+        .design()
+        .matrix_multiply(&data.polynomial_regression(1).unwrap())
+        .unwrap();
+    for (index, &element) in fitted.elements_enumerated() {
+        assert!((element - predicted[index]).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn regression_influence_diagnostics() {
+    let points = [(0.0, 1.0), (1.0, 3.0), (2.0, 4.0), (3.0, 8.0), (4.0, 9.5)];
+    let data = DataSet::<f64>::try_from(points).unwrap();
+    let fit = data.polynomial_fit(1).unwrap();
+    let hat = fit.normal_equations().hat_matrix().unwrap();
+
+    let leverage = fit.leverage();
+    for (i, &h) in leverage.iter().enumerate() {
+        assert!((h - hat[i][i]).abs() < 1e-12);
+    }
+    assert!((leverage.iter().sum::<f64>() - 2.0).abs() < 1e-12);
+
+    // Cook's distance is the scaled shift in the fitted values when a point is left out
+    let variance = fit.residual_variance().unwrap();
+    let fitted = fit
+        .normal_equations()
+        .design()
+        .matrix_multiply(fit.coefficients())
+        .unwrap();
+    let cooks_distance = fit.cooks_distance().unwrap();
+    for (left_out, &distance) in cooks_distance.iter().enumerate() {
+        let remaining = points
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| i != left_out)
+            .map(|(_, &point)| point)
+            .collect::<Vec<_>>();
+        let coefficients = DataSet::<f64>::try_from(remaining.as_slice())
+            .unwrap()
+            .polynomial_regression(1)
+            .unwrap();
+        let refitted = fit
+            .normal_equations()
+            .design()
+            .matrix_multiply(&coefficients)
+            .unwrap();
+        let shift = fitted
+            .column(0)
+            .zip(refitted.column(0))
+            .map(|(a, b)| (a - b) * (a - b))
+            .sum::<f64>();
+        assert!((distance - shift / (2.0 * variance)).abs() < 1e-9);
+    }
+
+    let studentized = fit.studentized_residuals().unwrap();
+    for ((&r, &e), &h) in studentized.iter().zip(fit.residuals()).zip(&leverage) {
+        assert!((r * (variance * (1.0 - h)).sqrt() - e).abs() < 1e-12);
+    }
+
+    // as many coefficients as points leaves no degrees of freedom
+    let exact = DataSet::<f64>::try_from([(0.0, 1.0), (1.0, 3.0)]).unwrap();
+    assert!(exact.polynomial_fit(1).unwrap().cooks_distance().is_err());
+}
+
+#[test]
+fn regression_residual_autocorrelation() {
+    // residuals that alternate in sign are negatively autocorrelated
+    let alternating = DataSet::<f64>::try_from([
+        (0.0, 1.0),
+        (1.0, -1.0),
+        (2.0, 1.0),
+        (3.0, -1.0),
+        (4.0, 1.0),
+        (5.0, -1.0),
+    ])
+    .unwrap()
+    .polynomial_fit(1)
+    .unwrap();
+    let e = alternating.residuals();
+    let sum_of_squares = e.iter().map(|e| e * e).sum::<f64>();
+    let differences = e.windows(2).map(|w| (w[1] - w[0]).powi(2)).sum::<f64>();
+    assert!((alternating.durbin_watson() - differences / sum_of_squares).abs() < 1e-12);
+    assert!(alternating.durbin_watson() > 2.0);
+
+    let autocorrelation = alternating.residual_autocorrelation(2).unwrap();
+    let lag = |k: usize| e.iter().zip(&e[k..]).map(|(a, b)| a * b).sum::<f64>() / sum_of_squares;
+    assert!((autocorrelation[0] - lag(1)).abs() < 1e-12);
+    assert!((autocorrelation[1] - lag(2)).abs() < 1e-12);
+    assert!(autocorrelation[0] < 0.0);
+    let q = 6.0 * 8.0 * (lag(1).powi(2) / 5.0 + lag(2).powi(2) / 4.0);
+    assert!((alternating.ljung_box(2).unwrap() - q).abs() < 1e-9);
+
+    // slowly varying residuals are positively autocorrelated
+    let smooth = DataSet::<f64>::try_from([
+        (0.0, 0.0),
+        (1.0, 2.0),
+        (2.0, 2.0),
+        (3.0, 0.0),
+        (4.0, -2.0),
+        (5.0, -2.0),
+        (6.0, 0.0),
+        (7.0, 2.0),
+        (8.0, 2.0),
+    ])
+    .unwrap()
+    .polynomial_fit(1)
+    .unwrap();
+    assert!(smooth.durbin_watson() < 2.0);
+    assert!(smooth.residual_autocorrelation(1).unwrap()[0] > 0.0);
+
+    assert!(smooth.residual_autocorrelation(9).is_err());
+}
+
+#[test]
+fn regression_robust_standard_errors() {
+    // the noise grows with x
+    let data = DataSet::<f64>::try_from([
+        (1.0, 2.1),
+        (2.0, 3.8),
+        (3.0, 6.5),
+        (4.0, 7.0),
+        (5.0, 11.9),
+        (6.0, 10.2),
+        (7.0, 17.5),
+    ])
+    .unwrap();
+    let fit = data.polynomial_fit(1).unwrap();
+    let design = fit.normal_equations().design();
+
+    let classical = fit
+        .coefficient_covariance(CovarianceEstimator::Classical)
+        .unwrap();
+    let variance = fit.residual_variance().unwrap();
+    for (index, &element) in classical.elements_enumerated() {
+        assert!((element - variance * fit.xtx_inverse()[index]).abs() < 1e-12);
+    }
+
+    // (XᵀX)⁻¹ Xᵀ diag(e²) X (XᵀX)⁻¹
+    let mut weighted = design.clone();
+    for (row, &e) in weighted.rows_mut().zip(fit.residuals()) {
+        for element in row.iter_mut() {
+            *element *= e * e;
+        }
+    }
+    let meat = design.transpose().matrix_multiply(&weighted).unwrap();
+    let hc0 = fit
+        .xtx_inverse()
+        .matrix_multiply(&meat)
+        .unwrap()
+        .matrix_multiply(fit.xtx_inverse())
+        .unwrap();
+    let robust = fit
+        .coefficient_covariance(CovarianceEstimator::Hc0)
+        .unwrap();
+    let corrected = fit
+        .coefficient_covariance(CovarianceEstimator::Hc1)
+        .unwrap();
+    for (index, &element) in hc0.elements_enumerated() {
+        assert!((element - robust[index]).abs() < 1e-12);
+        assert!((element * 7.0 / 5.0 - corrected[index]).abs() < 1e-12);
+    }
+
+    let standard_errors = fit.standard_errors(CovarianceEstimator::Hc1).unwrap();
+    assert_eq!(standard_errors.len(), 2);
+    for (i, &error) in standard_errors.iter().enumerate() {
+        assert!((error * error - corrected[i][i]).abs() < 1e-12);
+    }
+
+    // White's estimator does not need spare degrees of freedom
+    let exact = DataSet::<f64>::try_from([(0.0, 1.0), (1.0, 3.0)])
+        .unwrap()
+        .polynomial_fit(1)
+        .unwrap();
+    assert!(exact.standard_errors(CovarianceEstimator::Hc0).is_ok());
+    assert!(exact.standard_errors(CovarianceEstimator::Hc1).is_err());
+}
+
+#[test]
+fn matrix_polyval() {
+    let a = Matrix::try_from([
+        [1, 2], //
+        [3, 4],
+    ])
+    .unwrap();
+
+    // 2 - 3A + A²
+    let expected = a
+        .matrix_multiply(&a)
+        .unwrap()
+        .add(&a.scalar_multiply(-3))
+        .unwrap()
+        .add(&Matrix::identity(a.height_nonzero()).scalar_multiply(2))
+        .unwrap();
+    assert_eq!(a.polyval(&[2, -3, 1]).unwrap(), expected);
+
+    // Cayley–Hamilton: A satisfies its characteristic polynomial λ² - trace λ + det
+    let characteristic = [a.determinant().unwrap(), -a.trace(), 1];
+    assert_eq!(
+        a.polyval(&characteristic).unwrap(),
+        Matrix::zeros(a.shape())
+    );
+
+    assert_eq!(a.polyval(&[]).unwrap(), Matrix::zeros(a.shape()));
+    assert_eq!(
+        a.polyval(&[5]).unwrap(),
+        Matrix::identity(a.height_nonzero()).scalar_multiply(5)
+    );
+    assert!(Matrix::try_from([[1, 2]])
+        .unwrap()
+        .polyval(&[1, 1])
+        .is_err());
+}
+
+#[test]
+fn matrix_logarithm() {
+    fn assert_close(actual: &Matrix<f64>, expected: &Matrix<f64>) {
+        for (index, &element) in actual.elements_enumerated() {
+            assert!(
+                (element - expected[index]).abs() < 1e-9,
+                "{actual:?} != {expected:?}"
+            );
+        }
+    }
+
+    let e = std::f64::consts::E;
+    let diagonal = Matrix::<f64>::try_from([[e, 0.0], [0.0, e * e]]).unwrap();
+    assert_close(
+        &diagonal.log().unwrap(),
+        &Matrix::try_from([[1.0, 0.0], [0.0, 2.0]]).unwrap(),
+    );
+
+    // a rotation is the exponential of a skew symmetric generator
+    let theta = 2.5_f64;
+    let rotation = Matrix::<f64>::try_from([
+        [theta.cos(), -theta.sin()], //
+        [theta.sin(), theta.cos()],
+    ])
+    .unwrap();
+    assert_close(
+        &rotation.log().unwrap(),
+        &Matrix::try_from([[0.0, -theta], [theta, 0.0]]).unwrap(),
+    );
+
+    // exp of the nilpotent [[0, 1], [0, 0]] is I + N
+    let shear = Matrix::<f64>::try_from([[1.0, 1.0], [0.0, 1.0]]).unwrap();
+    assert_close(
+        &shear.log().unwrap(),
+        &Matrix::try_from([[0.0, 1.0], [0.0, 0.0]]).unwrap(),
+    );
+
+    let scalar = Matrix::<f64>::try_from([[e]]).unwrap();
+    assert_close(&scalar.log().unwrap(), &Matrix::try_from([[1.0]]).unwrap());
+
+    let identity = Matrix::<f64>::identity(std::num::NonZeroUsize::new(3).unwrap());
+    assert_close(&identity.log().unwrap(), &identity.scalar_multiply(0.0));
+
+    // a small positive eigenvalue still has a real logarithm
+    let small = Matrix::<f64>::try_from([[1e-10, 0.0], [0.0, 1.0]]).unwrap();
+    assert_close(
+        &small.log().unwrap(),
+        &Matrix::try_from([[1e-10_f64.ln(), 0.0], [0.0, 0.0]]).unwrap(),
+    );
+
+    for no_logarithm in [
+        Matrix::<f64>::try_from([[-1.0, 0.0], [0.0, 2.0]]).unwrap(),
+        Matrix::<f64>::try_from([[1.0, 2.0], [2.0, 4.0]]).unwrap(),
+    ] {
+        assert!(matches!(
+            no_logarithm.log(),
+            Err(MatrixError::Decomposition(
+                DecompositionError::NoPrincipalLogarithm
+            ))
+        ));
+    }
+    assert!(Matrix::<f64>::try_from([[1.0, 2.0]])
+        .unwrap()
+        .log()
+        .is_err());
+}
+
+#[test]
+fn sylvester_and_lyapunov() {
+    fn assert_close(actual: &Matrix<f64>, expected: &Matrix<f64>) {
+        for (index, &element) in actual.elements_enumerated() {
+            assert!(
+                (element - expected[index]).abs() < 1e-9,
+                "{actual:?} != {expected:?}"
+            );
+        }
+    }
+
+    // a has a complex conjugate pair of eigenvalues, b has both kinds of Schur block
+    let a = Matrix::<f64>::try_from([
+        [1.0, -2.0, 0.5], //
+        [3.0, 1.0, 0.0],
+        [0.0, 1.0, 4.0],
+    ])
+    .unwrap();
+    let b = Matrix::<f64>::try_from([
+        [2.0, 1.0, 0.0], //
+        [-1.0, 2.0, 0.0],
+        [0.5, 0.0, 3.0],
+    ])
+    .unwrap();
+    let c = Matrix::<f64>::try_from([
+        [1.0, 2.0, 3.0], //
+        [4.0, 5.0, 6.0],
+        [7.0, 8.0, 10.0],
+    ])
+    .unwrap();
+    let x = solve_sylvester(&a, &b, &c).unwrap();
+    let lhs = a
+        .matrix_multiply(&x)
+        .unwrap()
+        .add(&x.matrix_multiply(&b).unwrap())
+        .unwrap();
+    assert_close(&lhs, &c);
+
+    let b = Matrix::<f64>::try_from([[2.0, 1.0], [-1.0, 2.0]]).unwrap();
+    let c = Matrix::<f64>::try_from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]).unwrap();
+    let x = solve_sylvester(&a, &b, &c).unwrap();
+    let lhs = a
+        .matrix_multiply(&x)
+        .unwrap()
+        .add(&x.matrix_multiply(&b).unwrap())
+        .unwrap();
+    assert_close(&lhs, &c);
+    assert_eq!((x.height(), x.width()), (3, 2));
+
+    // a stable system has a symmetric positive definite solution
+    let stable = Matrix::<f64>::try_from([
+        [-1.0, 2.0], //
+        [-3.0, -4.0],
+    ])
+    .unwrap();
+    let q = Matrix::<f64>::identity(stable.height_nonzero());
+    let p = solve_lyapunov(&stable, &q).unwrap();
+    let residual = stable
+        .matrix_multiply(&p)
+        .unwrap()
+        .add(&p.matrix_multiply(&stable.transpose()).unwrap())
+        .unwrap()
+        .add(&q)
+        .unwrap();
+    assert_close(&residual, &q.scalar_multiply(0.0));
+    assert_close(&p, &p.transpose());
+    assert!(p.cholesky().is_ok());
+
+    // A and -B share the eigenvalue 1
+    let one = Matrix::<f64>::try_from([[1.0]]).unwrap();
+    assert!(matches!(
+        solve_sylvester(&one, &one.scalar_multiply(-1.0), &one),
+        Err(MatrixError::Decomposition(DecompositionError::Singular))
+    ));
+    assert!(solve_sylvester(&a, &b, &one).is_err());
+}
+
+#[test]
+fn controllability_and_observability() {
+    // a double integrator driven by a force
+    let a = Matrix::<f64>::try_from([
+        [1.0, 1.0], //
+        [0.0, 1.0],
+    ])
+    .unwrap();
+    let force = Matrix::<f64>::try_from([[0.0], [1.0]]).unwrap();
+    assert_eq!(
+        controllability_matrix(&a, &force).unwrap(),
+        Matrix::try_from([
+            [0.0, 1.0], //
+            [1.0, 1.0],
+        ])
+        .unwrap()
+    );
+    assert!(is_controllable(&a, &force).unwrap());
+
+    // pushing the position directly never changes the velocity
+    let position = Matrix::<f64>::try_from([[1.0], [0.0]]).unwrap();
+    assert!(!is_controllable(&a, &position).unwrap());
+
+    let measure_position = Matrix::<f64>::try_from([[1.0, 0.0]]).unwrap();
+    assert_eq!(
+        observability_matrix(&a, &measure_position).unwrap(),
+        Matrix::try_from([
+            [1.0, 0.0], //
+            [1.0, 1.0],
+        ])
+        .unwrap()
+    );
+    assert!(is_observable(&a, &measure_position).unwrap());
+    let measure_velocity = Matrix::<f64>::try_from([[0.0, 1.0]]).unwrap();
+    assert!(!is_observable(&a, &measure_velocity).unwrap());
+
+    assert!(controllability_matrix(&a, &measure_position).is_err());
+    assert!(observability_matrix(&a, &force).is_err());
+    assert!(is_controllable(&force, &force).is_err());
+}
+
+#[test]
+fn simulate_discrete_system() {
+    // position and velocity of a unit mass pushed with a unit force for 3 steps
+    let a = Matrix::<f64>::try_from([
+        [1.0, 1.0], //
+        [0.0, 1.0],
+    ])
+    .unwrap();
+    let b = Matrix::<f64>::try_from([[0.0], [1.0]]).unwrap();
+    let inputs = Matrix::<f64>::try_from([[1.0], [1.0], [1.0], [0.0]]).unwrap();
+
+    let trajectory = simulate_lti(&a, &b, &[0.0, 0.0], &inputs).unwrap();
+    assert_eq!(
+        trajectory,
+        Matrix::try_from([
+            [0.0, 0.0], //
+            [0.0, 1.0],
+            [1.0, 2.0],
+            [3.0, 3.0],
+            [6.0, 3.0],
+        ])
+        .unwrap()
+    );
+
+    // each step agrees with the matrix form A * x + B * u
+    for (step, input) in inputs.rows().enumerate() {
+        let state = Matrix::try_from([[trajectory[step][0]], [trajectory[step][1]]]).unwrap();
+        let next = a
+            .matrix_multiply(&state)
+            .unwrap()
+            .add(&b.scalar_multiply(input[0]))
+            .unwrap();
+        assert_eq!(
+            next.column(0).copied().collect::<Vec<_>>(),
+            trajectory[step + 1].to_vec()
+        );
+    }
+
+    assert!(simulate_lti(&a, &b, &[0.0], &inputs).is_err());
+    assert!(simulate_lti(&a, &b, &[0.0, 0.0], &a).is_err());
+    let two_inputs = Matrix::<f64>::try_from([[1.0, 0.0], [0.0, 1.0], [1.0, 1.0]]).unwrap();
+    assert!(simulate_lti(&a, &two_inputs, &[0.0, 0.0], &inputs).is_err());
+}
+
+#[test]
+fn regression_fit_report() {
+    let data = DataSet::<f64>::try_from([(0.0, 1.0), (1.0, 3.0), (2.0, 4.0), (3.0, 8.0)]).unwrap();
+    let fit = data.polynomial_fit(1).unwrap();
+    let report = fit.report();
+    assert_eq!(report.rank(), 2);
+    assert!(!report.used_pseudo_inverse());
+    assert_eq!(report.ridge_jitter(), None);
+    assert!(!report.is_degraded());
+    // XᵀX == [[4, 6], [6, 14]] has eigenvalues 9 ± √61
+    let expected = (9.0 + 61f64.sqrt()) / (9.0 - 61f64.sqrt());
+    assert!((report.condition_number() - expected).abs() < 1e-9);
+
+    // every input is the same so the slope and intercept cannot be told apart
+    let repeated = DataSet::<f64>::try_from([(1.0, 2.0), (1.0, 4.0), (1.0, 6.0)]).unwrap();
+    let fit = repeated.polynomial_fit(1).unwrap();
+    assert_eq!(fit.report().rank(), 1);
+    assert!(fit.report().used_pseudo_inverse());
+    assert!(fit.report().is_degraded());
+    // the minimum norm solution splits the mean evenly
+    for &coefficient in fit.coefficients().elements() {
+        assert!((coefficient - 2.0).abs() < 1e-9);
+    }
+    for &residual in fit.residuals() {
+        assert!(residual.abs() <= 2.0 + 1e-9);
+    }
+
+    // high degree polynomials on a wide range of inputs are badly conditioned
+    let points = (0..12)
+        .map(|x| (x as f64 * 10.0, x as f64))
+        .collect::<Vec<_>>();
+    let wide = DataSet::<f64>::try_from(points.as_slice()).unwrap();
+    assert!(wide.polynomial_fit(6).unwrap().report().is_degraded());
+}
+
+#[test]
+fn canonical_string_round_trip() {
+    let matrix = Matrix::<f64>::try_from([
+        [0.1, -2.5, 1e300], //
+        [f64::MIN_POSITIVE / 3.0, -0.0, 0.0],
+        [f64::INFINITY, f64::NEG_INFINITY, 1.0 / 3.0],
+    ])
+    .unwrap();
+    let canonical = matrix.to_canonical_string();
+    assert!(canonical.starts_with("3x3\n"));
+    assert_eq!(
+        canonical.lines().nth(2).unwrap().split(' ').nth(1),
+        Some("-0x0p0")
+    );
+
+    let parsed = Matrix::<f64>::from_canonical_string(&canonical).unwrap();
+    for (index, &element) in matrix.elements_enumerated() {
+        assert_eq!(element.to_bits(), parsed[index].to_bits());
+    }
+    assert_eq!(parsed.to_canonical_string(), canonical);
+
+    let halves = Matrix::<f32>::try_from([[1.5, -0.75], [f32::NAN, 8.0]]).unwrap();
+    assert_eq!(
+        halves.to_canonical_string(),
+        "2x2\n0x3p-1 -0x3p-2\nnan 0x1p3\n"
+    );
+    assert!(
+        Matrix::<f32>::from_canonical_string(&halves.to_canonical_string()).unwrap()[1][0].is_nan()
+    );
+
+    for invalid in [
+        "",
+        "0x2\n",
+        "2x1\n0x1p0\n",
+        "1x2\n0x1p0\n",
+        "1x1\n0x2p0\n",
+        "1x1\n1.0\n",
+        "1x1\n0x1p0\n0x1p0\n",
+        "1x1\n0x1p2000\n",
+    ] {
+        assert!(
+            Matrix::<f64>::from_canonical_string(invalid).is_err(),
+            "{invalid:?}"
+        );
+    }
+    // fits in an f64 but not an f32
+    assert!(Matrix::<f32>::from_canonical_string("1x1\n0x1p200\n").is_err());
+}
+
+#[test]
+fn flop_counting() {
+    let a = Matrix::<f64>::try_from([
+        [4.0, 1.0, 2.0], //
+        [1.0, 5.0, 3.0],
+        [2.0, 3.0, 6.0],
+    ])
+    .unwrap()
+    .cast::<Counted<f64>>();
+    let b = a.clone();
+
+    // n³ multiplications and n²(n - 1) additions
+    let (product, report) = count_flops(|| a.matrix_multiply(&b).unwrap());
+    assert_eq!(report.multiplications(), 27);
+    assert_eq!(report.additions(), 27);
+    assert_eq!(report.divisions(), 0);
+    assert_eq!(report.total(), 54);
+    assert_eq!(product[0][0].value(), 21.0);
+
+    // LU without row swaps does one division per eliminated element
+    let (_, lu) = count_flops(|| a.lu().unwrap());
+    assert_eq!(lu.divisions(), 3);
+    assert_eq!(lu.multiplications(), 5);
+
+    // a square root per diagonal element plus one for the symmetry tolerance
+    let (_, cholesky) = count_flops(|| a.cholesky().unwrap());
+    assert_eq!(cholesky.functions(), 4);
+
+    // counting is scoped to the closure
+    let (_, nothing) = count_flops(|| a.transpose());
+    assert_eq!(nothing, FlopReport::default());
+
+    // an outer count includes the operations of a nested count
+    let ((_, inner), outer) = count_flops(|| {
+        let _ = a.cholesky().unwrap();
+        count_flops(|| a.lu().unwrap())
+    });
+    assert_eq!(inner, lu);
+    assert_eq!(outer, cholesky + lu);
+}
+
+#[test]
+fn interval_arithmetic() {
+    let tenth = Interval::point(0.1);
+    let sum = tenth + Interval::point(0.2);
+    assert!(sum.contains(0.1 + 0.2));
+    assert!(sum.lower() < sum.upper());
+
+    let a = Interval::new(-1.0, 2.0).unwrap();
+    let b = Interval::new(3.0, 4.0).unwrap();
+    let product = a * b;
+    assert!(product.lower() <= -4.0 && product.upper() >= 8.0);
+    assert!(product.width() < 12.0 + 1e-12);
+    assert!((-b).contains(-3.5));
+    assert_eq!(b / a, Interval::entire());
+    assert!((a / b).contains(-1.0 / 3.0) && (a / b).contains(2.0 / 3.0));
+    assert!(Interval::new(2.0, 1.0).is_none());
+    assert!(a.partial_cmp(&b) == Some(std::cmp::Ordering::Less));
+    assert!(a.partial_cmp(&product).is_none());
+
+    // the exact determinant -2 is enclosed
+    let points = Matrix::<f64>::try_from([
+        [1.0, 2.0], //
+        [3.0, 4.0],
+    ])
+    .unwrap();
+    let intervals = Matrix::from_points(&points);
+    assert!(intervals.determinant().unwrap().contains(-2.0));
+}
+
+#[test]
+fn interval_gaussian_elimination() {
+    let a = Matrix::<f64>::try_from([
+        [4.0, 1.0, 0.0], //
+        [1.0, 3.0, 1.0],
+        [0.0, 1.0, 2.0],
+    ])
+    .unwrap();
+    let b = Matrix::<f64>::try_from([[1.0], [2.0], [3.0]]).unwrap();
+    let exact = a.lu().unwrap().solve(&b).unwrap();
+
+    let enclosure = Matrix::from_points(&a)
+        .interval_solve(&Matrix::from_points(&b))
+        .unwrap();
+    for (index, interval) in enclosure.elements_enumerated() {
+        assert!(interval.contains(exact[index]));
+        assert!(interval.width() < 1e-12);
+    }
+
+    // uncertainty in the matrix widens the enclosure of every point system
+    let uncertain = a.map(|&element| Interval::with_radius(element, 0.01));
+    let enclosure = uncertain.interval_solve(&Matrix::from_points(&b)).unwrap();
+    let perturbed = a.map(|&element| element + 0.01);
+    let perturbed_solution = perturbed.lu().unwrap().solve(&b).unwrap();
+    for (index, interval) in enclosure.elements_enumerated() {
+        assert!(interval.contains(exact[index]));
+        assert!(interval.contains(perturbed_solution[index]));
+        assert!(interval.width() > 1e-4);
+    }
+    assert_eq!(enclosure.midpoints().height(), 3);
+
+    let singular = Matrix::from_points(
+        &Matrix::<f64>::try_from([
+            [1.0, 2.0], //
+            [2.0, 4.0],
+        ])
+        .unwrap(),
+    );
+    let rhs = Matrix::from_points(&Matrix::<f64>::try_from([[1.0], [2.0]]).unwrap());
+    assert!(matches!(
+        singular.interval_solve(&rhs),
+        Err(MatrixError::Decomposition(DecompositionError::Singular))
+    ));
+    assert!(uncertain.interval_solve(&rhs).is_err());
+}
+
+#[test]
+fn dual_number_derivatives() {
+    use num::Float;
+
+    let x = 0.7_f64;
+    let slope = derivative(|x| x.sin() * x.exp(), x);
+    assert!((slope - (x.cos() + x.sin()) * x.exp()).abs() < 1e-12);
+    let slope = derivative(|x| x.powi(3).sqrt() / x.ln_1p(), x);
+    let expected = (1.5 * x.sqrt() * x.ln_1p() - x.powf(1.5) / (1.0 + x)) / x.ln_1p().powi(2);
+    assert!((slope - expected).abs() < 1e-12);
+
+    // the x⁰ intercept column stays differentiable at 0 and extreme exponents do not overflow
+    assert_eq!(derivative(|x| x.powi(0), 0.0), 0.0);
+    assert_eq!(derivative(|x| x.powi(1), 0.0), 1.0);
+    assert_eq!(derivative(|x| x.powi(2), 0.0), 0.0);
+    assert_eq!(derivative(|x| x.powi(3), 2.0), 12.0);
+    assert_eq!(derivative(|x| x.powi(i32::MIN), 1.0), i32::MIN as f64);
+
+    // Jacobi's formula: d/dt det(A + tB) == det(A) * trace(A⁻¹B) at t == 0
+    let a = Matrix::<f64>::try_from([
+        [2.0, 1.0, 0.0], //
+        [1.0, 3.0, 1.0],
+        [0.0, 1.0, 4.0],
+    ])
+    .unwrap();
+    let b = Matrix::<f64>::try_from([
+        [1.0, 0.0, 2.0], //
+        [0.0, 1.0, 0.0],
+        [1.0, 0.0, 1.0],
+    ])
+    .unwrap();
+    let mut perturbed = a.cast::<Dual<f64>>();
+    for (index, element) in perturbed.elements_mut_enumerated() {
+        *element = Dual::new(element.value(), b[index]);
+    }
+    let determinant = perturbed.determinant().unwrap();
+    let a_inverse_b = a.lu().unwrap().solve(&b).unwrap();
+    let expected = a.determinant().unwrap() * a_inverse_b.trace();
+    assert!((determinant.value() - a.determinant().unwrap()).abs() < 1e-12);
+    assert!((determinant.derivative() - expected).abs() < 1e-9);
+}
+
+#[test]
+fn dual_number_regression_gradient() {
+    let data = DataSet::<f64>::try_from([(0.0, 1.0), (1.0, 3.0), (2.0, 4.0), (3.0, 8.0)]).unwrap();
+    let coefficients = [0.5, 1.5];
+
+    // ∇ Σ(Xβ - y)² == 2Xᵀ(Xβ - y)
+    let gradient = gradient(|beta| data.polynomial_loss(beta), &coefficients);
+    let normal = data.polynomial_normal_equations(1).unwrap();
+    let beta = Matrix::try_from([[0.5], [1.5]]).unwrap();
+    let xtx_beta = normal.xtx().matrix_multiply(&beta).unwrap();
+    for (i, &partial) in gradient.iter().enumerate() {
+        assert!((partial - 2.0 * (xtx_beta[i][0] - normal.xty()[i][0])).abs() < 1e-12);
+    }
+
+    // the gradient vanishes at the least squares solution
+    let fitted = data.polynomial_regression(1).unwrap();
+    let optimum = fitted.column(0).copied().collect::<Vec<_>>();
+    for partial in crate::gradient(|beta| data.polynomial_loss(beta), &optimum) {
+        assert!(partial.abs() < 1e-9);
+    }
+    assert!(data.polynomial_loss(&optimum) < data.polynomial_loss(&coefficients));
+}
+
+#[test]
+fn clone_only_elements() {
+    use num::{BigInt, BigRational};
+
+    let a = Matrix::<i64>::try_from([
+        [2, -1, 0, 3, 1], //
+        [1, 4, 2, 0, -2],
+        [0, 3, 5, 1, 1],
+        [7, 0, 1, 2, 0],
+        [1, 1, 1, 1, 6],
+    ])
+    .unwrap();
+    let big = a.to_bigrational();
+
+    // the operations only need `Clone` so arbitrary precision elements use the same code paths
+    assert_eq!(
+        big.determinant().unwrap(),
+        BigRational::from_integer(BigInt::from(a.determinant().unwrap()))
+    );
+    assert_eq!(big.transpose(), a.transpose().to_bigrational());
+    assert_eq!(
+        big.matrix_multiply(&big).unwrap(),
+        a.matrix_multiply(&a).unwrap().to_bigrational()
+    );
+    assert_eq!(
+        big.add(&big).unwrap(),
+        a.scalar_multiply(2).to_bigrational()
+    );
+    assert_eq!(
+        big.trace(),
+        BigRational::from_integer(BigInt::from(a.trace()))
+    );
+    assert_eq!(
+        big.polyval(&[
+            BigRational::from_integer(1.into()),
+            BigRational::from_integer((-2).into())
+        ])
+        .unwrap(),
+        a.polyval(&[1, -2]).unwrap().to_bigrational()
+    );
+
+    let small = big
+        .submatrix(
+            (0, 0),
+            std::num::NonZeroUsize::new(3).unwrap(),
+            std::num::NonZeroUsize::new(3).unwrap(),
+        )
+        .unwrap();
+    let small_integer = a
+        .submatrix(
+            (0, 0),
+            std::num::NonZeroUsize::new(3).unwrap(),
+            std::num::NonZeroUsize::new(3).unwrap(),
+        )
+        .unwrap();
+    assert_eq!(
+        small.cofactor_matrix().unwrap(),
+        small_integer.cofactor_matrix().unwrap().to_bigrational()
+    );
+    assert_eq!(
+        small.matrix_multiply(&small).unwrap(),
+        small_integer
+            .matrix_multiply(&small_integer)
+            .unwrap()
+            .to_bigrational()
+    );
+}
+
+#[test]
+fn transposed_view() {
+    let a = Matrix::<f64>::try_from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]).unwrap();
+    let view = a.transposed_view();
+    assert_eq!(view.shape(), a.shape().transpose());
+    assert_eq!(view[(2, 1)], 6.0);
+    assert_eq!(view.to_matrix(), a.transpose());
+
+    assert_eq!(
+        Matrix::product(&view, &a).unwrap(),
+        a.transpose().matrix_multiply(&a).unwrap()
+    );
+    assert_eq!(
+        Matrix::product(&a, &view).unwrap(),
+        a.matrix_multiply(&a.transpose()).unwrap()
+    );
+    assert!(Matrix::product(&view, &view).is_err());
+
+    let system = Matrix::<f64>::try_from([[2.0, 1.0], [1.0, 3.0]]).unwrap();
+    let b = Matrix::<f64>::try_from([[1.0, 2.0]]).unwrap();
+    let lu = system.lu().unwrap();
+    assert_eq!(
+        lu.solve(&b.transposed_view()).unwrap(),
+        lu.solve(&b.transpose()).unwrap()
+    );
+}
+
+#[test]
+fn gram_matrices() {
+    let a = Matrix::<f64>::try_from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]).unwrap();
+    assert_eq!(a.gram(), a.transpose().matrix_multiply(&a).unwrap());
+    assert_eq!(a.outer_gram(), a.matrix_multiply(&a.transpose()).unwrap());
+    assert_eq!(a.gram().width(), 3);
+    assert_eq!(a.outer_gram().width(), 2);
+
+    let integers = Matrix::<i32>::try_from([[1, -2], [3, 4], [0, 5]]).unwrap();
+    assert_eq!(
+        integers.gram(),
+        Matrix::<i32>::try_from([[10, 10], [10, 45]]).unwrap()
+    );
+}
+
+#[test]
+fn gemm_accumulates_in_place() {
+    let a = Matrix::<f64>::try_from([[1.0, 2.0], [3.0, 4.0]]).unwrap();
+    let b = Matrix::<f64>::try_from([[5.0, 6.0], [7.0, 8.0]]).unwrap();
+    let product = a.matrix_multiply(&b).unwrap();
+
+    let mut c = Matrix::<f64>::try_from([[1.0, 1.0], [1.0, 1.0]]).unwrap();
+    c.gemm(2.0, &a, &b, 3.0).unwrap();
+    let mut expected = product.scalar_multiply(2.0);
+    for (_, element) in expected.elements_mut_enumerated() {
+        *element += 3.0;
+    }
+    assert_eq!(c, expected);
+
+    let mut ignored = Matrix::<f64>::try_from([[f64::NAN, 0.0], [0.0, f64::NAN]]).unwrap();
+    ignored.gemm(1.0, &a, &b, 0.0).unwrap();
+    assert_eq!(ignored, product);
+
+    c.gemm(1.0, &a.transposed_view(), &b, 0.0).unwrap();
+    assert_eq!(c, a.transpose().matrix_multiply(&b).unwrap());
+
+    let mut wrong = Matrix::<f64>::try_from([[0.0, 0.0]]).unwrap();
+    assert!(wrong.gemm(1.0, &a, &b, 0.0).is_err());
+}
+
+#[test]
+fn slice_kernels() {
+    let x = [1.0, 2.0, 3.0];
+    let mut y = [4.0, 5.0, 6.0];
+    assert_eq!(crate::kernels::dot(&x, &y).unwrap(), 32.0);
+
+    crate::kernels::axpy(2.0, &x, &mut y).unwrap();
+    assert_eq!(y, [6.0, 9.0, 12.0]);
+
+    crate::kernels::scale(0.5, &mut y);
+    assert_eq!(y, [3.0, 4.5, 6.0]);
+
+    assert_eq!(crate::kernels::dot(&[1, 2], &[3, 4]).unwrap(), 11);
+
+    // different lengths are an error rather than a panic
+    assert!(crate::kernels::dot(&[1.0, 2.0], &[3.0]).is_err());
+    assert!(crate::kernels::axpy(1.0, &[1.0, 2.0], &mut y).is_err());
+    assert_eq!(y, [3.0, 4.5, 6.0]);
+}
+
+#[test]
+fn chained_multiplication() {
+    let a = Matrix::<i64>::try_from([[1, 2, 3]]).unwrap();
+    let b = Matrix::<i64>::try_from([[1, 0], [2, 1], [0, 3]]).unwrap();
+    let c = Matrix::<i64>::try_from([[4, 1, 0, 2], [1, 1, 1, 1]]).unwrap();
+    let d = Matrix::<i64>::try_from([[1], [2], [3], [4]]).unwrap();
+
+    let left_to_right = a
+        .matrix_multiply(&b)
+        .unwrap()
+        .matrix_multiply(&c)
+        .unwrap()
+        .matrix_multiply(&d)
+        .unwrap();
+    assert_eq!(
+        Matrix::multi_multiply(&[&a, &b, &c, &d]).unwrap(),
+        left_to_right
+    );
+    assert_eq!(Matrix::multi_multiply(&[&b]).unwrap(), b);
+
+    assert!(Matrix::<i64>::multi_multiply(&[]).is_err());
+    assert!(Matrix::multi_multiply(&[&a, &c]).is_err());
+}
+
+#[test]
+fn select_rows_and_columns() {
+    let a = Matrix::<i32>::try_from([[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]]).unwrap();
+
+    assert_eq!(
+        a.select_rows((0..a.height()).step_by(2)).unwrap(),
+        Matrix::try_from([[1, 2, 3, 4], [9, 10, 11, 12]]).unwrap()
+    );
+    assert_eq!(
+        a.select_rows([2, 2]).unwrap(),
+        Matrix::try_from([[9, 10, 11, 12], [9, 10, 11, 12]]).unwrap()
+    );
+    assert_eq!(
+        a.select_columns((1..a.width()).step_by(2)).unwrap(),
+        Matrix::try_from([[2, 4], [6, 8], [10, 12]]).unwrap()
+    );
+    assert_eq!(
+        a.select_columns([3, 0]).unwrap(),
+        Matrix::try_from([[4, 1], [8, 5], [12, 9]]).unwrap()
+    );
+
+    assert!(a.select_rows([3]).is_err());
+    assert!(a.select_columns([]).is_err());
+}
+
+#[test]
+fn masked_selection_and_assignment() {
+    let mut a = Matrix::<f64>::try_from([[1.0, -2.0], [-3.0, 4.0]]).unwrap();
+
+    let (indices, values) = a.select_where(|&e| e < 0.0);
+    assert_eq!(indices, vec![MatrixIndex::from((0, 1)), (1, 0).into()]);
+    assert_eq!(values, vec![-2.0, -3.0]);
+
+    assert_eq!(a.assign_where(|&e: &f64| e < 0.0, 0.0).unwrap(), 2);
+    assert_eq!(a, Matrix::try_from([[1.0, 0.0], [0.0, 4.0]]).unwrap());
+
+    let mask = Matrix::try_from([[true, false], [false, true]]).unwrap();
+    assert_eq!(a.assign_where(&mask, 9.0).unwrap(), 2);
+    assert_eq!(a, Matrix::try_from([[9.0, 0.0], [0.0, 9.0]]).unwrap());
+
+    let wrong_shape = Matrix::try_from([[true, false]]).unwrap();
+    assert!(a.assign_where(&wrong_shape, 1.0).is_err());
+}
+
+#[test]
+fn sort_rows() {
+    let mut a = Matrix::<f64>::try_from([[3.0, 1.0], [1.0, 2.0], [2.0, 3.0], [1.0, 0.0]]).unwrap();
+
+    a.sort_rows_by_column(0, SortDirection::Ascending).unwrap();
+    assert_eq!(
+        a,
+        Matrix::try_from([[1.0, 2.0], [1.0, 0.0], [2.0, 3.0], [3.0, 1.0]]).unwrap()
+    );
+
+    a.sort_rows_by_column(1, SortDirection::Descending).unwrap();
+    assert_eq!(
+        a,
+        Matrix::try_from([[2.0, 3.0], [1.0, 2.0], [3.0, 1.0], [1.0, 0.0]]).unwrap()
+    );
+
+    a.sort_rows_by(|row| row[0] + row[1]);
+    assert_eq!(
+        a,
+        Matrix::try_from([[1.0, 0.0], [1.0, 2.0], [3.0, 1.0], [2.0, 3.0]]).unwrap()
+    );
+
+    assert!(a.sort_rows_by_column(2, SortDirection::Ascending).is_err());
+}
+
+#[test]
+fn sort_rows_with_nan() {
+    // more than 20 rows so the standard sort checks that the comparison is a total order
+    let rows: [[f64; 2]; 30] = std::array::from_fn(|i| {
+        let key = if i % 4 == 0 {
+            f64::NAN
+        } else {
+            ((i * 7) % 30) as f64
+        };
+        [key, i as f64]
+    });
+    let nan_count = rows.iter().filter(|row| row[0].is_nan()).count();
+
+    for direction in [SortDirection::Ascending, SortDirection::Descending] {
+        let mut a = Matrix::try_from(rows).unwrap();
+        a.sort_rows_by_column(0, direction).unwrap();
+        let keys = a.column(0).copied().collect::<Vec<_>>();
+        let (numbers, nans) = keys.split_at(keys.len() - nan_count);
+        assert!(nans.iter().all(|key| key.is_nan()));
+        assert!(numbers.windows(2).all(|pair| match direction {
+            SortDirection::Ascending => pair[0] <= pair[1],
+            SortDirection::Descending => pair[0] >= pair[1],
+        }));
+        // stable, so the NaN rows keep their original order
+        let nan_rows = a.rows().skip(numbers.len()).map(|row| row[1]);
+        assert!(nan_rows
+            .collect::<Vec<_>>()
+            .windows(2)
+            .all(|pair| pair[0] < pair[1]));
+    }
+
+    let mut a = Matrix::try_from(rows).unwrap();
+    a.sort_rows_by(|row| row[0]);
+    assert!(a.rows().skip(30 - nan_count).all(|row| row[0].is_nan()));
+}
+
+#[test]
+fn unique_and_dedup_rows() {
+    let mut a = Matrix::<i32>::try_from([[1, 0], [1, 0], [0, 1], [1, 0], [0, 1], [0, 1]]).unwrap();
+    assert_eq!(a.unique_rows(), Matrix::try_from([[1, 0], [0, 1]]).unwrap());
+    a.dedup_rows();
+    assert_eq!(
+        a,
+        Matrix::try_from([[1, 0], [0, 1], [1, 0], [0, 1]]).unwrap()
+    );
+
+    let mut points =
+        Matrix::<f64>::try_from([[0.0, 1.0], [1e-9, 1.0], [2.0, 2.0], [0.0, 1.0 - 1e-9]]).unwrap();
+    assert_eq!(
+        points.unique_rows_within(1e-6),
+        Matrix::try_from([[0.0, 1.0], [2.0, 2.0]]).unwrap()
+    );
+    assert_eq!(points.unique_rows().height(), 4);
+    points.dedup_rows_within(1e-6);
+    assert_eq!(points.height(), 3);
+}
+
+#[test]
+fn find_positions_and_count() {
+    let a = Matrix::<i32>::try_from([[1, 4, 2], [8, 3, 6]]).unwrap();
+
+    assert_eq!(a.find(|&e| e > 3), Some((0, 1).into()));
+    assert_eq!(a.find(|&e| e > 10), None);
+    assert_eq!(
+        a.positions(|&e| e % 2 == 0).collect::<Vec<_>>(),
+        vec![
+            MatrixIndex::from((0, 1)),
+            (0, 2).into(),
+            (1, 0).into(),
+            (1, 2).into()
+        ]
+    );
+    assert_eq!(a.count_where(|&e| e % 2 == 1), 2);
+}
+
+#[test]
+fn broadcasting() {
+    let a = Matrix::<i32>::try_from([[1, 2, 3], [4, 5, 6]]).unwrap();
+    let row = RowVector::try_from([10, 20, 30]).unwrap();
+    let column = ColumnVector::try_from([2, -1]).unwrap();
+
+    assert_eq!(
+        a.add_row_vector(&row).unwrap(),
+        Matrix::try_from([[11, 22, 33], [14, 25, 36]]).unwrap()
+    );
+    assert_eq!(
+        a.sub_row_vector(&row).unwrap(),
+        Matrix::try_from([[-9, -18, -27], [-6, -15, -24]]).unwrap()
+    );
+    assert_eq!(
+        a.scale_rows(&column).unwrap(),
+        Matrix::try_from([[2, 4, 6], [-4, -5, -6]]).unwrap()
+    );
+    assert_eq!(
+        a.broadcast_op(Axis::Column, &[1, 2], |e, v| e.max(v * 3))
+            .unwrap(),
+        Matrix::try_from([[3, 3, 3], [6, 6, 6]]).unwrap()
+    );
+
+    assert!(a
+        .add_column_vector(&ColumnVector::try_from([1, 2, 3]).unwrap())
+        .is_err());
+    assert!(a
+        .scale_columns(&RowVector::try_from([1, 2]).unwrap())
+        .is_err());
+}
+
+#[test]
+fn elementwise_comparisons() {
+    let a = Matrix::<f64>::try_from([[1.0, 2.0], [3.0, f64::NAN]]).unwrap();
+    let b = Matrix::<f64>::try_from([[1.0, 3.0], [2.0, 0.0]]).unwrap();
+
+    assert_eq!(
+        a.eq_elementwise(&b).unwrap(),
+        Matrix::try_from([[true, false], [false, false]]).unwrap()
+    );
+    assert_eq!(
+        a.lt_elementwise(&b).unwrap(),
+        Matrix::try_from([[false, true], [false, false]]).unwrap()
+    );
+    assert_eq!(
+        a.ge_elementwise(&b).unwrap(),
+        Matrix::try_from([[true, false], [true, false]]).unwrap()
+    );
+    assert!(a.ne_elementwise(&b).unwrap().any());
+    assert!(!a.le_elementwise(&b).unwrap().all());
+    assert!(a.eq_elementwise(&a).unwrap().any());
+    assert!(!a.eq_elementwise(&a).unwrap().all());
+
+    let mut c = b.clone();
+    let mask = a.gt_elementwise(&b).unwrap();
+    assert_eq!(c.assign_where(&mask, -1.0).unwrap(), 1);
+    assert_eq!(c, Matrix::try_from([[1.0, 3.0], [-1.0, 0.0]]).unwrap());
+
+    let wrong = Matrix::<f64>::try_from([[1.0, 2.0]]).unwrap();
+    assert!(a.eq_elementwise(&wrong).is_err());
+}
+
+#[test]
+fn abs_clamp_and_round() {
+    let a = Matrix::<f64>::try_from([[-1.25, 2.5], [0.123456, -1234.5]]).unwrap();
+
+    assert_eq!(
+        a.abs(),
+        Matrix::try_from([[1.25, 2.5], [0.123456, 1234.5]]).unwrap()
+    );
+    assert_eq!(
+        a.clamp(-1.0, 1.0),
+        Matrix::try_from([[-1.0, 1.0], [0.123456, -1.0]]).unwrap()
+    );
+    assert_eq!(
+        a.round_to(1),
+        Matrix::try_from([[-1.3, 2.5], [0.1, -1234.5]]).unwrap()
+    );
+    assert_eq!(
+        a.round_to(-2),
+        Matrix::try_from([[0.0, 0.0], [0.0, -1200.0]]).unwrap()
+    );
+
+    // 10⁴⁰⁰ and 1e300 * 10²⁰ overflow, the elements are kept as they are
+    let extreme = Matrix::<f64>::try_from([[1.5, 1e300]]).unwrap();
+    assert_eq!(extreme.round_to(400), extreme);
+    assert_eq!(
+        extreme.round_to(20),
+        Matrix::try_from([[1.5, 1e300]]).unwrap()
+    );
+}
+
+#[test]
+fn u8_scaling() {
+    let a = Matrix::<f64>::try_from([[-1.0, 0.0, 0.5], [1.0, 2.0, f64::NAN]]).unwrap();
+    let bytes = a.to_u8_scaled(0.0, 1.0);
+    assert_eq!(
+        bytes,
+        Matrix::try_from([[0, 0, 128], [255, 255, 0]]).unwrap()
+    );
+
+    let restored = Matrix::<f64>::from_u8_scaled(&bytes, 0.0, 1.0);
+    assert_eq!(restored[(1, 0)], 1.0);
+    assert!((restored[(0, 2)] - 0.5).abs() <= 0.5 / 255.0);
+}
+
+#[test]
+fn ascii_heatmap() {
+    let a = Matrix::<f64>::try_from([[0.0, -1.0, 0.5], [2.0, f64::NAN, 0.0]]).unwrap();
+    let palette = Palette::new(" .o@").unwrap();
+    assert_eq!(a.render_ascii(&palette), " o.\n@? \n");
+
+    let zeros = Matrix::<f64>::zeros(Shape {
+        rows: std::num::NonZeroUsize::new(1).unwrap(),
+        cols: std::num::NonZeroUsize::new(3).unwrap(),
+    });
+    assert_eq!(zeros.render_ascii(&Palette::default()), "   \n");
+
+    let colored = a.render_ascii(&palette.with_ansi_colors());
+    assert!(colored.contains("\x1b[38;5;255m@"));
+    assert!(colored.ends_with("\x1b[0m\n"));
+
+    assert!(Palette::new("").is_none());
+}
+
+#[test]
+fn sparsity_summary() {
+    let a = Matrix::<f64>::try_from([
+        [4.0, 1.0, 0.0, 0.0],
+        [1.0, 4.0, 1e-12, 0.0],
+        [0.0, 1.0, 4.0, 1.0],
+        [0.0, 0.0, 1.0, 4.0],
+    ])
+    .unwrap();
+
+    assert_eq!(a.sparsity(1e-9), 0.4375);
+    assert_eq!(a.sparsity(0.0), 0.375);
+    assert_eq!(a.nonzero_pattern(1e-9)[0], [true, true, false, false]);
+
+    let bandwidth = a.bandwidth(1e-9);
+    assert_eq!(bandwidth, Bandwidth { lower: 1, upper: 1 });
+    assert!(bandwidth.is_tridiagonal());
+    assert!(!bandwidth.is_diagonal());
+    assert_eq!(a.bandwidth(0.0).upper, 1);
+
+    let mut b = a.clone();
+    b[(3, 0)] = 2.0;
+    assert_eq!(b.bandwidth(1e-9), Bandwidth { lower: 3, upper: 1 });
+}
+
+#[test]
+fn matrix_summary() {
+    let a = Matrix::<f64>::try_from([[1.0, 10.0], [2.0, 20.0], [3.0, 60.0]]).unwrap();
+    let summary = a.summary().unwrap();
+
+    assert_eq!(
+        summary.columns()[0],
+        Statistics {
+            min: 1.0,
+            max: 3.0,
+            mean: 2.0,
+            std: 1.0
+        }
+    );
+    assert_eq!(summary.columns()[1].mean, 30.0);
+    assert_eq!(summary.overall().min, 1.0);
+    assert_eq!(summary.overall().max, 60.0);
+    assert_eq!(summary.overall().mean, 16.0);
+
+    let table = summary.to_string();
+    assert_eq!(table.lines().count(), 5);
+    assert!(table
+        .lines()
+        .nth(3)
+        .unwrap()
+        .trim_start()
+        .starts_with("mean"));
+
+    assert!(Matrix::<f64>::try_from([[1.0, 2.0]])
+        .unwrap()
+        .summary()
+        .is_err());
+}
+
+#[test]
+fn csv_round_trip() {
+    let a = Matrix::<f64>::from_csv_str("1, 2.5\n\n -3,4 \n").unwrap();
+    assert_eq!(a, Matrix::try_from([[1.0, 2.5], [-3.0, 4.0]]).unwrap());
+    assert_eq!(a.to_csv_string(), "1, 2.5\n-3, 4\n");
+    assert_eq!(Matrix::<f64>::from_csv_str(&a.to_csv_string()).unwrap(), a);
+
+    let ragged = Matrix::<f64>::from_csv_str("1, 2\n3\n").unwrap_err();
+    assert_eq!(ragged.line_number(), 2);
+    let invalid = Matrix::<i32>::from_csv_str("1, x\n").unwrap_err();
+    assert!(matches!(
+        invalid.kind(),
+        ParseMatrixErrorKind::InvalidElement(element) if element == "x"
+    ));
+    assert!(Matrix::<f64>::from_csv_str("\n\n").is_err());
+}
+
+#[test]
+fn matrix_expressions() {
+    let a = Matrix::<f64>::try_from([[1.0, 2.0], [3.0, 4.0]]).unwrap();
+    let b = Matrix::<f64>::try_from([[0.0, 1.0], [1.0, 0.0]]).unwrap();
+    let bindings = std::collections::HashMap::from([
+        ("A".to_string(), a.clone()),
+        ("B".to_string(), b.clone()),
+    ]);
+
+    let expected = a
+        .matrix_multiply(&b.transpose())
+        .unwrap()
+        .add(&Matrix::identity(a.height_nonzero()).scalar_multiply(2.0))
+        .unwrap();
+    assert_eq!(
+        parse_matrix_expression("A * B' + 2*I", &bindings).unwrap(),
+        ExpressionValue::Matrix(expected)
+    );
+    assert_eq!(
+        parse_matrix_expression("-(A - A) * 3", &bindings).unwrap(),
+        ExpressionValue::Matrix(a.scalar_multiply(0.0).scalar_multiply(-3.0))
+    );
+    assert_eq!(
+        parse_matrix_expression("det(A) * 2 + 1", &bindings)
+            .unwrap()
+            .as_scalar(),
+        Some(-3.0)
+    );
+    assert_eq!(
+        parse_matrix_expression("I * inv(2 * I) * A", &bindings).unwrap(),
+        ExpressionValue::Matrix(a.scalar_multiply(0.5))
+    );
+
+    let tree = Expression::<f64>::parse("t(A)''").unwrap();
+    assert_eq!(
+        tree,
+        Expression::Transpose(Box::new(Expression::Transpose(Box::new(Expression::Call(
+            Function::Transpose,
+            Box::new(Expression::Variable("A".to_string()))
+        )))))
+    );
+
+    assert!(matches!(
+        parse_matrix_expression("A + 1", &bindings),
+        Err(ExpressionError::ScalarMatrixAddition)
+    ));
+    assert!(matches!(
+        parse_matrix_expression("2 * I", &bindings),
+        Err(ExpressionError::UnsizedIdentity)
+    ));
+    assert!(matches!(
+        parse_matrix_expression("C", &bindings),
+        Err(ExpressionError::Undefined(name)) if name == "C"
+    ));
+    assert!(matches!(
+        parse_matrix_expression("exp(A)", &bindings),
+        Err(ExpressionError::UnknownFunction(_))
+    ));
+    assert!(matches!(
+        parse_matrix_expression("A * # B", &bindings),
+        Err(ExpressionError::UnexpectedCharacter {
+            character: '#',
+            position: 4
+        })
+    ));
+    assert!(matches!(
+        parse_matrix_expression("(A", &bindings),
+        Err(ExpressionError::UnexpectedEnd)
+    ));
+    assert!(matches!(
+        parse_matrix_expression("A * B B", &bindings),
+        Err(ExpressionError::UnexpectedToken { position: 6, .. })
+    ));
+    assert!(matches!(
+        parse_matrix_expression(
+            "A * Matrix",
+            &std::collections::HashMap::from([
+                ("A".to_string(), a.clone()),
+                (
+                    "Matrix".to_string(),
+                    Matrix::try_from([[1.0, 2.0]]).unwrap()
+                )
+            ])
+        ),
+        Err(ExpressionError::Matrix(_))
+    ));
+}
+
+#[test]
+fn deeply_nested_expressions() {
+    let bindings = std::collections::HashMap::<String, Matrix<f64>>::new();
+
+    let minuses = "-".repeat(200_000) + "1";
+    assert!(matches!(
+        parse_matrix_expression(&minuses, &bindings),
+        Err(ExpressionError::TooDeeplyNested {
+            limit: 256,
+            position: 256
+        })
+    ));
+    let parentheses = "(".repeat(200_000) + "1" + &")".repeat(200_000);
+    assert!(matches!(
+        parse_matrix_expression(&parentheses, &bindings),
+        Err(ExpressionError::TooDeeplyNested { limit: 256, .. })
+    ));
+    let calls = "det(".repeat(1_000) + "1" + &")".repeat(1_000);
+    assert!(matches!(
+        parse_matrix_expression(&calls, &bindings),
+        Err(ExpressionError::TooDeeplyNested { limit: 256, .. })
+    ));
+
+    let within_limit = "-(".repeat(100) + "2" + &")".repeat(100);
+    assert_eq!(
+        parse_matrix_expression(&within_limit, &bindings)
+            .unwrap()
+            .as_scalar(),
+        Some(2.0)
+    );
+    let sum = vec!["1"; 100].join(" + ");
+    assert_eq!(
+        parse_matrix_expression(&sum, &bindings)
+            .unwrap()
+            .as_scalar(),
+        Some(100.0)
+    );
+    let long_sum = vec!["1"; 100_000].join(" + ");
+    assert!(matches!(
+        parse_matrix_expression(&long_sum, &bindings),
+        Err(ExpressionError::TooDeeplyNested { limit: 256, .. })
+    ));
+    let transposes = "1".to_string() + &"'".repeat(100_000);
+    assert!(matches!(
+        parse_matrix_expression(&transposes, &bindings),
+        Err(ExpressionError::TooDeeplyNested { limit: 256, .. })
+    ));
+}
+
+#[test]
+fn workspace_round_trip() {
+    let a = Matrix::<f64>::try_from([[1.0, 2.5], [-3.0, 4.0]]).unwrap();
+    let data = DataSet::<f64>::try_from([(0.0, 1.0), (1.0, 3.0), (2.0, 4.5)]).unwrap();
+
+    let mut workspace = Workspace::new();
+    assert!(workspace.is_empty());
+    assert_eq!(workspace.insert_matrix("A", a.clone()).unwrap(), None);
+    workspace.insert_data_set("points_1", data).unwrap();
+    assert!(matches!(
+        workspace.insert_matrix("not valid", a.clone()),
+        Err(WorkspaceError::InvalidName(_))
+    ));
+    assert!(matches!(
+        workspace.insert_matrix("../escape", a.clone()),
+        Err(WorkspaceError::InvalidName(_))
+    ));
+
+    let product = parse_matrix_expression("A * A'", &workspace).unwrap();
+    assert_eq!(
+        product.as_matrix(),
+        Some(&a.matrix_multiply(&a.transpose()).unwrap())
+    );
+
+    let directory = std::env::temp_dir().join(format!("workspace_{}", std::process::id()));
+    workspace.save(&directory).unwrap();
+    let loaded = Workspace::<f64>::load(&directory).unwrap();
+    std::fs::remove_dir_all(&directory).unwrap();
+    assert_eq!(loaded, workspace);
+    assert_eq!(
+        loaded.matrices().map(|(name, _)| name).collect::<Vec<_>>(),
+        ["A"]
+    );
+
+    assert!(Workspace::<f64>::load(&directory).unwrap().is_empty());
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn seeded_generators() {
+    let size = std::num::NonZeroUsize::new(5).unwrap();
+
+    let q = Matrix::<f64>::random_orthogonal(size, 3);
+    assert_eq!(q, Matrix::random_orthogonal(size, 3));
+    assert_ne!(q, Matrix::random_orthogonal(size, 4));
+    let identity = q.gram().round_to(10);
+    assert_eq!(identity, Matrix::identity(size));
+
+    for (spd, condition_number) in [
+        (Matrix::<f64>::well_conditioned_spd(size, 11), 1e1),
+        (Matrix::<f64>::ill_conditioned_spd(size, 11), 1e10),
+    ] {
+        assert_eq!(spd, spd.transpose());
+        let eigenvalues = spd.symmetric_eigen().unwrap();
+        let eigenvalues = eigenvalues.eigenvalues();
+        assert!((eigenvalues[0] - 1.0).abs() < 1e-6);
+        let ratio = eigenvalues[eigenvalues.len() - 1] / eigenvalues[0];
+        assert!((ratio / condition_number - 1.0).abs() < 1e-6);
+    }
+
+    let count = std::num::NonZeroUsize::new(20).unwrap();
+    let exact = DataSet::synthetic_polynomial(&[1.0, -2.0, 0.5], 0.0, count, 1);
+    assert_eq!(exact.data()[0].input(), &-1.0);
+    assert_eq!(exact.data()[0].output(), &3.5);
+    let fit = exact.polynomial_fit(2).unwrap();
+    assert_eq!(
+        fit.coefficients().round_to(8),
+        Matrix::try_from([[1.0], [-2.0], [0.5]]).unwrap()
+    );
+
+    let noisy = DataSet::synthetic_polynomial(&[1.0, -2.0, 0.5], 0.1, count, 1);
+    assert_eq!(
+        noisy,
+        DataSet::synthetic_polynomial(&[1.0, -2.0, 0.5], 0.1, count, 1)
+    );
+    assert_ne!(noisy, exact);
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn synthetic_noise_models() {
+    let count = std::num::NonZeroUsize::new(201).unwrap();
+    let coefficients = [1.0, 2.0];
+    let residuals = |noise: &NoiseModel<f64>| {
+        DataSet::synthetic_polynomial_with_noise(&coefficients, noise, count, 9)
+            .data()
+            .iter()
+            .map(|point| point.output() - (1.0 + 2.0 * point.input()))
+            .collect::<Vec<_>>()
+    };
+
+    assert!(residuals(&NoiseModel::None).iter().all(|&r| r == 0.0));
+
+    let uniform = residuals(&NoiseModel::Uniform { half_width: 0.5 });
+    assert!(uniform.iter().all(|r| r.abs() <= 0.5));
+
+    // the noise vanishes at x == 0, the middle point
+    let heteroscedastic = residuals(&NoiseModel::Heteroscedastic { scale: 1.0 });
+    assert_eq!(heteroscedastic[100], 0.0);
+
+    let contaminated = residuals(&NoiseModel::Contaminated {
+        base: Box::new(NoiseModel::Uniform { half_width: 0.1 }),
+        rate: 0.1,
+        magnitude: 100.0,
+    });
+    let outliers = contaminated.iter().filter(|r| r.abs() > 0.1).count();
+    assert!((5..=40).contains(&outliers));
+
+    let gaussian = NoiseModel::Gaussian {
+        standard_deviation: 0.1,
+    };
+    assert_eq!(
+        DataSet::synthetic_polynomial_with_noise(&coefficients, &gaussian, count, 9),
+        DataSet::synthetic_polynomial(&coefficients, 0.1, count, 9)
+    );
+}
+
+#[test]
+fn timestamps() {
+    let parse = |s: &str| s.parse::<Timestamp>().unwrap().unix_seconds();
+    assert_eq!(parse("1970-01-01"), 0);
+    assert_eq!(parse("1706708700"), 1_706_708_700);
+    assert_eq!(parse("2024-01-31T13:45:00Z"), 1_706_708_700);
+    assert_eq!(parse("2024-01-31 13:45:00"), 1_706_708_700);
+    assert_eq!(parse("2000-03-01"), 951_868_800);
+    assert_eq!(parse("1969-12-31T23:59:59"), -1);
+
+    for invalid in [
+        "2023-02-29",
+        "2024-13-01",
+        "2024-01-31T24:00:00",
+        "yesterday",
+        "2024-01",
+        "9999999999999-01-01",
+        "-9999999999999-01-01",
+        "9223372036854775807-03-01",
+        "-9223372036854775807-01-01",
+        // 2³² + 1 would wrap to 1 if it were truncated to a u32
+        "2024-4294967297-01",
+        "2024-01-4294967297",
+    ] {
+        assert!(invalid.parse::<Timestamp>().is_err(), "{invalid}");
+    }
+
+    for seconds in [-1, 0, 951_868_800, 1_706_708_700, -62_135_596_800] {
+        let timestamp = Timestamp::from_unix_seconds(seconds);
+        assert_eq!(
+            timestamp.to_string().parse::<Timestamp>().unwrap(),
+            timestamp
+        );
+    }
+    assert_eq!(
+        Timestamp::from_unix_seconds(1_706_708_700).to_string(),
+        "2024-01-31T13:45:00Z"
+    );
+
+    let (earliest, latest) = (
+        Timestamp::from_unix_seconds(i64::MIN),
+        Timestamp::from_unix_seconds(i64::MAX),
+    );
+    assert_eq!(latest.seconds_since(latest), Some(0));
+    assert_eq!(earliest.seconds_since(latest), None);
+    assert_eq!(latest.seconds_since(earliest), None);
+    // the last day whose midnight fits, where adding the time of day overflows
+    let last_day = latest.to_string();
+    let last_midnight = last_day.split_once('T').unwrap().0;
+    assert!(last_midnight.parse::<Timestamp>().is_ok());
+    assert!(format!("{last_midnight}T23:59:59")
+        .parse::<Timestamp>()
+        .is_err());
+}
+
+#[test]
+fn time_series_trend() {
+    let series = "2024-01-03, 7\n2024-01-01, 3\n2024-01-02T12:00:00Z, 6\n"
+        .parse::<TimeSeries<f64>>()
+        .unwrap();
+    assert_eq!(series.start(), "2024-01-01".parse().unwrap());
+
+    let offsets = series.to_offsets(TimeUnit::Days);
+    let inputs = offsets
+        .data()
+        .iter()
+        .map(|point| *point.input())
+        .collect::<Vec<_>>();
+    assert_eq!(inputs, [2.0, 0.0, 1.5]);
+
+    let fit = offsets.polynomial_fit(1).unwrap();
+    assert_eq!(
+        fit.coefficients().round_to(8),
+        Matrix::try_from([[3.0], [2.0]]).unwrap()
+    );
+
+    let hours = series.to_offsets_from("2024-01-02".parse().unwrap(), TimeUnit::Hours);
+    assert_eq!(*hours.data()[1].input(), -24.0);
+
+    let far = series.to_offsets_from(Timestamp::from_unix_seconds(i64::MIN), TimeUnit::Seconds);
+    assert!(far.data().iter().all(|point| *point.input() > 9.2e18));
+}
+
+#[test]
+fn solve_linear_system() {
+    let a = Matrix::<f64>::try_from([
+        [2.0, 1.0, -1.0], //
+        [-3.0, -1.0, 2.0],
+        [-2.0, 1.0, 2.0],
+    ])
+    .unwrap();
+    let b = Matrix::try_from([[8.0, -1.0], [-11.0, 2.0], [-3.0, 0.0]]).unwrap();
+
+    let x = a.solve(&b).unwrap();
+    assert_eq!(
+        x.round_to(10),
+        Matrix::try_from([[2.0, 2.0], [3.0, -2.0], [-1.0, 3.0]]).unwrap()
+    );
+
+    let singular = Matrix::<f64>::try_from([[1.0, 2.0], [2.0, 4.0]]).unwrap();
+    assert!(matches!(
+        singular.solve(&Matrix::try_from([[1.0], [2.0]]).unwrap()),
+        Err(MatrixError::Decomposition(_))
+    ));
+    assert!(matches!(
+        a.solve(&Matrix::try_from([[1.0], [2.0]]).unwrap()),
+        Err(MatrixError::Arithmetic { .. })
+    ));
+}
+
+#[test]
+fn lag_and_rolling_features() {
+    let mut outputs = vec![10.0];
+    for _ in 0..7 {
+        outputs.push(1.0 + 0.5 * outputs[outputs.len() - 1]);
+    }
+    let points = outputs
+        .iter()
+        .enumerate()
+        .map(|(index, &output)| (index as f64, output))
+        .collect::<Vec<_>>();
+    let data = DataSet::try_from(points.as_slice()).unwrap();
+
+    let fit = data.with_lags(&[1]).unwrap().fit().unwrap();
+    assert_eq!(
+        fit.coefficients().round_to(8),
+        Matrix::try_from([[1.0], [0.5]]).unwrap()
+    );
+
+    let length = std::num::NonZeroUsize::new(2).unwrap();
+    let features = data
+        .autoregressive_features(
+            &[1, 3],
+            &[
+                RollingWindow {
+                    length,
+                    aggregate: Aggregate::Mean,
+                },
+                RollingWindow {
+                    length,
+                    aggregate: Aggregate::StandardDeviation,
+                },
+            ],
+        )
+        .unwrap();
+    assert_eq!(
+        features.names(),
+        ["lag_1", "lag_3", "rolling_mean_2", "rolling_std_2"]
+    );
+    assert_eq!(features.inputs().height(), 5);
+    assert_eq!(features.inputs()[0], [4.0, 10.0, 5.0, 1.0]);
+    assert_eq!(features.outputs()[0], [3.0]);
+
+    assert!(data.with_lags(&[]).is_err());
+    assert!(data.with_lags(&[0]).is_err());
+    assert!(data.with_lags(&[8]).is_err());
+}
+
+#[test]
+fn segmented_regression() {
+    let regime = |x: f64| if x < 5.0 { x } else { 5.0 + 3.0 * (x - 5.0) };
+    let points = [0.0, 1.0, 2.0, 3.0, 4.0, 6.0, 7.0, 8.0, 9.0, 10.0].map(|x| (x, regime(x)));
+    let data = DataSet::<f64>::try_from(points).unwrap();
+
+    let fit = data.segmented_regression_search(1).unwrap();
+    assert_eq!(fit.breakpoints(), [5.0]);
+    assert!(fit.fit().residual_sum_of_squares() < 1e-16);
+    assert!((fit.predict(7.5) - regime(7.5)).abs() < 1e-8);
+
+    let round = |polynomial: Vec<f64>| {
+        polynomial
+            .into_iter()
+            .map(|c| (c * 1e8).round() / 1e8)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(round(fit.segment_polynomial(0)), [0.0, 1.0]);
+    assert_eq!(round(fit.segment_polynomial(1)), [-10.0, 3.0]);
+
+    // quadratic segments stay continuous at the breakpoints
+    let quadratic = data.segmented_regression(&[6.5, 3.0], 2).unwrap();
+    assert_eq!(quadratic.breakpoints(), [3.0, 6.5]);
+    for (segment, &breakpoint) in quadratic.breakpoints().iter().enumerate() {
+        let evaluate = |polynomial: Vec<f64>| {
+            polynomial
+                .iter()
+                .rev()
+                .fold(0.0, |sum, &c| sum * breakpoint + c)
+        };
+        let left = evaluate(quadratic.segment_polynomial(segment));
+        let right = evaluate(quadratic.segment_polynomial(segment + 1));
+        assert!((left - right).abs() < 1e-8);
+        assert!((quadratic.predict(breakpoint) - left).abs() < 1e-8);
+    }
+
+    assert!(data.segmented_regression(&[5.0], 0).is_err());
+    assert!(data.segmented_regression_search(5).is_err());
+
+    for breakpoints in [[f64::NAN, 5.0], [5.0, f64::INFINITY]] {
+        assert!(matches!(
+            data.segmented_regression(&breakpoints, 1),
+            Err(MatrixError::NotFinite(_))
+        ));
+    }
+    let with_nan = DataSet::<f64>::try_from(
+        (0..30)
+            .map(|x| (if x == 7 { f64::NAN } else { x as f64 }, regime(x as f64)))
+            .collect::<Vec<_>>()
+            .as_slice(),
+    )
+    .unwrap();
+    assert!(matches!(
+        with_nan.segmented_regression_search(1),
+        Err(MatrixError::NotFinite(_))
+    ));
+    assert!(matches!(
+        with_nan.segmented_regression(&[5.0], 1),
+        Err(MatrixError::NotFinite(_))
+    ));
+}
+
+#[test]
+fn determinant_lu() {
+    let matrix = Matrix::<f64>::try_from([
+        [2.0, -1.0, 0.0, 3.0, 1.0, 4.0], //
+        [1.0, 3.0, -2.0, 0.0, 5.0, 1.0],
+        [0.0, 2.0, 4.0, -1.0, 2.0, 0.0],
+        [3.0, 0.0, 1.0, 2.0, -3.0, 2.0],
+        [1.0, 1.0, 0.0, 4.0, 2.0, -1.0],
+        [-2.0, 3.0, 1.0, 0.0, 1.0, 3.0],
+    ])
+    .unwrap();
+    let expected = matrix.determinant_by_cofactor().unwrap();
+    assert!((matrix.determinant_lu().unwrap() - expected).abs() < 1e-9 * expected.abs());
+
+    // the second difference matrix of size n has determinant n + 1
+    let size = std::num::NonZeroUsize::new(40).unwrap();
+    let mut tridiagonal = Matrix::<f64>::zeros(Shape::square(size));
+    for i in 0..size.get() {
+        tridiagonal[i][i] = 2.0;
+        if i + 1 < size.get() {
+            tridiagonal[i][i + 1] = -1.0;
+            tridiagonal[i + 1][i] = -1.0;
+        }
+    }
+    assert!((tridiagonal.determinant_lu().unwrap() - 41.0).abs() < 1e-9);
+
+    let mut singular = matrix.clone();
+    singular[5].copy_from_slice(&[1.0, 1.0, 0.0, 4.0, 2.0, -1.0]);
+    assert!(singular.determinant_lu().unwrap().abs() < 1e-9);
+
+    assert!(matches!(
+        Matrix::<f64>::try_from([[1.0, 2.0]])
+            .unwrap()
+            .determinant_lu(),
+        Err(MatrixError::Determinant(_))
+    ));
+}
+
+#[test]
+fn inverse_by_gauss_jordan() {
+    let matrix = Matrix::<f64>::try_from([
+        [2.0, -1.0, 0.0, 3.0, 1.0, 4.0], //
+        [1.0, 3.0, -2.0, 0.0, 5.0, 1.0],
+        [0.0, 2.0, 4.0, -1.0, 2.0, 0.0],
+        [3.0, 0.0, 1.0, 2.0, -3.0, 2.0],
+        [1.0, 1.0, 0.0, 4.0, 2.0, -1.0],
+        [-2.0, 3.0, 1.0, 0.0, 1.0, 3.0],
+    ])
+    .unwrap();
+
+    let inverse = matrix.inverse().unwrap();
+    assert_eq!(
+        inverse.round_to(8),
+        matrix.inverse_by_adjugate().unwrap().round_to(8)
+    );
+    // the adjugate only needs a ring with division, not a float
+    let unimodular = Matrix::<i64>::try_from([[2, 1, 0], [1, 1, 0], [0, 0, -1]]).unwrap();
+    assert_eq!(
+        unimodular.inverse_by_adjugate().unwrap(),
+        Matrix::try_from([[1, -1, 0], [-1, 2, 0], [0, 0, -1]]).unwrap()
+    );
+    assert_eq!(
+        matrix.matrix_multiply(&inverse).unwrap().round_to(10),
+        Matrix::identity(matrix.width_nonzero())
+    );
+
+    assert_eq!(
+        Matrix::<f64>::try_from([[4.0]]).unwrap().inverse().unwrap(),
+        Matrix::try_from([[0.25]]).unwrap()
+    );
+
+    let mut singular = matrix.clone();
+    singular[5].copy_from_slice(&[1.0, 1.0, 0.0, 4.0, 2.0, -1.0]);
+    assert!(matches!(
+        singular.inverse(),
+        Err(MatrixError::Inverse(InverseError::DeterminantZero))
+    ));
+    assert!(matches!(
+        Matrix::<f64>::try_from([[1.0, 2.0]]).unwrap().inverse(),
+        Err(MatrixError::Inverse(InverseError::DimensionError(
+            DimensionError::NotSquare
+        )))
+    ));
+
+    // singular up to rounding, the closed form would divide by a determinant of about 1e-17
+    let nearly_singular =
+        Matrix::<f64>::try_from([[0.1, 0.2, 0.3], [0.4, 0.5, 0.6], [0.7, 0.8, 0.9]]).unwrap();
+    assert!(matches!(
+        nearly_singular.inverse(),
+        Err(MatrixError::Inverse(InverseError::DeterminantZero))
+    ));
+}
+
+#[test]
+fn isotonic_regression() {
+    let data = DataSet::<f64>::try_from([
+        (0.0, 1.0),
+        (1.0, 3.0),
+        (2.0, 2.0),
+        (3.0, 4.0),
+        (3.0, 6.0),
+        (4.0, 3.5),
+        (5.0, 7.0),
+    ])
+    .unwrap();
+
+    let fit = data.isotonic_regression().unwrap();
+    assert_eq!(fit.inputs(), [0.0, 1.0, 2.0, 3.0, 4.0, 5.0]);
+    // 3, 2 pool to 2.5 and 4, 6, 3.5 pool to 4.5
+    assert_eq!(fit.fitted(), [1.0, 2.5, 2.5, 4.5, 4.5, 7.0]);
+    assert!(fit.fitted().windows(2).all(|pair| pair[0] <= pair[1]));
+    assert_eq!(
+        fit.residual_sum_of_squares(),
+        0.25 + 0.25 + 0.25 + 2.25 + 1.0
+    );
+
+    assert_eq!(fit.predict(-1.0), 1.0);
+    assert_eq!(fit.predict(4.5), 5.75);
+    assert_eq!(fit.predict(10.0), 7.0);
+
+    let monotone = DataSet::<f64>::try_from([(0.0, 1.0), (1.0, 2.0)]).unwrap();
+    assert_eq!(monotone.isotonic_regression().unwrap().fitted(), [1.0, 2.0]);
+
+    for gap in [(f64::NAN, 1.0), (1.0, f64::NAN), (f64::INFINITY, 1.0)] {
+        let points = (0..30)
+            .map(|x| if x == 11 { gap } else { (x as f64, x as f64) })
+            .collect::<Vec<_>>();
+        let with_gap = DataSet::<f64>::try_from(points.as_slice()).unwrap();
+        assert!(matches!(
+            with_gap.isotonic_regression(),
+            Err(MatrixError::NotFinite(_))
+        ));
+    }
+}
+
+#[test]
+fn row_echelon_forms() {
+    let matrix = Matrix::<f64>::try_from([
+        [1.0, 2.0, 1.0, 4.0], //
+        [2.0, 4.0, 0.0, 6.0],
+        [3.0, 6.0, 1.0, 10.0],
+    ])
+    .unwrap();
+
+    let (reduced, pivots) = matrix.rref();
+    assert_eq!(pivots, [0, 2]);
+    assert_eq!(
+        reduced.round_to(10),
+        Matrix::try_from([
+            [1.0, 2.0, 0.0, 3.0], //
+            [0.0, 0.0, 1.0, 1.0],
+            [0.0, 0.0, 0.0, 0.0],
+        ])
+        .unwrap()
+    );
+
+    let (echelon, pivots) = matrix.row_echelon();
+    assert_eq!(pivots, [0, 2]);
+    for (row, &column) in pivots.iter().enumerate() {
+        assert!(echelon[row][..column].iter().all(|&element| element == 0.0));
+        assert_ne!(echelon[row][column], 0.0);
+        assert!((row + 1..echelon.height()).all(|below| echelon[below][column] == 0.0));
+    }
+    assert!(echelon[2].iter().all(|&element| element == 0.0));
+    assert_eq!(echelon[0], [3.0, 6.0, 1.0, 10.0]);
+}
+
+#[test]
+fn fourier_regression() {
+    let period = 12.0;
+    let seasonal = |x: f64| {
+        let angle = std::f64::consts::TAU * x / period;
+        5.0 + 2.0 * angle.sin() - 1.0 * angle.cos() + 0.5 * (2.0 * angle).cos()
+    };
+    let points = (0..36)
+        .map(|month| (month as f64, seasonal(month as f64)))
+        .collect::<Vec<_>>();
+    let data = DataSet::try_from(points.as_slice()).unwrap();
+
+    let fit = data.fourier_regression(2, period).unwrap();
+    let round = |values: Vec<f64>| {
+        values
+            .into_iter()
+            .map(|value| (value * 1e8).round() / 1e8)
+            .collect::<Vec<_>>()
+    };
+    assert!((fit.intercept() - 5.0).abs() < 1e-8);
+    assert_eq!(round(fit.sine_coefficients()), [2.0, 0.0]);
+    assert_eq!(round(fit.cosine_coefficients()), [-1.0, 0.5]);
+    assert!((fit.predict(7.5) - seasonal(7.5)).abs() < 1e-8);
+    assert!(fit.fit().residual_sum_of_squares() < 1e-16);
+
+    let mean_only = data.fourier_regression(0, period).unwrap();
+    assert!((mean_only.intercept() - 5.0).abs() < 1e-8);
+
+    assert!(matches!(
+        data.fourier_regression(1, f64::NAN),
+        Err(MatrixError::NotFinite(_))
+    ));
+    assert!(matches!(
+        data.fourier_regression(1, f64::INFINITY),
+        Err(MatrixError::NotFinite(_))
+    ));
+    assert!(matches!(
+        data.fourier_regression(1, 0.0),
+        Err(MatrixError::DimensionError(DimensionError::TooSmall))
+    ));
+    assert!(matches!(
+        data.fourier_regression(1, -12.0),
+        Err(MatrixError::DimensionError(DimensionError::TooSmall))
+    ));
+}
+
+#[test]
+fn rank_with_tolerance() {
+    let deficient = Matrix::<f64>::try_from([
+        [1.0, 2.0, 3.0], //
+        [4.0, 5.0, 6.0],
+        [7.0, 8.0, 9.0],
+    ])
+    .unwrap();
+    assert_eq!(deficient.rank(1e-12), 2);
+
+    let mut nearly = deficient.clone();
+    nearly[2][2] += 1e-9;
+    assert_eq!(nearly.rank(1e-12), 3);
+    assert_eq!(nearly.rank(1e-6), 2);
+
+    let design = DataSet::<f64>::try_from([(1.0, 0.0), (1.0, 1.0), (1.0, 2.0)])
+        .unwrap()
+        .polynomial_input_matrix(1);
+    assert_eq!(design.rank(1e-12), 1);
+
+    let integers = Matrix::try_from([
+        [2, 4, 1, 3], //
+        [4, 8, 3, 7],
+        [6, 12, 4, 10],
+    ])
+    .unwrap();
+    assert_eq!(integers.rank_exact(), 2);
+    assert_eq!(Matrix::try_from([[3, 1], [1, 3]]).unwrap().rank_exact(), 2);
+    assert_eq!(integers.to_bigrational().rank_exact(), 2);
+}
+
+#[test]
+fn model_round_trip() {
+    let regime = |x: f64| if x < 5.0 { x } else { 5.0 + 3.0 * (x - 5.0) };
+    let points = [0.0, 1.0, 2.0, 3.0, 4.0, 6.0, 7.0, 8.0, 9.0, 10.0].map(|x| (x, regime(x)));
+    let data = DataSet::<f64>::try_from(points).unwrap();
+
+    let segmented = data.segmented_regression(&[5.0], 1).unwrap();
+    let fourier = data.fourier_regression(1, 12.0).unwrap();
+    let isotonic = data.isotonic_regression().unwrap();
+    let scaled = Model::polynomial([1.0, -2.0, 0.5])
+        .unwrap()
+        .with_input_scaling(InputScaling {
+            offset: 5.0,
+            scale: 2.0,
+        });
+
+    let expected_scaled = |x: f64| {
+        let x = (x - 5.0) / 2.0;
+        1.0 - 2.0 * x + 0.5 * x * x
+    };
+    let directory = std::env::temp_dir();
+    let cases: [(_, &dyn Fn(f64) -> f64); 4] = [
+        (segmented.to_model(), &|x| segmented.predict(x)),
+        (fourier.to_model(), &|x| fourier.predict(x)),
+        (isotonic.to_model(), &|x| isotonic.predict(x)),
+        (scaled.clone(), &expected_scaled),
+    ];
+    for (index, (model, predict)) in cases.into_iter().enumerate() {
+        let parsed = model.to_model_string().parse::<Model<f64>>().unwrap();
+        assert_eq!(parsed, model);
+
+        let path = directory.join(format!("model_{}_{index}.txt", std::process::id()));
+        model.save(&path).unwrap();
+        let loaded = Model::<f64>::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(loaded, model);
+
+        for x in [-1.0, 2.5, 5.0, 7.5, 12.0] {
+            assert!((loaded.predict(x) - predict(x)).abs() < 1e-9);
+        }
+    }
+
+    let text = scaled.to_model_string();
+    assert!(text.starts_with("matrix-model 1\nbasis polynomial\ndegree 2\n"));
+    assert!(matches!(
+        "basis polynomial".parse::<Model<f64>>(),
+        Err(ModelError::MissingHeader)
+    ));
+    assert!(matches!(
+        text.replace("matrix-model 1", "matrix-model 2").parse::<Model<f64>>(),
+        Err(ModelError::UnsupportedVersion(version)) if version == "2"
+    ));
+    assert!(matches!(
+        text.replace("polynomial", "spline").parse::<Model<f64>>(),
+        Err(ModelError::UnknownBasis(basis)) if basis == "spline"
+    ));
+    assert!(matches!(
+        text.replace("degree 2", "degree 3").parse::<Model<f64>>(),
+        Err(ModelError::CoefficientCount {
+            expected: 4,
+            actual: 3,
+            ..
+        })
+    ));
+    assert!(matches!(
+        text.replace("input-scale 2", "input-scale two")
+            .parse::<Model<f64>>(),
+        Err(ModelError::InvalidField { .. })
+    ));
+    // a scale of 0 would make every prediction infinite or NaN
+    for scale in ["0", "-0", "inf", "NaN"] {
+        assert!(matches!(
+            text.replace("input-scale 2", &format!("input-scale {scale}"))
+                .parse::<Model<f64>>(),
+            Err(ModelError::InvalidField { field, .. }) if field == "input-scale"
+        ));
+    }
+    assert!(matches!(
+        text.replace("degree 2", "degree 2\ndegree 3").parse::<Model<f64>>(),
+        Err(ModelError::DuplicateField(field)) if field == "degree"
+    ));
+    assert!(matches!(
+        format!("{text}period 12\n").parse::<Model<f64>>(),
+        Err(ModelError::UnknownField(field, "polynomial")) if field == "period"
+    ));
+    assert!(matches!(
+        format!("{text}degre 2\n").parse::<Model<f64>>(),
+        Err(ModelError::UnknownField(field, _)) if field == "degre"
+    ));
+}
+
+#[test]
+fn model_predict_matrix() {
+    let model = Model::polynomial([1.0, -2.0, 0.5]).unwrap();
+    let inputs = Matrix::<f64>::try_from([
+        [0.0, 1.0, 2.0], //
+        [-1.0, 4.0, 0.5],
+    ])
+    .unwrap();
+
+    let predictions = model.predict_matrix(&inputs);
+    assert_eq!(predictions, inputs.map(|&x| model.predict(x)));
+    assert_eq!(predictions[0], [1.0, -0.5, -1.0]);
+
+    #[cfg(feature = "rayon")]
+    assert_eq!(model.par_predict_matrix(&inputs), predictions);
+}
+
+#[test]
+fn pipeline() {
+    let curve = |x: f64, z: f64| 2.0 + 0.5 * x - 0.25 * x * x + 3.0 * z;
+    let rows: [[f64; 2]; 12] = std::array::from_fn(|i| [i as f64 * 10.0, (i % 3) as f64]);
+    let inputs = Matrix::try_from(rows).unwrap();
+    let outputs = Matrix::try_from(rows.map(|[x, z]| [curve(x, z)])).unwrap();
+
+    let exact = Pipeline::new(Estimator::LeastSquares)
+        .standardize()
+        .polynomial_features(2)
+        .fit(&inputs, &outputs)
+        .unwrap();
+    assert_eq!(exact.coefficients().len(), 5);
+    let new_inputs = Matrix::try_from([[15.0, 2.0], [200.0, 0.0]]).unwrap();
+    let predictions = exact.predict(&new_inputs).unwrap();
+    assert!((predictions[0][0] - curve(15.0, 2.0)).abs() < 1e-6);
+    assert!((predictions[1][0] - curve(200.0, 0.0)).abs() < 1e-6);
+
+    // the means and scales come from the training inputs, not the new ones
+    let FittedStep::Standardize { means, .. } = &exact.steps()[0] else {
+        panic!("the first step standardizes");
+    };
+    assert_eq!(means, &[55.0, 1.0]);
+    let transformed = exact.transform(&new_inputs).unwrap();
+    assert_eq!(transformed.width(), 4);
+    assert!(transformed[0][2] > 0.0);
+
+    let ridge = Pipeline::new(Estimator::Ridge { penalty: 10.0 })
+        .standardize()
+        .polynomial_features(2)
+        .fit(&inputs, &outputs)
+        .unwrap();
+    let norm = |coefficients: &[f64]| coefficients[1..].iter().map(|c| c * c).sum::<f64>();
+    assert!(norm(ridge.coefficients()) < norm(exact.coefficients()));
+
+    assert!(matches!(
+        exact.predict(&Matrix::try_from([[1.0]]).unwrap()),
+        Err(MatrixError::DimensionError(
+            DimensionError::UnexpectedWidth { .. }
+        ))
+    ));
+    assert!(Pipeline::new(Estimator::LeastSquares)
+        .polynomial_features(0)
+        .fit(&inputs, &outputs)
+        .is_err());
+}
+
+#[test]
+fn design_matrix_builder() {
+    let builder = DesignMatrixBuilder::new(["x1", "x2"]).degree(2);
+    let inputs = Matrix::<f64>::try_from([
+        [1.0, 2.0], //
+        [3.0, -1.0],
+    ])
+    .unwrap();
+
+    let design = builder.build(&inputs).unwrap();
+    assert_eq!(design.names(), ["1", "x1", "x2", "x1^2", "x1*x2", "x2^2"]);
+    assert_eq!(design.matrix()[0], [1.0, 1.0, 2.0, 1.0, 2.0, 4.0]);
+    assert_eq!(design.matrix()[1], [1.0, 3.0, -1.0, 9.0, -3.0, 1.0]);
+    assert_eq!(design.column_index("x1*x2"), Some(4));
+    assert!(design.terms()[4].is_interaction());
+
+    let cubic = DesignMatrixBuilder::new(["a", "b", "c"]).degree(3);
+    // all monomials of three variables up to degree 3
+    assert_eq!(cubic.terms().len(), 20);
+    let names = cubic
+        .intercept(false)
+        .build(&Matrix::<f64>::try_from([[1.0, 2.0, 3.0]]).unwrap())
+        .unwrap();
+    assert!(names.names().contains(&"a*b*c".to_string()));
+    assert!(names.names().contains(&"a^2*c".to_string()));
+    assert_eq!(names.names().len(), 19);
+
+    let powers = DesignMatrixBuilder::new(["x1", "x2"])
+        .degree(3)
+        .interactions(false);
+    let powers = powers.build(&inputs).unwrap();
+    assert_eq!(
+        powers.names(),
+        ["1", "x1", "x2", "x1^2", "x2^2", "x1^3", "x2^3"]
+    );
+
+    // y = 1 + x1*x2 is recovered exactly
+    let rows: [[f64; 2]; 6] = std::array::from_fn(|i| [i as f64, (i * i % 5) as f64]);
+    let outputs = Matrix::try_from(rows.map(|[x1, x2]| [1.0 + x1 * x2])).unwrap();
+    let fit = builder
+        .build(&Matrix::try_from(rows).unwrap())
+        .unwrap()
+        .normal_equations(outputs)
+        .unwrap()
+        .fit()
+        .unwrap();
+    let coefficients = fit.coefficients().round_to(8);
+    assert_eq!(
+        coefficients.elements().copied().collect::<Vec<_>>(),
+        [1.0, 0.0, 0.0, 0.0, 1.0, 0.0]
+    );
+
+    assert!(builder
+        .build(&Matrix::<f64>::try_from([[1.0]]).unwrap())
+        .is_err());
+    assert!(DesignMatrixBuilder::new(["x"])
+        .degree(0)
+        .intercept(false)
+        .build(&Matrix::<f64>::try_from([[1.0]]).unwrap())
+        .is_err());
+}
+
+#[test]
+fn variance_inflation_factors() {
+    // x3 == x1 + x2 + noise, x4 is unrelated
+    let noise = [0.01, -0.02, 0.0, 0.015, -0.01, 0.005, -0.015, 0.02];
+    let rows: [[f64; 5]; 8] = std::array::from_fn(|i| {
+        let (x1, x2) = (i as f64, ((i * 3) % 5) as f64);
+        [1.0, x1, x2, x1 + x2 + noise[i], ((i * 7) % 4) as f64]
+    });
+    let design = Matrix::try_from(rows).unwrap();
+
+    let factors = design.variance_inflation_factors().unwrap();
+    assert!(factors[0].is_nan());
+    assert!(factors[1] > 100.0 && factors[2] > 100.0 && factors[3] > 100.0);
+    assert!(factors[4] < 2.0);
+
+    // each VIF is 1 / (1 - R²) of regressing the column on the others
+    let others = DesignMatrixBuilder::new(["x2", "x3", "x4"])
+        .build(&Matrix::try_from(rows.map(|row| [row[2], row[3], row[4]])).unwrap())
+        .unwrap();
+    let fit = others
+        .normal_equations(Matrix::try_from(rows.map(|row| [row[1]])).unwrap())
+        .unwrap()
+        .fit()
+        .unwrap();
+    let mean = 3.5;
+    let total = (0..8).map(|x| (x as f64 - mean).powi(2)).sum::<f64>();
+    let expected = total / fit.residual_sum_of_squares();
+    assert!((factors[1] / expected - 1.0).abs() < 1e-6);
+
+    let outputs = Matrix::try_from(rows.map(|row| [row[1] - row[4]])).unwrap();
+    let report = *NormalEquations::new(design.clone(), outputs.clone())
+        .unwrap()
+        .fit()
+        .unwrap()
+        .report();
+    assert!(report.is_collinear());
+    assert_eq!(
+        report.max_variance_inflation(),
+        factors[3].max(factors[1]).max(factors[2])
+    );
+
+    let independent = Matrix::try_from(rows.map(|row| [row[0], row[1], row[4]])).unwrap();
+    let report = *NormalEquations::new(independent, outputs)
+        .unwrap()
+        .fit()
+        .unwrap()
+        .report();
+    assert!(!report.is_collinear());
+
+    // an exact linear combination is infinitely inflated
+    let exact = Matrix::<f64>::try_from([
+        [1.0, 2.0, 3.0],
+        [2.0, 1.0, 3.0],
+        [0.0, 5.0, 5.0],
+        [4.0, 4.0, 8.0],
+    ])
+    .unwrap();
+    assert!(exact
+        .variance_inflation_factors()
+        .unwrap()
+        .iter()
+        .all(|factor| factor.is_infinite()));
+}
+
+#[test]
+fn matrix_power() {
+    // Fibonacci numbers from powers of [[1, 1], [1, 0]]
+    let fibonacci = Matrix::try_from([[1u64, 1], [1, 0]]).unwrap();
+    assert_eq!(
+        fibonacci.pow(0).unwrap(),
+        Matrix::try_from([[1, 0], [0, 1]]).unwrap()
+    );
+    assert_eq!(fibonacci.pow(1).unwrap(), fibonacci);
+    assert_eq!(fibonacci.pow(10).unwrap()[0], [89, 55]);
+    assert_eq!(fibonacci.pow(90).unwrap()[0][1], 2_880_067_194_370_816_120);
+
+    // a two state Markov chain converges to its stationary distribution [2/3, 1/3]
+    let transition = Matrix::<f64>::try_from([[0.9, 0.1], [0.2, 0.8]]).unwrap();
+    let steady = transition.pow(200).unwrap();
+    for row in steady.rows() {
+        assert!((row[0] - 2.0 / 3.0).abs() < 1e-12);
+        assert!((row[1] - 1.0 / 3.0).abs() < 1e-12);
+    }
+
+    let a = Matrix::<f64>::try_from([[2.0, 1.0], [0.0, 1.0]]).unwrap();
+    let a_cubed = a.pow(3).unwrap();
+    assert_eq!(a_cubed, Matrix::try_from([[8.0, 7.0], [0.0, 1.0]]).unwrap());
+    let round_trip = a.powi(-3).unwrap().matrix_multiply(&a_cubed).unwrap();
+    assert_eq!(
+        round_trip.round_to(12),
+        Matrix::identity(a.height_nonzero())
+    );
+    assert_eq!(a.powi(2).unwrap(), a.pow(2).unwrap());
+
+    let singular = Matrix::<f64>::try_from([[1.0, 2.0], [2.0, 4.0]]).unwrap();
+    assert!(matches!(singular.powi(-1), Err(MatrixError::Inverse(_))));
+    assert!(matches!(
+        Matrix::try_from([[1, 2, 3]]).unwrap().pow(2),
+        Err(MatrixError::DimensionError(DimensionError::NotSquare))
+    ));
+}
+
+#[test]
+fn matrix_exponential() {
+    let zero = Matrix::<f64>::zeros(Shape::square(std::num::NonZeroUsize::new(3).unwrap()));
+    assert_eq!(zero.exp().unwrap(), Matrix::identity(zero.height_nonzero()));
+
+    // a diagonal matrix exponentiates each element, from small to large norms
+    for scale in [1e-3, 0.1, 0.5, 1.5, 4.0, 40.0] {
+        let diagonal = Matrix::<f64>::try_from([[scale, 0.0], [0.0, -2.0 * scale]]).unwrap();
+        let exponential = diagonal.exp().unwrap();
+        assert!((exponential[0][0] / scale.exp() - 1.0).abs() < 1e-12);
+        assert!((exponential[1][1] / (-2.0 * scale).exp() - 1.0).abs() < 1e-12);
+        assert_eq!(exponential[0][1], 0.0);
+    }
+
+    // x'' == -x so e^(At) rotates by t
+    let t = 2.5;
+    let rotation = Matrix::<f64>::try_from([[0.0, t], [-t, 0.0]])
+        .unwrap()
+        .exp()
+        .unwrap();
+    let expected = Matrix::try_from([[t.cos(), t.sin()], [-t.sin(), t.cos()]]).unwrap();
+    assert_eq!(rotation.round_to(12), expected.round_to(12));
+
+    // a nilpotent matrix has a finite series: I + N + N²/2
+    let nilpotent =
+        Matrix::<f64>::try_from([[0.0, 1.0, 2.0], [0.0, 0.0, 3.0], [0.0, 0.0, 0.0]]).unwrap();
+    let expected = Matrix::try_from([[1.0, 1.0, 3.5], [0.0, 1.0, 3.0], [0.0, 0.0, 1.0]]).unwrap();
+    assert_eq!(nilpotent.exp().unwrap().round_to(12), expected);
+
+    // exp and log are inverses
+    let a = Matrix::<f64>::try_from([[0.5, 0.2, 0.0], [-0.3, 0.1, 0.4], [0.0, 0.2, -0.6]]).unwrap();
+    assert_eq!(a.exp().unwrap().log().unwrap().round_to(10), a.round_to(10));
+
+    assert!(matches!(
+        Matrix::<f64>::try_from([[1.0, 2.0]]).unwrap().exp(),
+        Err(MatrixError::DimensionError(DimensionError::NotSquare))
+    ));
+}
+
+#[test]
+fn stepwise_selection() {
+    // y == 1 + 2 x1 - x1*x2 + noise, x2 and x2^2 and x1^2 do not matter on their own
+    let noise = [
+        0.03, -0.02, 0.01, -0.04, 0.02, 0.0, -0.01, 0.03, -0.03, 0.02, 0.01, -0.02,
+    ];
+    let rows: [[f64; 2]; 12] = std::array::from_fn(|i| [i as f64 / 2.0, ((i * 5) % 7) as f64]);
+    let outputs: [[f64; 1]; 12] = std::array::from_fn(|i| {
+        let [x1, x2] = rows[i];
+        [1.0 + 2.0 * x1 - x1 * x2 + noise[i]]
+    });
+    let outputs = Matrix::try_from(outputs).unwrap();
+    let design = DesignMatrixBuilder::new(["x1", "x2"])
+        .degree(2)
+        .build(&Matrix::try_from(rows).unwrap())
+        .unwrap();
+
+    for direction in [SelectionDirection::Forward, SelectionDirection::Backward] {
+        for criterion in [
+            SelectionCriterion::AdjustedRSquared,
+            SelectionCriterion::Aic,
+        ] {
+            let selection = design
+                .stepwise_selection(&outputs, direction, criterion)
+                .unwrap();
+            assert!(selection.names().contains(&"1".to_string()));
+            assert!(selection.names().contains(&"x1".to_string()));
+            assert!(selection.names().contains(&"x1*x2".to_string()));
+            assert!(selection.fit().r_squared() > 0.999);
+            assert_eq!(selection.fit().parameters(), selection.columns().len());
+        }
+    }
+
+    let forward = design
+        .stepwise_selection(
+            &outputs,
+            SelectionDirection::Forward,
+            SelectionCriterion::Aic,
+        )
+        .unwrap();
+    assert_eq!(forward.names(), ["1", "x1", "x1*x2"]);
+    assert_eq!(forward.columns(), [0, 1, 4]);
+    assert_eq!(forward.score(), forward.fit().aic());
+    let full = design
+        .normal_equations(outputs.clone())
+        .unwrap()
+        .fit()
+        .unwrap();
+    assert!(forward.score() < full.aic());
+    assert!(full.r_squared() >= forward.fit().r_squared());
+
+    assert!(design
+        .stepwise_selection(
+            &Matrix::try_from([[1.0]]).unwrap(),
+            SelectionDirection::Forward,
+            SelectionCriterion::Aic
+        )
+        .is_err());
+}
+
+#[test]
+fn data_set_group_by() {
+    // inputs below 10 are one device category and the rest another
+    let data = DataSet::<f64>::try_from([
+        (1.0, 3.0),
+        (12.0, 20.0),
+        (2.0, 5.0),
+        (14.0, 18.0),
+        (3.0, 7.0),
+        (16.0, 16.0),
+    ])
+    .unwrap();
+    let category = |point: &DataPoint<f64>| *point.input() >= 10.0;
+
+    let groups = data.group_by(category);
+    assert_eq!(groups.len(), 2);
+    assert_eq!(
+        groups[&false],
+        DataSet::try_from([(1.0, 3.0), (2.0, 5.0), (3.0, 7.0)]).unwrap()
+    );
+    assert_eq!(groups[&true].len(), 3);
+
+    let trends = data
+        .fit_per_group(category, |group| group.polynomial_fit(1))
+        .unwrap();
+    let slope = |fit: &RegressionFit<f64>| (fit.coefficients()[1][0] * 1e8).round() / 1e8;
+    assert_eq!(slope(&trends[&false]), 2.0);
+    assert_eq!(slope(&trends[&true]), -1.0);
+
+    let by_output = data.group_by(|point| (*point.output() / 10.0) as i32);
+    assert_eq!(by_output.keys().copied().collect::<Vec<_>>(), [0, 1, 2]);
+
+    // the first failing group in key order is returned
+    let failed = data.fit_per_group(category, |group| match *group.data()[0].input() {
+        input if input < 10.0 => Err::<(), _>(input),
+        _ => Err(-1.0),
+    });
+    assert_eq!(failed, Err(1.0));
+}
+
+#[test]
+fn data_set_merge_and_join() {
+    let first = DataSet::<f64>::try_from([(0.0, 1.0), (1.0, 2.0), (2.0, 3.0)]).unwrap();
+    let second = DataSet::<f64>::try_from([(3.0, 4.0), (1.0, 2.5)]).unwrap();
+
+    let merged = first.merge(&second);
+    assert_eq!(merged.len(), 5);
+    assert_eq!(merged.data()[..3], first.data()[..]);
+    assert_eq!(merged.data()[3..], second.data()[..]);
+
+    // a second run measured at slightly different inputs and in another order
+    let run =
+        DataSet::<f64, &str>::try_from([(2.01, "c"), (0.0, "a"), (5.0, "unmatched"), (0.98, "b")])
+            .unwrap();
+    let joined = first.join_on_input(&run, 0.05).unwrap();
+    assert_eq!(
+        joined,
+        DataSet::try_from([(0.0, (1.0, "a")), (1.0, (2.0, "b")), (2.0, (3.0, "c"))]).unwrap()
+    );
+
+    let exact = first.join_on_input(&run, 0.0).unwrap();
+    assert_eq!(exact.len(), 1);
+    assert_eq!(exact.data()[0].output(), &(1.0, "a"));
+
+    // each point is matched at most once
+    let repeated = DataSet::<f64>::try_from([(1.0, 10.0), (1.0, 20.0)]).unwrap();
+    let joined = first.join_on_input(&repeated, 0.5).unwrap();
+    assert_eq!(joined.len(), 1);
+    assert_eq!(joined.data()[0].output(), &(2.0, 10.0));
+
+    assert!(matches!(
+        first
+            .join_on_input(&second, 0.0001)
+            .map(|joined| joined.len()),
+        Ok(1)
+    ));
+    // inputs that are not finite never match, even each other
+    let gaps = DataSet::<f64>::try_from(
+        [(f64::NAN, 7.0), (1.0, 8.0), (f64::INFINITY, 9.0)]
+            .into_iter()
+            .cycle()
+            .take(30)
+            .collect::<Vec<_>>()
+            .as_slice(),
+    )
+    .unwrap();
+    let joined = gaps.join_on_input(&gaps, f64::INFINITY).unwrap();
+    assert_eq!(joined.len(), 10);
+    assert!(joined
+        .data()
+        .iter()
+        .all(|point| point.output() == &(8.0, 8.0)));
+    let far = DataSet::<f64>::try_from([(100.0, 0.0)]).unwrap();
+    assert!(matches!(
+        first.join_on_input(&far, 1.0),
+        Err(DataSetError::Empty)
+    ));
+}
+
+#[test]
+fn outer_product_and_mul_vector() {
+    let outer = Matrix::outer_product(&[1, 2, 3], &[4, 5]).unwrap();
+    assert_eq!(
+        outer,
+        Matrix::try_from([
+            [4, 5], //
+            [8, 10],
+            [12, 15],
+        ])
+        .unwrap()
+    );
+    assert!(matches!(
+        Matrix::<i32>::outer_product(&[], &[1]),
+        Err(MatrixError::DimensionError(DimensionError::Zero))
+    ));
+
+    let a = Matrix::try_from([
+        [1, 2, 3], //
+        [4, 5, 6],
+    ])
+    .unwrap();
+    assert_eq!(a.mul_vector(&[1, 0, -1]).unwrap(), [-2, -2]);
+    let column = Matrix::try_from([[1], [0], [-1]]).unwrap();
+    assert_eq!(
+        a.mul_vector(&[1, 0, -1]).unwrap(),
+        a.matrix_multiply(&column)
+            .unwrap()
+            .column(0)
+            .copied()
+            .collect::<Vec<_>>()
+    );
+    assert!(matches!(
+        a.mul_vector(&[1, 2]),
+        Err(MatrixError::Arithmetic { .. })
+    ));
+}
+
+#[test]
+fn unit_transforms() {
+    // output == 2 + 0.5 * celsius - 0.01 * celsius²
+    let polynomial = |x: f64| 2.0 + 0.5 * x - 0.01 * x * x;
+    let celsius = [-10.0, 0.0, 15.0, 20.0, 35.0].map(|x| (x, polynomial(x)));
+    let data = DataSet::<f64>::try_from(celsius).unwrap();
+    let round = |values: Vec<f64>| {
+        values
+            .into_iter()
+            .map(|v| (v * 1e8).round() / 1e8)
+            .collect::<Vec<_>>()
+    };
+
+    let to_kelvin = UnitTransform::Affine {
+        scale: 1.0,
+        offset: 273.15,
+    };
+    let (kelvin, record) = data.map_inputs(to_kelvin).unwrap();
+    assert_eq!(record.target(), TransformTarget::Inputs);
+    assert!((*kelvin.data()[1].input() - 273.15).abs() < 1e-12);
+    assert_eq!(kelvin.data()[1].output(), data.data()[1].output());
+
+    // fit in fahrenheit, then read the coefficients back in celsius
+    let (fahrenheit, record) = data
+        .map_inputs(UnitTransform::Affine {
+            scale: 1.8,
+            offset: 32.0,
+        })
+        .unwrap();
+    let fit = fahrenheit.polynomial_fit(2).unwrap();
+    let coefficients = fit.coefficients().elements().copied().collect::<Vec<_>>();
+    let original = record.original_polynomial(&coefficients).unwrap();
+    assert_eq!(round(original), [2.0, 0.5, -0.01]);
+
+    // a model fitted in fahrenheit accepts celsius through its input scaling
+    let model = Model::polynomial(coefficients)
+        .unwrap()
+        .with_input_scaling(record.input_scaling().unwrap());
+    assert!((model.predict(25.0) - polynomial(25.0)).abs() < 1e-8);
+
+    let (scaled, record) = data
+        .map_outputs(UnitTransform::Affine {
+            scale: 1000.0,
+            offset: -5.0,
+        })
+        .unwrap();
+    let fit = scaled.polynomial_fit(2).unwrap();
+    let coefficients = fit.coefficients().elements().copied().collect::<Vec<_>>();
+    assert_eq!(
+        round(record.original_polynomial(&coefficients).unwrap()),
+        [2.0, 0.5, -0.01]
+    );
+    assert!(record.input_scaling().is_none());
+
+    let positive = DataSet::<f64>::try_from([(1.0, 10.0), (2.0, 100.0), (3.0, 1000.0)]).unwrap();
+    let (logged, record) = positive.map_outputs(UnitTransform::Log10).unwrap();
+    assert_eq!(
+        round(logged.data().iter().map(|p| *p.output()).collect()),
+        [1.0, 2.0, 3.0]
+    );
+    assert!((record.transform().invert(2.0) - 100.0).abs() < 1e-9);
+    assert!(record.original_polynomial(&[1.0, 1.0]).is_none());
+
+    let custom = UnitTransform::Custom {
+        name: "square root",
+        forward: f64::sqrt,
+        inverse: |x| x * x,
+    };
+    let (rooted, record) = positive.map_inputs(custom).unwrap();
+    assert_eq!(record.transform().name(), "square root");
+    assert!((record.transform().invert(*rooted.data()[1].input()) - 2.0).abs() < 1e-12);
+
+    // a scale of 0 maps every value to the offset so it can not be undone
+    let flatten = UnitTransform::Affine {
+        scale: 0.0,
+        offset: 1.0,
+    };
+    assert!(matches!(
+        data.map_inputs(flatten),
+        Err(MatrixError::NotInvertible(_))
+    ));
+    assert!(data.map_outputs(flatten).is_err());
+}
@@ -0,0 +1,219 @@
+use std::{fmt::Display, str::FromStr};
+
+use num::Float;
+
+use crate::{DataSet, ParseTimestampError};
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// A moment in time stored as whole seconds since `1970-01-01T00:00:00Z` (unix seconds) <br>
+/// Parsed from unix seconds (`1706708700`), a UTC date (`2024-01-31`),
+/// or a UTC date and time (`2024-01-31T13:45:00`, `2024-01-31 13:45:00`, optionally ending in `Z`).
+/// Displayed as a date and time like `2024-01-31T13:45:00Z`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Timestamp(i64);
+impl Timestamp {
+    pub fn from_unix_seconds(seconds: i64) -> Self {
+        return Timestamp(seconds);
+    }
+    pub fn unix_seconds(&self) -> i64 {
+        return self.0;
+    }
+    /// The timestamp at midnight UTC of a date
+    /// ## Returns
+    /// - [None] if `month` is not `1..=12` or `day` is not a day of that month
+    /// - [None] if the date is too far from 1970 for its unix seconds to fit in an [i64]
+    pub fn from_date(year: i64, month: u32, day: u32) -> Option<Self> {
+        if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+            return None;
+        }
+        let days = days_from_civil(year, month, day)?;
+        return days.checked_mul(SECONDS_PER_DAY).map(Timestamp);
+    }
+    /// The signed number of seconds from `origin` to `self`
+    /// ## Returns
+    /// - [None] if the difference does not fit in an [i64]
+    pub fn seconds_since(&self, origin: Timestamp) -> Option<i64> {
+        return self.0.checked_sub(origin.0);
+    }
+}
+
+impl FromStr for Timestamp {
+    type Err = ParseTimestampError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ParseTimestampError::new(s);
+
+        if let Ok(seconds) = s.parse::<i64>() {
+            return Ok(Timestamp(seconds));
+        }
+
+        let value = s.strip_suffix('Z').unwrap_or(s);
+        let (date, time) = match value.split_once(['T', ' ']) {
+            Some((date, time)) => (date, Some(time)),
+            None => (value, None),
+        };
+
+        // a leading '-' belongs to the year
+        let (sign, date) = match date.strip_prefix('-') {
+            Some(date) => (-1, date),
+            None => (1, date),
+        };
+        let [year, month, day] = parse_fields(date, '-').ok_or_else(error)?;
+        let month = u32::try_from(month).map_err(|_| error())?;
+        let day = u32::try_from(day).map_err(|_| error())?;
+        let date = Timestamp::from_date(sign * year, month, day).ok_or_else(error)?;
+
+        let Some(time) = time else {
+            return Ok(date);
+        };
+        let [hour, minute, second] = parse_fields(time, ':').ok_or_else(error)?;
+        if hour >= 24 || minute >= 60 || second >= 60 {
+            return Err(error());
+        }
+
+        let seconds = date
+            .0
+            .checked_add(hour * 3_600 + minute * 60 + second)
+            .ok_or_else(error)?;
+        return Ok(Timestamp(seconds));
+    }
+}
+
+impl Display for Timestamp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let days = self.0.div_euclid(SECONDS_PER_DAY);
+        let seconds = self.0.rem_euclid(SECONDS_PER_DAY);
+        let (year, month, day) = civil_from_days(days);
+        return write!(
+            f,
+            "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+            seconds / 3_600,
+            seconds % 3_600 / 60,
+            seconds % 60
+        );
+    }
+}
+
+/// The unit of the numeric offsets made by [DataSet::to_offsets]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeUnit {
+    Seconds,
+    Minutes,
+    Hours,
+    Days,
+}
+impl TimeUnit {
+    pub fn seconds(&self) -> i64 {
+        return match self {
+            TimeUnit::Seconds => 1,
+            TimeUnit::Minutes => 60,
+            TimeUnit::Hours => 3_600,
+            TimeUnit::Days => SECONDS_PER_DAY,
+        };
+    }
+}
+
+/// A [DataSet] whose inputs are [Timestamp]s <br>
+/// Read one from CSV with [DataSet::from_csv] and fit it after [DataSet::to_offsets].
+pub type TimeSeries<F> = DataSet<Timestamp, F>;
+
+impl<F: Float> DataSet<Timestamp, F> {
+    /// The earliest timestamp
+    pub fn start(&self) -> Timestamp {
+        return self
+            .data()
+            .iter()
+            .map(|data_point| *data_point.input())
+            .min()
+            .expect("a DataSet is never empty");
+    }
+
+    /// Replace each timestamp with the time since [DataSet::start] in `unit`s <br>
+    /// A fit of the result predicts at `t` with the input `(t - start) / unit`.
+    pub fn to_offsets(&self, unit: TimeUnit) -> DataSet<F> {
+        return self.to_offsets_from(self.start(), unit);
+    }
+
+    /// Replace each timestamp with the time since `origin` in `unit`s <br>
+    /// Timestamps before `origin` have negative offsets.
+    pub fn to_offsets_from(&self, origin: Timestamp, unit: TimeUnit) -> DataSet<F> {
+        let unit = F::from(unit.seconds()).expect("i64 converts to a float");
+        let points = self
+            .data()
+            .iter()
+            .map(|data_point| {
+                // the difference of any two i64 fits in an i128
+                let seconds = i128::from(data_point.input().unix_seconds())
+                    - i128::from(origin.unix_seconds());
+                let offset = F::from(seconds).expect("i128 converts to a float") / unit;
+                (offset, *data_point.output())
+            })
+            .collect::<Vec<_>>();
+
+        return DataSet::try_from(points.as_slice()).expect("a DataSet is never empty");
+    }
+}
+
+/// Split `text` on `separator` into exactly three non-negative integers
+fn parse_fields(text: &str, separator: char) -> Option<[i64; 3]> {
+    let mut fields = text.split(separator).map(|field| {
+        if field.is_empty() || !field.bytes().all(|byte| byte.is_ascii_digit()) {
+            None
+        } else {
+            field.parse::<i64>().ok()
+        }
+    });
+    let parsed = [fields.next()??, fields.next()??, fields.next()??];
+    if fields.next().is_some() {
+        return None;
+    }
+    return Some(parsed);
+}
+
+fn is_leap_year(year: i64) -> bool {
+    return year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    return match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    };
+}
+
+/// Days since 1970-01-01 in the proleptic Gregorian calendar
+/// (Howard Hinnant's `days_from_civil`), [None] if it does not fit in an [i64]
+fn days_from_civil(year: i64, month: u32, day: u32) -> Option<i64> {
+    let year = if month <= 2 {
+        year.checked_sub(1)?
+    } else {
+        year
+    };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month_from_march = (i64::from(month) + 9) % 12;
+    let day_of_year = (153 * month_from_march + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    return era.checked_mul(146_097)?.checked_add(day_of_era - 719_468);
+}
+
+/// The inverse of [days_from_civil]
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_from_march = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_from_march + 2) / 5 + 1) as u32;
+    let month = if month_from_march < 10 {
+        month_from_march + 3
+    } else {
+        month_from_march - 9
+    } as u32;
+    let year = era * 400 + year_of_era + i64::from(month <= 2);
+    return (year, month, day);
+}
@@ -0,0 +1,173 @@
+use num::Float;
+
+use crate::{regression::add_affine_power, DataPoint, DataSet, InputScaling, MatrixError};
+
+/// An invertible change of units such as Celsius to Kelvin or a log scale
+#[derive(Debug, Clone, Copy)]
+pub enum UnitTransform<F> {
+    /// `scale * x + offset`, for example Celsius to Kelvin is `1, 273.15`
+    /// and Celsius to Fahrenheit is `1.8, 32`. The `scale` must not be `0`.
+    Affine { scale: F, offset: F },
+    /// The natural logarithm, `NaN` for values that are not positive
+    Log,
+    /// The base `10` logarithm, `NaN` for values that are not positive
+    Log10,
+    /// Any other change of units given as a function and its inverse,
+    /// closures that capture nothing can be passed for both
+    Custom {
+        name: &'static str,
+        forward: fn(F) -> F,
+        inverse: fn(F) -> F,
+    },
+}
+impl<F: Float> UnitTransform<F> {
+    /// A name for the transform like `affine` or `log`
+    pub fn name(&self) -> &'static str {
+        return match self {
+            UnitTransform::Affine { .. } => "affine",
+            UnitTransform::Log => "log",
+            UnitTransform::Log10 => "log10",
+            UnitTransform::Custom { name, .. } => name,
+        };
+    }
+    /// [MatrixError::NotInvertible] for an [UnitTransform::Affine] with a `scale` of `0`
+    fn check_invertible(&self) -> Result<(), MatrixError> {
+        return match self {
+            UnitTransform::Affine { scale, .. } if scale.is_zero() => Err(
+                MatrixError::NotInvertible("an affine transform with a scale of 0"),
+            ),
+            _ => Ok(()),
+        };
+    }
+    /// Convert `value` to the new units
+    pub fn apply(&self, value: F) -> F {
+        return match self {
+            UnitTransform::Affine { scale, offset } => *scale * value + *offset,
+            UnitTransform::Log => value.ln(),
+            UnitTransform::Log10 => value.log10(),
+            UnitTransform::Custom { forward, .. } => forward(value),
+        };
+    }
+    /// Convert `value` back to the original units
+    pub fn invert(&self, value: F) -> F {
+        return match self {
+            UnitTransform::Affine { scale, offset } => (value - *offset) / *scale,
+            UnitTransform::Log => value.exp(),
+            UnitTransform::Log10 => F::from(10).expect("10 converts to a float").powf(value),
+            UnitTransform::Custom { inverse, .. } => inverse(value),
+        };
+    }
+}
+
+/// Whether a [TransformRecord] changed the inputs or the outputs of a [DataSet]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransformTarget {
+    Inputs,
+    Outputs,
+}
+
+/// What [DataSet::map_inputs] or [DataSet::map_outputs] did,
+/// kept to interpret a fit of the new [DataSet] in the original units
+#[derive(Debug, Clone, Copy)]
+pub struct TransformRecord<F> {
+    target: TransformTarget,
+    transform: UnitTransform<F>,
+}
+impl<F: Float> TransformRecord<F> {
+    pub fn target(&self) -> TransformTarget {
+        return self.target;
+    }
+    pub fn transform(&self) -> &UnitTransform<F> {
+        return &self.transform;
+    }
+
+    /// Convert polynomial coefficients fitted on the new [DataSet] to the original units <br>
+    /// Only an [UnitTransform::Affine] keeps a polynomial a polynomial, every other transform gives [None].
+    /// For inputs `p(a * x + b)` is expanded with the binomial theorem,
+    /// for outputs `(p(x) - b) / a` is the polynomial the original outputs follow.
+    /// ## Parameters
+    /// - `coefficients`: the coefficient of each power in ascending order like [DataSet::polynomial_fit].
+    pub fn original_polynomial(&self, coefficients: &[F]) -> Option<Vec<F>> {
+        let UnitTransform::Affine { scale, offset } = self.transform else {
+            return None;
+        };
+
+        let mut original = vec![F::zero(); coefficients.len()];
+        match self.target {
+            TransformTarget::Inputs => {
+                for (power, &coefficient) in coefficients.iter().enumerate() {
+                    add_affine_power(&mut original, coefficient, scale, offset, power);
+                }
+            }
+            TransformTarget::Outputs => {
+                for (element, &coefficient) in original.iter_mut().zip(coefficients) {
+                    *element = coefficient / scale;
+                }
+                if let Some(constant) = original.first_mut() {
+                    *constant = *constant - offset / scale;
+                }
+            }
+        }
+        return Some(original);
+    }
+
+    /// The [InputScaling] that makes a [crate::Model] fitted on the new inputs accept original inputs <br>
+    /// [None] unless the record is an [UnitTransform::Affine] of the inputs.
+    pub fn input_scaling(&self) -> Option<InputScaling<F>> {
+        return match (self.target, self.transform) {
+            // a x + b == (x - (-b / a)) / (1 / a)
+            (TransformTarget::Inputs, UnitTransform::Affine { scale, offset }) => {
+                Some(InputScaling {
+                    offset: -offset / scale,
+                    scale: F::one() / scale,
+                })
+            }
+            _ => None,
+        };
+    }
+}
+
+impl<F: Float> DataSet<F> {
+    /// Convert every input to new units, keeping the outputs
+    /// ## Returns
+    /// - The new [DataSet] and a [TransformRecord] to map results back to the original units.
+    /// ## Errors
+    /// - [MatrixError::NotInvertible]
+    ///   - if `transform` is an [UnitTransform::Affine] with a `scale` of `0`
+    pub fn map_inputs(
+        &self,
+        transform: UnitTransform<F>,
+    ) -> Result<(DataSet<F>, TransformRecord<F>), MatrixError> {
+        transform.check_invertible()?;
+        let mapped = self.map_points(|point| (transform.apply(*point.input()), *point.output()));
+        let record = TransformRecord {
+            target: TransformTarget::Inputs,
+            transform,
+        };
+        return Ok((mapped, record));
+    }
+
+    /// Convert every output to new units, keeping the inputs
+    /// ## Returns
+    /// - The new [DataSet] and a [TransformRecord] to map results back to the original units.
+    /// ## Errors
+    /// - [MatrixError::NotInvertible]
+    ///   - if `transform` is an [UnitTransform::Affine] with a `scale` of `0`
+    pub fn map_outputs(
+        &self,
+        transform: UnitTransform<F>,
+    ) -> Result<(DataSet<F>, TransformRecord<F>), MatrixError> {
+        transform.check_invertible()?;
+        let mapped = self.map_points(|point| (*point.input(), transform.apply(*point.output())));
+        let record = TransformRecord {
+            target: TransformTarget::Outputs,
+            transform,
+        };
+        return Ok((mapped, record));
+    }
+
+    fn map_points(&self, f: impl FnMut(&DataPoint<F>) -> (F, F)) -> DataSet<F> {
+        let points = self.data().iter().map(f).collect::<Vec<_>>();
+        return DataSet::try_from(points.as_slice()).expect("self is not empty");
+    }
+}
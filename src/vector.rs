@@ -0,0 +1,403 @@
+use std::{
+    marker::PhantomData,
+    num::NonZeroUsize,
+    ops::{Index, IndexMut, Mul},
+};
+
+use num::{Float, Num};
+
+#[cfg(feature = "rayon")]
+use crate::matrix::reduction::{pairwise_sum, REDUCTION_BLOCK_LENGTH};
+use crate::{kernels, DimensionError, Matrix, MatrixError, Shape, Summation};
+
+/// Marker for a [Vector] that is a single column (`n x 1`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Column;
+
+/// Marker for a [Vector] that is a single row (`1 x n`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Row;
+
+/// A non empty list of elements with an orientation <br>
+/// Use the [ColumnVector] and [RowVector] aliases.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Vector<E, Orientation> {
+    elements: Box<[E]>,
+    orientation: PhantomData<Orientation>,
+}
+
+/// An `n x 1` [Vector]
+pub type ColumnVector<E> = Vector<E, Column>;
+
+/// A `1 x n` [Vector]
+pub type RowVector<E> = Vector<E, Row>;
+
+impl<E, O> Vector<E, O> {
+    pub fn len(&self) -> usize {
+        return self.elements.len();
+    }
+    /// Always `false`, a [Vector] has at least one element
+    pub fn is_empty(&self) -> bool {
+        return self.elements.is_empty();
+    }
+    /// convenience method when a [NonZeroUsize] is needed
+    pub fn len_nonzero(&self) -> NonZeroUsize {
+        return NonZeroUsize::new(self.len()).expect("length cannot be zero");
+    }
+    pub fn as_slice(&self) -> &[E] {
+        return &self.elements;
+    }
+    pub fn as_mut_slice(&mut self) -> &mut [E] {
+        return &mut self.elements;
+    }
+    pub fn iter(&self) -> impl Iterator<Item = &E> {
+        return self.elements.iter();
+    }
+    /// The same elements with the other orientation
+    pub fn transpose<Other>(self) -> Vector<E, Other> {
+        return Vector {
+            elements: self.elements,
+            orientation: PhantomData,
+        };
+    }
+
+    /// Check that `self` and `rhs` have the same length
+    fn same_length(&self, rhs: &Self) -> Result<(), MatrixError> {
+        return if self.len() != rhs.len() {
+            Err(DimensionError::UnexpectedLength {
+                expected: self.len(),
+                actual: rhs.len(),
+            }
+            .into())
+        } else {
+            Ok(())
+        };
+    }
+}
+
+impl<E, O> TryFrom<Vec<E>> for Vector<E, O> {
+    type Error = MatrixError;
+
+    fn try_from(elements: Vec<E>) -> Result<Self, Self::Error> {
+        if elements.is_empty() {
+            return Err(DimensionError::Zero.into());
+        }
+        return Ok(Vector {
+            elements: elements.into_boxed_slice(),
+            orientation: PhantomData,
+        });
+    }
+}
+
+impl<E, O, const LENGTH: usize> TryFrom<[E; LENGTH]> for Vector<E, O> {
+    type Error = MatrixError;
+
+    /// Like [Matrix::try_from] for arrays, this only fails if `LENGTH` is `0`
+    fn try_from(elements: [E; LENGTH]) -> Result<Self, Self::Error> {
+        return Vector::try_from(Vec::from(elements));
+    }
+}
+
+impl<E, O> Index<usize> for Vector<E, O> {
+    type Output = E;
+    fn index(&self, index: usize) -> &Self::Output {
+        return self.elements.index(index);
+    }
+}
+
+impl<E, O> IndexMut<usize> for Vector<E, O> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        return self.elements.index_mut(index);
+    }
+}
+
+impl<E: Num + Clone, O> Vector<E, O> {
+    /// The sum of the products of matching elements
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if `self` and `rhs` have different lengths
+    pub fn dot(&self, rhs: &Self) -> Result<E, MatrixError> {
+        self.same_length(rhs)?;
+
+        return kernels::dot(self.as_slice(), rhs.as_slice());
+    }
+
+    /// The dot product of `self` with itself
+    pub fn norm_squared(&self) -> E {
+        return self.iter().fold(E::zero(), |sum, element| {
+            sum + element.clone() * element.clone()
+        });
+    }
+
+    /// Multiply every element by `scalar`
+    pub fn scale(&self, scalar: E) -> Self {
+        return Vector {
+            elements: self
+                .iter()
+                .map(|element| element.clone() * scalar.clone())
+                .collect(),
+            orientation: PhantomData,
+        };
+    }
+
+    /// The 3d cross product `self × rhs`
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if `self` or `rhs` does not have exactly 3 elements
+    pub fn cross(&self, rhs: &Self) -> Result<Self, MatrixError> {
+        for length in [self.len(), rhs.len()] {
+            if length != 3 {
+                return Err(DimensionError::UnexpectedLength {
+                    expected: 3,
+                    actual: length,
+                }
+                .into());
+            }
+        }
+
+        let a = |index: usize| self.elements[index].clone();
+        let b = |index: usize| rhs.elements[index].clone();
+        return Vector::try_from([
+            a(1) * b(2) - a(2) * b(1),
+            a(2) * b(0) - a(0) * b(2),
+            a(0) * b(1) - a(1) * b(0),
+        ]);
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<E: Num + Clone + Send + Sync, O> Vector<E, O> {
+    /// [Vector::dot] split into fixed length blocks that are reduced on the rayon thread pool <br>
+    /// The blocks do not depend on the number of threads and their partial sums are combined in a fixed
+    /// (pairwise) order, so the result is the same on every run.
+    /// It can differ from [Vector::dot] in the last few bits because the additions happen in a different order.
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if `self` and `rhs` have different lengths
+    pub fn par_dot(&self, rhs: &Self) -> Result<E, MatrixError> {
+        use rayon::prelude::*;
+
+        self.same_length(rhs)?;
+
+        let block_sums = self
+            .as_slice()
+            .par_chunks(REDUCTION_BLOCK_LENGTH)
+            .zip(rhs.as_slice().par_chunks(REDUCTION_BLOCK_LENGTH))
+            .map(|(lhs, rhs)| {
+                kernels::dot(lhs, rhs).expect("blocks of equal length vectors have equal lengths")
+            })
+            .collect::<Vec<_>>();
+
+        return Ok(pairwise_sum(&block_sums));
+    }
+}
+
+impl<F: Float, O> Vector<F, O> {
+    /// The euclidean length
+    pub fn norm(&self) -> F {
+        return self.norm_squared().sqrt();
+    }
+
+    /// [Vector::dot] accumulated with `summation`
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if `self` and `rhs` have different lengths
+    pub fn dot_with(&self, rhs: &Self, summation: Summation) -> Result<F, MatrixError> {
+        self.same_length(rhs)?;
+        return Ok(summation.sum(self.iter().zip(rhs.iter()).map(|(&lhs, &rhs)| lhs * rhs)));
+    }
+
+    /// [Vector::norm] with the squares accumulated with `summation`
+    pub fn norm_with(&self, summation: Summation) -> F {
+        return summation
+            .sum(self.iter().map(|&element| element * element))
+            .sqrt();
+    }
+
+    /// A vector with the same direction and a [Vector::norm] of `1`
+    /// ## Returns
+    /// - [None] if the norm of `self` is `0`
+    pub fn normalize(&self) -> Option<Self> {
+        let norm = self.norm();
+        if norm.is_zero() {
+            return None;
+        }
+        return Some(Vector {
+            elements: self.iter().map(|&element| element / norm).collect(),
+            orientation: PhantomData,
+        });
+    }
+}
+
+impl<F: Float, O> Vector<F, O> {
+    /// The angle in radians between `self` and `other`, in `[0, π]`
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if `self` and `other` have different lengths
+    /// - [MatrixError::ZeroVector]
+    ///   - if either vector has a norm of `0`
+    pub fn angle_between(&self, other: &Self) -> Result<F, MatrixError> {
+        self.same_length(other)?;
+        let (self_norm, other_norm) = (self.norm(), other.norm());
+        if self_norm.is_zero() || other_norm.is_zero() {
+            return Err(MatrixError::ZeroVector);
+        }
+
+        // 2 * atan2(|â - b̂|, |â + b̂|) stays accurate for nearly parallel vectors where acos(â · b̂) does not
+        let mut difference = F::zero();
+        let mut sum = F::zero();
+        for (&a, &b) in self.iter().zip(other.iter()) {
+            let (a, b) = (a / self_norm, b / other_norm);
+            difference = difference + (a - b) * (a - b);
+            sum = sum + (a + b) * (a + b);
+        }
+
+        let two = F::one() + F::one();
+        return Ok(two * difference.sqrt().atan2(sum.sqrt()));
+    }
+
+    /// The component of `self` that is parallel to `onto` <br>
+    /// `(self · onto / onto · onto) * onto`
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if `self` and `onto` have different lengths
+    /// - [MatrixError::ZeroVector]
+    ///   - if `onto` has a norm of `0`
+    pub fn project_onto(&self, onto: &Self) -> Result<Self, MatrixError> {
+        let dot = self.dot(onto)?;
+        let onto_norm_squared = onto.norm_squared();
+        if onto_norm_squared.is_zero() {
+            return Err(MatrixError::ZeroVector);
+        }
+
+        return Ok(onto.scale(dot / onto_norm_squared));
+    }
+
+    /// The component of `self` that is perpendicular to `from` <br>
+    /// `self - self.project_onto(from)`
+    /// ## Errors
+    /// - [MatrixError::DimensionError]
+    ///   - if `self` and `from` have different lengths
+    /// - [MatrixError::ZeroVector]
+    ///   - if `from` has a norm of `0`
+    pub fn reject_from(&self, from: &Self) -> Result<Self, MatrixError> {
+        let projection = self.project_onto(from)?;
+
+        return Ok(Vector {
+            elements: self
+                .iter()
+                .zip(projection.iter())
+                .map(|(&element, &parallel)| element - parallel)
+                .collect(),
+            orientation: PhantomData,
+        });
+    }
+}
+
+impl<E: Num + Clone> ColumnVector<E> {
+    /// The `len x 1` [Matrix] with the same elements
+    pub fn to_matrix(&self) -> Matrix<E> {
+        let mut matrix = Matrix::zeros(Shape {
+            rows: self.len_nonzero(),
+            cols: NonZeroUsize::MIN,
+        });
+        for (row, element) in self.iter().enumerate() {
+            matrix[row][0] = element.clone();
+        }
+        return matrix;
+    }
+
+    /// The outer product `self * rhs`, a `self.len() x rhs.len()` [Matrix] <br>
+    /// Any column vector can be multiplied by any row vector so this cannot fail.
+    pub fn outer(&self, rhs: &RowVector<E>) -> Matrix<E> {
+        return Matrix::outer_product(self.as_slice(), rhs.as_slice())
+            .expect("vectors are never empty");
+    }
+}
+
+impl<E: Num + Clone> RowVector<E> {
+    /// The `1 x len` [Matrix] with the same elements
+    pub fn to_matrix(&self) -> Matrix<E> {
+        let mut matrix = Matrix::zeros(Shape {
+            rows: NonZeroUsize::MIN,
+            cols: self.len_nonzero(),
+        });
+        matrix[0].clone_from_slice(self.as_slice());
+        return matrix;
+    }
+
+    /// The vector-matrix product `self * rhs`
+    /// ## Errors
+    /// - [MatrixError::Arithmetic]
+    ///   - if `self.len()` != `rhs.height()`
+    pub fn mul_matrix(&self, rhs: &Matrix<E>) -> Result<Self, MatrixError> {
+        let product = self.to_matrix().matrix_multiply(rhs)?;
+        return Vector::try_from(product[0].to_vec());
+    }
+}
+
+impl<E: Num + Clone> Matrix<E> {
+    /// The matrix-vector product `self * rhs`
+    /// ## Errors
+    /// - [MatrixError::Arithmetic]
+    ///   - if `self.width()` != `rhs.len()`
+    pub fn mul_column_vector(&self, rhs: &ColumnVector<E>) -> Result<ColumnVector<E>, MatrixError> {
+        return Vector::try_from(self.mul_vector(rhs.as_slice())?);
+    }
+}
+
+impl<E: Num + Clone> Mul<&Matrix<E>> for &RowVector<E> {
+    type Output = RowVector<E>;
+    /// The vector-matrix product `self * rhs`, see [RowVector::mul_matrix]
+    /// ## Panics
+    /// - if `self.len()` != `rhs.height()`
+    fn mul(self, rhs: &Matrix<E>) -> RowVector<E> {
+        return self
+            .mul_matrix(rhs)
+            .expect("the vector length must equal the matrix height");
+    }
+}
+
+impl<E: Num + Clone> Mul<&ColumnVector<E>> for &Matrix<E> {
+    type Output = ColumnVector<E>;
+    /// The matrix-vector product `self * rhs`, see [Matrix::mul_column_vector]
+    /// ## Panics
+    /// - if `self.width()` != `rhs.len()`
+    fn mul(self, rhs: &ColumnVector<E>) -> ColumnVector<E> {
+        return self
+            .mul_column_vector(rhs)
+            .expect("the matrix width must equal the vector length");
+    }
+}
+
+impl<E: Clone> TryFrom<&Matrix<E>> for ColumnVector<E> {
+    type Error = MatrixError;
+
+    /// Only succeeds for an `n x 1` [Matrix]
+    fn try_from(matrix: &Matrix<E>) -> Result<Self, Self::Error> {
+        if matrix.width() != 1 {
+            return Err(DimensionError::UnexpectedWidth {
+                expected: 1,
+                actual: matrix.shape(),
+            }
+            .into());
+        }
+        return Vector::try_from(matrix.column(0).cloned().collect::<Vec<_>>());
+    }
+}
+
+impl<E: Clone> TryFrom<&Matrix<E>> for RowVector<E> {
+    type Error = MatrixError;
+
+    /// Only succeeds for a `1 x n` [Matrix]
+    fn try_from(matrix: &Matrix<E>) -> Result<Self, Self::Error> {
+        if matrix.height() != 1 {
+            return Err(DimensionError::UnexpectedHeight {
+                expected: 1,
+                actual: matrix.shape(),
+            }
+            .into());
+        }
+        return Vector::try_from(matrix[0].to_vec());
+    }
+}
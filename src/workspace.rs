@@ -0,0 +1,216 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use crate::{DataSet, ExpressionBindings, ExpressionValue, Matrix, WorkspaceError};
+
+/// The subdirectory [Workspace::save] writes matrices to
+const MATRIX_DIRECTORY: &str = "matrices";
+/// The subdirectory [Workspace::save] writes data sets to
+const DATA_SET_DIRECTORY: &str = "data_sets";
+
+/// Named matrices and data sets that can be saved to and loaded from a directory <br>
+/// Names are letters, digits, and underscores so each value can be stored as `<name>.csv`.
+/// A saved workspace looks like
+/// ```text
+/// directory/
+///     matrices/<name>.csv
+///     data_sets/<name>.csv
+/// ```
+/// A [Workspace] can be used as the bindings of an [crate::Expression].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Workspace<F> {
+    matrices: BTreeMap<String, Matrix<F>>,
+    data_sets: BTreeMap<String, DataSet<F>>,
+}
+impl<F> Default for Workspace<F> {
+    fn default() -> Self {
+        return Workspace {
+            matrices: BTreeMap::new(),
+            data_sets: BTreeMap::new(),
+        };
+    }
+}
+impl<F> Workspace<F> {
+    pub fn new() -> Self {
+        return Self::default();
+    }
+    /// `true` if there are no matrices or data sets
+    pub fn is_empty(&self) -> bool {
+        return self.matrices.is_empty() && self.data_sets.is_empty();
+    }
+
+    /// Store `matrix` as `name`
+    /// ## Returns
+    /// - The matrix that was previously stored as `name`
+    /// ## Errors
+    /// - [WorkspaceError::InvalidName]
+    ///   - if `name` is empty or has characters other than letters, digits, and underscores
+    pub fn insert_matrix(
+        &mut self,
+        name: &str,
+        matrix: Matrix<F>,
+    ) -> Result<Option<Matrix<F>>, WorkspaceError> {
+        check_name(name)?;
+        return Ok(self.matrices.insert(name.to_string(), matrix));
+    }
+    pub fn matrix(&self, name: &str) -> Option<&Matrix<F>> {
+        return self.matrices.get(name);
+    }
+    pub fn remove_matrix(&mut self, name: &str) -> Option<Matrix<F>> {
+        return self.matrices.remove(name);
+    }
+    /// The names and values of every matrix in alphabetical order
+    pub fn matrices(&self) -> impl Iterator<Item = (&str, &Matrix<F>)> {
+        return self
+            .matrices
+            .iter()
+            .map(|(name, matrix)| (name.as_str(), matrix));
+    }
+
+    /// Store `data_set` as `name`
+    /// ## Returns
+    /// - The data set that was previously stored as `name`
+    /// ## Errors
+    /// - [WorkspaceError::InvalidName]
+    ///   - if `name` is empty or has characters other than letters, digits, and underscores
+    pub fn insert_data_set(
+        &mut self,
+        name: &str,
+        data_set: DataSet<F>,
+    ) -> Result<Option<DataSet<F>>, WorkspaceError> {
+        check_name(name)?;
+        return Ok(self.data_sets.insert(name.to_string(), data_set));
+    }
+    pub fn data_set(&self, name: &str) -> Option<&DataSet<F>> {
+        return self.data_sets.get(name);
+    }
+    pub fn remove_data_set(&mut self, name: &str) -> Option<DataSet<F>> {
+        return self.data_sets.remove(name);
+    }
+    /// The names and values of every data set in alphabetical order
+    pub fn data_sets(&self) -> impl Iterator<Item = (&str, &DataSet<F>)> {
+        return self
+            .data_sets
+            .iter()
+            .map(|(name, data_set)| (name.as_str(), data_set));
+    }
+}
+
+impl<F: Display> Workspace<F> {
+    /// Write every matrix and data set as CSV under `directory`, creating it if needed <br>
+    /// Files for names that are no longer in the workspace are left alone.
+    /// ## Errors
+    /// - [WorkspaceError::Io]
+    ///   - if a directory can not be created or a file can not be written
+    pub fn save(&self, directory: impl AsRef<Path>) -> Result<(), WorkspaceError> {
+        let directory = directory.as_ref();
+
+        let matrix_directory = create_directory(directory.join(MATRIX_DIRECTORY))?;
+        for (name, matrix) in self.matrices.iter() {
+            write_file(&matrix_directory, name, matrix.to_csv_string())?;
+        }
+
+        let data_set_directory = create_directory(directory.join(DATA_SET_DIRECTORY))?;
+        for (name, data_set) in self.data_sets.iter() {
+            write_file(&data_set_directory, name, data_set.to_csv_string())?;
+        }
+
+        return Ok(());
+    }
+}
+
+impl<F> Workspace<F>
+where
+    F: FromStr,
+    F::Err: std::error::Error + 'static,
+{
+    /// Read a workspace written by [Workspace::save] <br>
+    /// Missing subdirectories are treated as empty and files without a `.csv` extension are skipped.
+    /// ## Errors
+    /// - [WorkspaceError::Io]
+    ///   - if a directory or file can not be read
+    /// - [WorkspaceError::InvalidName]
+    ///   - if a file name is not a valid name
+    /// - [WorkspaceError::ParseMatrix] or [WorkspaceError::ParseDataSet]
+    ///   - if a file is not valid CSV for its kind
+    pub fn load(directory: impl AsRef<Path>) -> Result<Self, WorkspaceError> {
+        let directory = directory.as_ref();
+        let mut workspace = Workspace::new();
+
+        for (name, path, csv) in read_directory(&directory.join(MATRIX_DIRECTORY))? {
+            let matrix = Matrix::from_csv_str(&csv)
+                .map_err(|source| WorkspaceError::ParseMatrix { path, source })?;
+            workspace.insert_matrix(&name, matrix)?;
+        }
+
+        for (name, path, csv) in read_directory(&directory.join(DATA_SET_DIRECTORY))? {
+            let data_set = csv
+                .parse()
+                .map_err(|source| WorkspaceError::ParseDataSet { path, source })?;
+            workspace.insert_data_set(&name, data_set)?;
+        }
+
+        return Ok(workspace);
+    }
+}
+
+impl<F: Clone> ExpressionBindings<F> for Workspace<F> {
+    fn binding(&self, name: &str) -> Option<ExpressionValue<F>> {
+        return self.matrix(name).cloned().map(ExpressionValue::Matrix);
+    }
+}
+
+fn check_name(name: &str) -> Result<(), WorkspaceError> {
+    if name.is_empty() || !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        return Err(WorkspaceError::InvalidName(name.to_string()));
+    }
+    return Ok(());
+}
+
+fn create_directory(path: PathBuf) -> Result<PathBuf, WorkspaceError> {
+    return match fs::create_dir_all(&path) {
+        Ok(()) => Ok(path),
+        Err(source) => Err(WorkspaceError::Io { path, source }),
+    };
+}
+
+fn write_file(directory: &Path, name: &str, contents: String) -> Result<(), WorkspaceError> {
+    let path = directory.join(format!("{name}.csv"));
+    return fs::write(&path, contents).map_err(|source| WorkspaceError::Io { path, source });
+}
+
+/// The name, path, and contents of every `.csv` file in `directory`, or nothing if it does not exist
+fn read_directory(directory: &Path) -> Result<Vec<(String, PathBuf, String)>, WorkspaceError> {
+    if !directory.exists() {
+        return Ok(Vec::new());
+    }
+    let io_error = |source| WorkspaceError::Io {
+        path: directory.to_path_buf(),
+        source,
+    };
+
+    let mut files = Vec::new();
+    for entry in fs::read_dir(directory).map_err(io_error)? {
+        let path = entry.map_err(io_error)?.path();
+        if path.extension().and_then(|extension| extension.to_str()) != Some("csv") {
+            continue;
+        }
+        let name = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or_default()
+            .to_string();
+        let contents = fs::read_to_string(&path).map_err(|source| WorkspaceError::Io {
+            path: path.clone(),
+            source,
+        })?;
+        files.push((name, path, contents));
+    }
+
+    return Ok(files);
+}